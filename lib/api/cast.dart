@@ -4,19 +4,92 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'description.dart';
+import 'monitor.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'scpd.dart';
+import 'ssdp.dart';
 part 'cast.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `collect_ssdp_responses`, `create_ssdp_socket`, `extract_location`, `get_av_url`, `get_local_ip`, `get_rc_url`, `parse_device_xml`, `send_soap_action`, `try_broadcast_ssdp`, `try_multicast_ssdp`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `accepts`, `broadcast`, `broadcast_targets`, `build_magic_packet`, `build_search_request`, `collect_ssdp_responses`, `create_ssdp_socket`, `create_ssdp_socket_v6`, `discover_projectors`, `fill_missing_udn`, `get_av_url`, `get_local_ip`, `get_rc_url`, `host_from_url`, `http_request`, `into_tokio_socket`, `matches_search_target`, `multicast_interface_addrs`, `mx`, `never_cancelled`, `open_multicast_v4`, `open_multicast_v6`, `parse_device_xml`, `parse_ip`, `parse_secure_on_password`, `projector_info_from_description`, `receive_datagrams`, `record_device`, `retransmit`, `send_action`, `send_broadcast`, `send_magic_packets`, `send_multicast_v4`, `send_multicast_v6`, `send_soap_action`, `stream_scan`, `targets`, `timeout_duration`, `try_broadcast_ssdp`, `try_multicast_ssdp`, `ttl`, `udn_from_usn`, `unicast_search`, `wait_cancelled`, `wake_targets`, `wake_timeout`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 通过设备描述文件地址直接添加设备
+Future<ProjectorInfo> addDeviceByLocation({required String url}) =>
+    RustLib.instance.api.crateApiCastAddDeviceByLocation(url: url);
+
+/// 从 devices_to_json 生成的 JSON 恢复设备列表 (兼容旧版本保存的 JSON)
+List<ProjectorInfo> devicesFromJson({required String json}) =>
+    RustLib.instance.api.crateApiCastDevicesFromJson(json: json);
+
+/// 将设备列表序列化为 JSON，便于保存用户选择过的设备
+String devicesToJson({required List<ProjectorInfo> devices}) =>
+    RustLib.instance.api.crateApiCastDevicesToJson(devices: devices);
+
+/// 向 ip:1900 发送单播 M-SEARCH，并根据返回的 LOCATION 解析设备
+Future<ProjectorInfo> probeIp(
+        {required String ip, required BigInt timeoutSecs}) =>
+    RustLib.instance.api.crateApiCastProbeIp(ip: ip, timeoutSecs: timeoutSecs);
+
+/// 发送定向 M-SEARCH (ST: uuid:...) 查找指定设备，返回最新的地址和控制 URL
+Future<ProjectorInfo> resolveDevice(
+        {required String udn, required BigInt timeoutSecs}) =>
+    RustLib.instance.api.crateApiCastResolveDevice(
+        udn: udn, timeoutSecs: timeoutSecs);
 
 Future<List<ProjectorInfo>> scanProjectors({required BigInt timeoutSecs}) =>
     RustLib.instance.api.crateApiCastScanProjectors(timeoutSecs: timeoutSecs);
 
+/// 流式扫描：每解析完一个设备立即推送 DeviceFound，结束时推送 Completed
+Stream<ScanEvent> scanProjectorsStream({required ScanOptions options}) =>
+    RustLib.instance.api.crateApiCastScanProjectorsStream(options: options);
+
+/// 按指定搜索目标扫描，只返回 ST 匹配的设备
+Future<List<ProjectorInfo>> scanProjectorsWithOptions(
+        {required ScanOptions options}) =>
+    RustLib.instance.api.crateApiCastScanProjectorsWithOptions(
+        options: options);
+
+/// 扫描并返回诊断信息 (使用的接口、广播目标等)
+Future<ScanReport> scanProjectorsWithReport({required ScanOptions options}) =>
+    RustLib.instance.api.crateApiCastScanProjectorsWithReport(options: options);
+
+/// 发送 Wake-on-LAN 并反复定向搜索，直到设备响应 SSDP 且 AVTransport 控制地址可用，返回最新的设备信息
+/// udn_or_ip 为设备 UDN (uuid:...) 或 IP；超时错误会说明停在哪个阶段
+Future<ProjectorInfo> wakeAndWait(
+        {required String macAddress,
+        required String udnOrIp,
+        required BigInt timeoutSecs}) =>
+    RustLib.instance.api.crateApiCastWakeAndWait(
+        macAddress: macAddress, udnOrIp: udnOrIp, timeoutSecs: timeoutSecs);
+
+/// 向 255.255.255.255:9 发送一次魔术包
 Future<void> wakeOnLan({required String macAddress}) =>
     RustLib.instance.api.crateApiCastWakeOnLan(macAddress: macAddress);
 
+/// 按 options 发送魔术包：可指定目标地址和端口、定向广播、重复次数及 SecureOn 密码
+Future<void> wakeOnLanWithOptions(
+        {required String macAddress, required WakeOptions options}) =>
+    RustLib.instance.api.crateApiCastWakeOnLanWithOptions(
+        macAddress: macAddress, options: options);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ScanHandle>>
+/// 可取消的扫描。一个 ScanHandle 只对应一次扫描，取消后需重新创建
+abstract class ScanHandle implements RustOpaqueInterface {
+  /// 取消扫描：停止接收响应并中止正在进行的描述文件请求
+  void cancel();
+
+  factory ScanHandle({required ScanOptions options}) =>
+      RustLib.instance.api.crateApiCastScanHandleNew(options: options);
+
+  /// 执行扫描；被取消时立即返回已发现的设备 (diagnostics.cancelled 为 true)
+  Future<ScanReport> run();
+
+  /// 流式执行扫描，事件与 scan_projectors_stream 相同；被取消时同样以 Completed 结束
+  Stream<ScanEvent> runStream();
+}
+
 @freezed
 sealed class ProjectorInfo with _$ProjectorInfo {
   const ProjectorInfo._();
@@ -24,14 +97,39 @@ sealed class ProjectorInfo with _$ProjectorInfo {
     required String friendlyName,
     required String ip,
     required String locationXmlUrl,
+    required String udn,
     String? avTransportUrl,
     String? renderingControlUrl,
+    required DeviceDescription description,
+    required DeviceKind kind,
+    required DeviceCapabilities capabilities,
+    SsdpMessage? ssdp,
+    /// 网卡 MAC (aa:bb:cc:dd:ee:ff)，用于远程开机；优先取系统邻居表，其次取描述文件
+    /// 邻居表仅对同一子网的设备有效；iOS 上不可用，Android 10+ 的应用通常无权读取，此时多为 null
+    String? macAddress,
   }) = _ProjectorInfo;
 
   /// 投送视频 (SetAVTransportURI + Play)
   Future<void> castVideo({required String videoUrl}) => RustLib.instance.api
       .crateApiCastProjectorInfoCastVideo(that: this, videoUrl: videoUrl);
 
+  /// 检查设备是否在线：同时请求描述文件 (HEAD，失败时 GET) 并发送单播 M-SEARCH，返回各自的延迟
+  Future<DeviceStatus> checkAlive({required BigInt timeoutMs}) =>
+      RustLib.instance.api.crateApiCastProjectorInfoCheckAlive(
+          that: this, timeoutMs: timeoutMs);
+
+  /// 下载最接近 preferred_size 像素的图标，返回图片数据 (按设备 UDN 缓存)
+  Future<Uint8List> fetchIcon({required int preferredSize}) =>
+      RustLib.instance.api.crateApiCastProjectorInfoFetchIcon(
+          that: this, preferredSize: preferredSize);
+
+  /// 获取设备所有服务的 SCPD (动作、参数、状态变量)，包括嵌入设备的服务
+  /// 单个服务的 SCPD 获取失败时跳过该服务，只有全部失败时才返回错误
+  Future<List<ServiceDescription>> fetchServices() =>
+      RustLib.instance.api.crateApiCastProjectorInfoFetchServices(
+        that: this,
+      );
+
   /// 获取播放进度 (当前时长, 总时长) 格式 HH:MM:SS
   Future<(String, String)> getPositionInfo() =>
       RustLib.instance.api.crateApiCastProjectorInfoGetPositionInfo(
@@ -56,6 +154,12 @@ sealed class ProjectorInfo with _$ProjectorInfo {
         that: this,
       );
 
+  /// 设备描述中声明的图标 (mimetype、宽高、色深、URL)
+  List<DeviceIcon> icons() =>
+      RustLib.instance.api.crateApiCastProjectorInfoIcons(
+        that: this,
+      );
+
   /// 暂停
   Future<void> pause() => RustLib.instance.api.crateApiCastProjectorInfoPause(
         that: this,
@@ -66,6 +170,11 @@ sealed class ProjectorInfo with _$ProjectorInfo {
         that: this,
       );
 
+  /// 刷新设备地址和控制 URL：先尝试保存的 LOCATION，不可达或 UDN 不符时按 UDN 重新搜索
+  Future<ProjectorInfo> refresh({required BigInt timeoutSecs}) =>
+      RustLib.instance.api.crateApiCastProjectorInfoRefresh(
+          that: this, timeoutSecs: timeoutSecs);
+
   /// 进度跳转 - target_time 格式为 HH:MM:SS
   Future<void> seek({required String targetTime}) => RustLib.instance.api
       .crateApiCastProjectorInfoSeek(that: this, targetTime: targetTime);
@@ -82,6 +191,79 @@ sealed class ProjectorInfo with _$ProjectorInfo {
   Future<void> stop() => RustLib.instance.api.crateApiCastProjectorInfoStop(
         that: this,
       );
+
+  /// 设备是否支持指定服务的指定动作 (如 AVTransport:1 的 Seek)
+  Future<bool> supportsAction(
+          {required String serviceType, required String action}) =>
+      RustLib.instance.api.crateApiCastProjectorInfoSupportsAction(
+          that: this, serviceType: serviceType, action: action);
+
+  /// 向设备发送 Wake-on-LAN 魔术包，需要已知 mac_address
+  Future<void> wake() => RustLib.instance.api.crateApiCastProjectorInfoWake(
+        that: this,
+      );
+}
+
+@freezed
+sealed class ScanDiagnostics with _$ScanDiagnostics {
+  const ScanDiagnostics._();
+  const factory ScanDiagnostics({
+    required List<String> multicastInterfaces,
+    required bool usedBroadcast,
+    required List<String> broadcastTargets,
+    required List<String> errors,
+    required bool cancelled,
+    required bool usedSweep,
+    required int sweptHosts,
+  }) = _ScanDiagnostics;
+
+  static Future<ScanDiagnostics> default_() =>
+      RustLib.instance.api.crateApiCastScanDiagnosticsDefault();
+}
+
+@freezed
+sealed class ScanEvent with _$ScanEvent {
+  const ScanEvent._();
+
+  const factory ScanEvent.deviceFound(
+    ProjectorInfo field0,
+  ) = ScanEvent_DeviceFound;
+  const factory ScanEvent.completed({
+    required int total,
+    required ScanDiagnostics diagnostics,
+  }) = ScanEvent_Completed;
+}
+
+@freezed
+sealed class ScanOptions with _$ScanOptions {
+  const ScanOptions._();
+  const factory ScanOptions({
+    required BigInt timeoutSecs,
+    required List<String> searchTargets,
+    required List<String> interfaces,
+    required bool enableIpv6,
+    required bool renderersOnly,
+    required int sendCount,
+    required BigInt sendIntervalMs,
+    int? mxSecs,
+    int? multicastTtl,
+    required bool subnetSweep,
+    int? sweepConcurrency,
+    int? sweepRatePerSec,
+  }) = _ScanOptions;
+
+  /// 默认参数：搜索 rootdevice，发送 3 次，间隔 500ms；其余字段可在 Dart 侧用 copyWith 修改
+  static ScanOptions withTimeout({required BigInt timeoutSecs}) =>
+      RustLib.instance.api.crateApiCastScanOptionsWithTimeout(
+          timeoutSecs: timeoutSecs);
+}
+
+@freezed
+sealed class ScanReport with _$ScanReport {
+  const factory ScanReport({
+    required List<ProjectorInfo> devices,
+    required ScanDiagnostics diagnostics,
+  }) = _ScanReport;
 }
 
 enum TransportState {
@@ -93,3 +275,16 @@ enum TransportState {
   unknown,
   ;
 }
+
+/// 远程开机参数
+@freezed
+sealed class WakeOptions with _$WakeOptions {
+  const factory WakeOptions({
+    String? targetAddress,
+    required int port,
+    required bool directedBroadcast,
+    required int repeatCount,
+    required BigInt repeatIntervalMs,
+    String? secureOnPassword,
+  }) = _WakeOptions;
+}
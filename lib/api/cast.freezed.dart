@@ -17,8 +17,16 @@ mixin _$ProjectorInfo {
   String get friendlyName;
   String get ip;
   String get locationXmlUrl;
+  String get udn;
   String? get avTransportUrl;
   String? get renderingControlUrl;
+  DeviceDescription get description;
+  DeviceKind get kind;
+  DeviceCapabilities get capabilities;
+  SsdpMessage? get ssdp;
+  /// 网卡 MAC (aa:bb:cc:dd:ee:ff)，用于远程开机；优先取系统邻居表，其次取描述文件
+  /// 邻居表仅对同一子网的设备有效；iOS 上不可用，Android 10+ 的应用通常无权读取，此时多为 null
+  String? get macAddress;
 
   /// Create a copy of ProjectorInfo
   /// with the given fields replaced by the non-null parameter values.
@@ -38,19 +46,29 @@ mixin _$ProjectorInfo {
             (identical(other.ip, ip) || other.ip == ip) &&
             (identical(other.locationXmlUrl, locationXmlUrl) ||
                 other.locationXmlUrl == locationXmlUrl) &&
+            (identical(other.udn, udn) || other.udn == udn) &&
             (identical(other.avTransportUrl, avTransportUrl) ||
                 other.avTransportUrl == avTransportUrl) &&
             (identical(other.renderingControlUrl, renderingControlUrl) ||
-                other.renderingControlUrl == renderingControlUrl));
+                other.renderingControlUrl == renderingControlUrl) &&
+            (identical(other.description, description) ||
+                other.description == description) &&
+            (identical(other.kind, kind) || other.kind == kind) &&
+            (identical(other.capabilities, capabilities) ||
+                other.capabilities == capabilities) &&
+            (identical(other.ssdp, ssdp) || other.ssdp == ssdp) &&
+            (identical(other.macAddress, macAddress) ||
+                other.macAddress == macAddress));
   }
 
   @override
   int get hashCode => Object.hash(runtimeType, friendlyName, ip, locationXmlUrl,
-      avTransportUrl, renderingControlUrl);
+      udn, avTransportUrl, renderingControlUrl, description, kind, capabilities,
+      ssdp, macAddress);
 
   @override
   String toString() {
-    return 'ProjectorInfo(friendlyName: $friendlyName, ip: $ip, locationXmlUrl: $locationXmlUrl, avTransportUrl: $avTransportUrl, renderingControlUrl: $renderingControlUrl)';
+    return 'ProjectorInfo(friendlyName: $friendlyName, ip: $ip, locationXmlUrl: $locationXmlUrl, udn: $udn, avTransportUrl: $avTransportUrl, renderingControlUrl: $renderingControlUrl, description: $description, kind: $kind, capabilities: $capabilities, ssdp: $ssdp, macAddress: $macAddress)';
   }
 }
 
@@ -64,8 +82,18 @@ abstract mixin class $ProjectorInfoCopyWith<$Res> {
       {String friendlyName,
       String ip,
       String locationXmlUrl,
+      String udn,
       String? avTransportUrl,
-      String? renderingControlUrl});
+      String? renderingControlUrl,
+      DeviceDescription description,
+      DeviceKind kind,
+      DeviceCapabilities capabilities,
+      SsdpMessage? ssdp,
+      String? macAddress});
+
+  $DeviceDescriptionCopyWith<$Res> get description;
+  $DeviceCapabilitiesCopyWith<$Res> get capabilities;
+  $SsdpMessageCopyWith<$Res>? get ssdp;
 }
 
 /// @nodoc
@@ -84,8 +112,14 @@ class _$ProjectorInfoCopyWithImpl<$Res>
     Object? friendlyName = null,
     Object? ip = null,
     Object? locationXmlUrl = null,
+    Object? udn = null,
     Object? avTransportUrl = freezed,
     Object? renderingControlUrl = freezed,
+    Object? description = null,
+    Object? kind = null,
+    Object? capabilities = null,
+    Object? ssdp = freezed,
+    Object? macAddress = freezed,
   }) {
     return _then(_self.copyWith(
       friendlyName: null == friendlyName
@@ -100,6 +134,10 @@ class _$ProjectorInfoCopyWithImpl<$Res>
           ? _self.locationXmlUrl
           : locationXmlUrl // ignore: cast_nullable_to_non_nullable
               as String,
+      udn: null == udn
+          ? _self.udn
+          : udn // ignore: cast_nullable_to_non_nullable
+              as String,
       avTransportUrl: freezed == avTransportUrl
           ? _self.avTransportUrl
           : avTransportUrl // ignore: cast_nullable_to_non_nullable
@@ -108,8 +146,62 @@ class _$ProjectorInfoCopyWithImpl<$Res>
           ? _self.renderingControlUrl
           : renderingControlUrl // ignore: cast_nullable_to_non_nullable
               as String?,
+      description: null == description
+          ? _self.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as DeviceDescription,
+      kind: null == kind
+          ? _self.kind
+          : kind // ignore: cast_nullable_to_non_nullable
+              as DeviceKind,
+      capabilities: null == capabilities
+          ? _self.capabilities
+          : capabilities // ignore: cast_nullable_to_non_nullable
+              as DeviceCapabilities,
+      ssdp: freezed == ssdp
+          ? _self.ssdp
+          : ssdp // ignore: cast_nullable_to_non_nullable
+              as SsdpMessage?,
+      macAddress: freezed == macAddress
+          ? _self.macAddress
+          : macAddress // ignore: cast_nullable_to_non_nullable
+              as String?,
     ));
   }
+
+  /// Create a copy of ProjectorInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DeviceDescriptionCopyWith<$Res> get description {
+    return $DeviceDescriptionCopyWith<$Res>(_self.description, (value) {
+      return _then(_self.copyWith(description: value));
+    });
+  }
+
+  /// Create a copy of ProjectorInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DeviceCapabilitiesCopyWith<$Res> get capabilities {
+    return $DeviceCapabilitiesCopyWith<$Res>(_self.capabilities, (value) {
+      return _then(_self.copyWith(capabilities: value));
+    });
+  }
+
+  /// Create a copy of ProjectorInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $SsdpMessageCopyWith<$Res>? get ssdp {
+    if (_self.ssdp == null) {
+      return null;
+    }
+
+    return $SsdpMessageCopyWith<$Res>(_self.ssdp!, (value) {
+      return _then(_self.copyWith(ssdp: value));
+    });
+  }
 }
 
 /// Adds pattern-matching-related methods to [ProjectorInfo].
@@ -204,7 +296,10 @@ extension ProjectorInfoPatterns on ProjectorInfo {
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>(
     TResult Function(String friendlyName, String ip, String locationXmlUrl,
-            String? avTransportUrl, String? renderingControlUrl)?
+            String udn, String? avTransportUrl, String? renderingControlUrl,
+            DeviceDescription description, DeviceKind kind,
+            DeviceCapabilities capabilities, SsdpMessage? ssdp,
+            String? macAddress)?
         $default, {
     required TResult orElse(),
   }) {
@@ -212,7 +307,9 @@ extension ProjectorInfoPatterns on ProjectorInfo {
     switch (_that) {
       case _ProjectorInfo() when $default != null:
         return $default(_that.friendlyName, _that.ip, _that.locationXmlUrl,
-            _that.avTransportUrl, _that.renderingControlUrl);
+            _that.udn, _that.avTransportUrl, _that.renderingControlUrl,
+            _that.description, _that.kind, _that.capabilities, _that.ssdp,
+            _that.macAddress);
       case _:
         return orElse();
     }
@@ -234,14 +331,19 @@ extension ProjectorInfoPatterns on ProjectorInfo {
   @optionalTypeArgs
   TResult when<TResult extends Object?>(
     TResult Function(String friendlyName, String ip, String locationXmlUrl,
-            String? avTransportUrl, String? renderingControlUrl)
+            String udn, String? avTransportUrl, String? renderingControlUrl,
+            DeviceDescription description, DeviceKind kind,
+            DeviceCapabilities capabilities, SsdpMessage? ssdp,
+            String? macAddress)
         $default,
   ) {
     final _that = this;
     switch (_that) {
       case _ProjectorInfo():
         return $default(_that.friendlyName, _that.ip, _that.locationXmlUrl,
-            _that.avTransportUrl, _that.renderingControlUrl);
+            _that.udn, _that.avTransportUrl, _that.renderingControlUrl,
+            _that.description, _that.kind, _that.capabilities, _that.ssdp,
+            _that.macAddress);
     }
   }
 
@@ -260,14 +362,19 @@ extension ProjectorInfoPatterns on ProjectorInfo {
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>(
     TResult? Function(String friendlyName, String ip, String locationXmlUrl,
-            String? avTransportUrl, String? renderingControlUrl)?
+            String udn, String? avTransportUrl, String? renderingControlUrl,
+            DeviceDescription description, DeviceKind kind,
+            DeviceCapabilities capabilities, SsdpMessage? ssdp,
+            String? macAddress)?
         $default,
   ) {
     final _that = this;
     switch (_that) {
       case _ProjectorInfo() when $default != null:
         return $default(_that.friendlyName, _that.ip, _that.locationXmlUrl,
-            _that.avTransportUrl, _that.renderingControlUrl);
+            _that.udn, _that.avTransportUrl, _that.renderingControlUrl,
+            _that.description, _that.kind, _that.capabilities, _that.ssdp,
+            _that.macAddress);
       case _:
         return null;
     }
@@ -281,8 +388,14 @@ class _ProjectorInfo extends ProjectorInfo {
       {required this.friendlyName,
       required this.ip,
       required this.locationXmlUrl,
+      required this.udn,
       this.avTransportUrl,
-      this.renderingControlUrl})
+      this.renderingControlUrl,
+      required this.description,
+      required this.kind,
+      required this.capabilities,
+      this.ssdp,
+      this.macAddress})
       : super._();
 
   @override
@@ -292,9 +405,23 @@ class _ProjectorInfo extends ProjectorInfo {
   @override
   final String locationXmlUrl;
   @override
+  final String udn;
+  @override
   final String? avTransportUrl;
   @override
   final String? renderingControlUrl;
+  @override
+  final DeviceDescription description;
+  @override
+  final DeviceKind kind;
+  @override
+  final DeviceCapabilities capabilities;
+  @override
+  final SsdpMessage? ssdp;
+  /// 网卡 MAC (aa:bb:cc:dd:ee:ff)，用于远程开机；优先取系统邻居表，其次取描述文件
+  /// 邻居表仅对同一子网的设备有效；iOS 上不可用，Android 10+ 的应用通常无权读取，此时多为 null
+  @override
+  final String? macAddress;
 
   /// Create a copy of ProjectorInfo
   /// with the given fields replaced by the non-null parameter values.
@@ -314,19 +441,29 @@ class _ProjectorInfo extends ProjectorInfo {
             (identical(other.ip, ip) || other.ip == ip) &&
             (identical(other.locationXmlUrl, locationXmlUrl) ||
                 other.locationXmlUrl == locationXmlUrl) &&
+            (identical(other.udn, udn) || other.udn == udn) &&
             (identical(other.avTransportUrl, avTransportUrl) ||
                 other.avTransportUrl == avTransportUrl) &&
             (identical(other.renderingControlUrl, renderingControlUrl) ||
-                other.renderingControlUrl == renderingControlUrl));
+                other.renderingControlUrl == renderingControlUrl) &&
+            (identical(other.description, description) ||
+                other.description == description) &&
+            (identical(other.kind, kind) || other.kind == kind) &&
+            (identical(other.capabilities, capabilities) ||
+                other.capabilities == capabilities) &&
+            (identical(other.ssdp, ssdp) || other.ssdp == ssdp) &&
+            (identical(other.macAddress, macAddress) ||
+                other.macAddress == macAddress));
   }
 
   @override
   int get hashCode => Object.hash(runtimeType, friendlyName, ip, locationXmlUrl,
-      avTransportUrl, renderingControlUrl);
+      udn, avTransportUrl, renderingControlUrl, description, kind, capabilities,
+      ssdp, macAddress);
 
   @override
   String toString() {
-    return 'ProjectorInfo(friendlyName: $friendlyName, ip: $ip, locationXmlUrl: $locationXmlUrl, avTransportUrl: $avTransportUrl, renderingControlUrl: $renderingControlUrl)';
+    return 'ProjectorInfo(friendlyName: $friendlyName, ip: $ip, locationXmlUrl: $locationXmlUrl, udn: $udn, avTransportUrl: $avTransportUrl, renderingControlUrl: $renderingControlUrl, description: $description, kind: $kind, capabilities: $capabilities, ssdp: $ssdp, macAddress: $macAddress)';
   }
 }
 
@@ -342,8 +479,21 @@ abstract mixin class _$ProjectorInfoCopyWith<$Res>
       {String friendlyName,
       String ip,
       String locationXmlUrl,
+      String udn,
       String? avTransportUrl,
-      String? renderingControlUrl});
+      String? renderingControlUrl,
+      DeviceDescription description,
+      DeviceKind kind,
+      DeviceCapabilities capabilities,
+      SsdpMessage? ssdp,
+      String? macAddress});
+
+  @override
+  $DeviceDescriptionCopyWith<$Res> get description;
+  @override
+  $DeviceCapabilitiesCopyWith<$Res> get capabilities;
+  @override
+  $SsdpMessageCopyWith<$Res>? get ssdp;
 }
 
 /// @nodoc
@@ -362,8 +512,14 @@ class __$ProjectorInfoCopyWithImpl<$Res>
     Object? friendlyName = null,
     Object? ip = null,
     Object? locationXmlUrl = null,
+    Object? udn = null,
     Object? avTransportUrl = freezed,
     Object? renderingControlUrl = freezed,
+    Object? description = null,
+    Object? kind = null,
+    Object? capabilities = null,
+    Object? ssdp = freezed,
+    Object? macAddress = freezed,
   }) {
     return _then(_ProjectorInfo(
       friendlyName: null == friendlyName
@@ -378,6 +534,10 @@ class __$ProjectorInfoCopyWithImpl<$Res>
           ? _self.locationXmlUrl
           : locationXmlUrl // ignore: cast_nullable_to_non_nullable
               as String,
+      udn: null == udn
+          ? _self.udn
+          : udn // ignore: cast_nullable_to_non_nullable
+              as String,
       avTransportUrl: freezed == avTransportUrl
           ? _self.avTransportUrl
           : avTransportUrl // ignore: cast_nullable_to_non_nullable
@@ -386,6 +546,2155 @@ class __$ProjectorInfoCopyWithImpl<$Res>
           ? _self.renderingControlUrl
           : renderingControlUrl // ignore: cast_nullable_to_non_nullable
               as String?,
+      description: null == description
+          ? _self.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as DeviceDescription,
+      kind: null == kind
+          ? _self.kind
+          : kind // ignore: cast_nullable_to_non_nullable
+              as DeviceKind,
+      capabilities: null == capabilities
+          ? _self.capabilities
+          : capabilities // ignore: cast_nullable_to_non_nullable
+              as DeviceCapabilities,
+      ssdp: freezed == ssdp
+          ? _self.ssdp
+          : ssdp // ignore: cast_nullable_to_non_nullable
+              as SsdpMessage?,
+      macAddress: freezed == macAddress
+          ? _self.macAddress
+          : macAddress // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+
+  /// Create a copy of ProjectorInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DeviceDescriptionCopyWith<$Res> get description {
+    return $DeviceDescriptionCopyWith<$Res>(_self.description, (value) {
+      return _then(_self.copyWith(description: value));
+    });
+  }
+
+  /// Create a copy of ProjectorInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DeviceCapabilitiesCopyWith<$Res> get capabilities {
+    return $DeviceCapabilitiesCopyWith<$Res>(_self.capabilities, (value) {
+      return _then(_self.copyWith(capabilities: value));
+    });
+  }
+
+  /// Create a copy of ProjectorInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $SsdpMessageCopyWith<$Res>? get ssdp {
+    if (_self.ssdp == null) {
+      return null;
+    }
+
+    return $SsdpMessageCopyWith<$Res>(_self.ssdp!, (value) {
+      return _then(_self.copyWith(ssdp: value));
+    });
+  }
+}
+
+/// @nodoc
+mixin _$ScanDiagnostics {
+  List<String> get multicastInterfaces;
+  bool get usedBroadcast;
+  List<String> get broadcastTargets;
+  List<String> get errors;
+  bool get cancelled;
+  bool get usedSweep;
+  int get sweptHosts;
+
+  /// Create a copy of ScanDiagnostics
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ScanDiagnosticsCopyWith<ScanDiagnostics> get copyWith =>
+      _$ScanDiagnosticsCopyWithImpl<ScanDiagnostics>(
+          this as ScanDiagnostics, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ScanDiagnostics &&
+            const DeepCollectionEquality()
+                .equals(other.multicastInterfaces, multicastInterfaces) &&
+            (identical(other.usedBroadcast, usedBroadcast) ||
+                other.usedBroadcast == usedBroadcast) &&
+            const DeepCollectionEquality()
+                .equals(other.broadcastTargets, broadcastTargets) &&
+            const DeepCollectionEquality().equals(other.errors, errors) &&
+            (identical(other.cancelled, cancelled) ||
+                other.cancelled == cancelled) &&
+            (identical(other.usedSweep, usedSweep) ||
+                other.usedSweep == usedSweep) &&
+            (identical(other.sweptHosts, sweptHosts) ||
+                other.sweptHosts == sweptHosts));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType,
+      const DeepCollectionEquality().hash(multicastInterfaces), usedBroadcast,
+      const DeepCollectionEquality().hash(broadcastTargets),
+      const DeepCollectionEquality().hash(errors), cancelled, usedSweep,
+      sweptHosts);
+
+  @override
+  String toString() {
+    return 'ScanDiagnostics(multicastInterfaces: $multicastInterfaces, usedBroadcast: $usedBroadcast, broadcastTargets: $broadcastTargets, errors: $errors, cancelled: $cancelled, usedSweep: $usedSweep, sweptHosts: $sweptHosts)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ScanDiagnosticsCopyWith<$Res> {
+  factory $ScanDiagnosticsCopyWith(
+          ScanDiagnostics value, $Res Function(ScanDiagnostics) _then) =
+      _$ScanDiagnosticsCopyWithImpl;
+  @useResult
+  $Res call(
+      {List<String> multicastInterfaces,
+      bool usedBroadcast,
+      List<String> broadcastTargets,
+      List<String> errors,
+      bool cancelled,
+      bool usedSweep,
+      int sweptHosts});
+}
+
+/// @nodoc
+class _$ScanDiagnosticsCopyWithImpl<$Res>
+    implements $ScanDiagnosticsCopyWith<$Res> {
+  _$ScanDiagnosticsCopyWithImpl(this._self, this._then);
+
+  final ScanDiagnostics _self;
+  final $Res Function(ScanDiagnostics) _then;
+
+  /// Create a copy of ScanDiagnostics
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? multicastInterfaces = null,
+    Object? usedBroadcast = null,
+    Object? broadcastTargets = null,
+    Object? errors = null,
+    Object? cancelled = null,
+    Object? usedSweep = null,
+    Object? sweptHosts = null,
+  }) {
+    return _then(_self.copyWith(
+      multicastInterfaces: null == multicastInterfaces
+          ? _self.multicastInterfaces
+          : multicastInterfaces // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      usedBroadcast: null == usedBroadcast
+          ? _self.usedBroadcast
+          : usedBroadcast // ignore: cast_nullable_to_non_nullable
+              as bool,
+      broadcastTargets: null == broadcastTargets
+          ? _self.broadcastTargets
+          : broadcastTargets // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      errors: null == errors
+          ? _self.errors
+          : errors // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      cancelled: null == cancelled
+          ? _self.cancelled
+          : cancelled // ignore: cast_nullable_to_non_nullable
+              as bool,
+      usedSweep: null == usedSweep
+          ? _self.usedSweep
+          : usedSweep // ignore: cast_nullable_to_non_nullable
+              as bool,
+      sweptHosts: null == sweptHosts
+          ? _self.sweptHosts
+          : sweptHosts // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [ScanDiagnostics].
+extension ScanDiagnosticsPatterns on ScanDiagnostics {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_ScanDiagnostics value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ScanDiagnostics() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_ScanDiagnostics value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanDiagnostics():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_ScanDiagnostics value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanDiagnostics() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(List<String> multicastInterfaces, bool usedBroadcast,
+            List<String> broadcastTargets, List<String> errors, bool cancelled,
+            bool usedSweep, int sweptHosts)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ScanDiagnostics() when $default != null:
+        return $default(_that.multicastInterfaces, _that.usedBroadcast,
+            _that.broadcastTargets, _that.errors, _that.cancelled,
+            _that.usedSweep, _that.sweptHosts);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(List<String> multicastInterfaces, bool usedBroadcast,
+            List<String> broadcastTargets, List<String> errors, bool cancelled,
+            bool usedSweep, int sweptHosts)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanDiagnostics():
+        return $default(_that.multicastInterfaces, _that.usedBroadcast,
+            _that.broadcastTargets, _that.errors, _that.cancelled,
+            _that.usedSweep, _that.sweptHosts);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(List<String> multicastInterfaces, bool usedBroadcast,
+            List<String> broadcastTargets, List<String> errors, bool cancelled,
+            bool usedSweep, int sweptHosts)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanDiagnostics() when $default != null:
+        return $default(_that.multicastInterfaces, _that.usedBroadcast,
+            _that.broadcastTargets, _that.errors, _that.cancelled,
+            _that.usedSweep, _that.sweptHosts);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _ScanDiagnostics extends ScanDiagnostics {
+  const _ScanDiagnostics(
+      {required final List<String> multicastInterfaces,
+      required this.usedBroadcast,
+      required final List<String> broadcastTargets,
+      required final List<String> errors,
+      required this.cancelled,
+      required this.usedSweep,
+      required this.sweptHosts})
+      : _multicastInterfaces = multicastInterfaces,
+        _broadcastTargets = broadcastTargets,
+        _errors = errors,
+        super._();
+
+  final List<String> _multicastInterfaces;
+  @override
+  List<String> get multicastInterfaces {
+    if (_multicastInterfaces is EqualUnmodifiableListView)
+      return _multicastInterfaces;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_multicastInterfaces);
+  }
+
+  @override
+  final bool usedBroadcast;
+  final List<String> _broadcastTargets;
+  @override
+  List<String> get broadcastTargets {
+    if (_broadcastTargets is EqualUnmodifiableListView)
+      return _broadcastTargets;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_broadcastTargets);
+  }
+
+  final List<String> _errors;
+  @override
+  List<String> get errors {
+    if (_errors is EqualUnmodifiableListView) return _errors;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_errors);
+  }
+
+  @override
+  final bool cancelled;
+  @override
+  final bool usedSweep;
+  @override
+  final int sweptHosts;
+
+  /// Create a copy of ScanDiagnostics
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$ScanDiagnosticsCopyWith<_ScanDiagnostics> get copyWith =>
+      __$ScanDiagnosticsCopyWithImpl<_ScanDiagnostics>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _ScanDiagnostics &&
+            const DeepCollectionEquality()
+                .equals(other._multicastInterfaces, _multicastInterfaces) &&
+            (identical(other.usedBroadcast, usedBroadcast) ||
+                other.usedBroadcast == usedBroadcast) &&
+            const DeepCollectionEquality()
+                .equals(other._broadcastTargets, _broadcastTargets) &&
+            const DeepCollectionEquality().equals(other._errors, _errors) &&
+            (identical(other.cancelled, cancelled) ||
+                other.cancelled == cancelled) &&
+            (identical(other.usedSweep, usedSweep) ||
+                other.usedSweep == usedSweep) &&
+            (identical(other.sweptHosts, sweptHosts) ||
+                other.sweptHosts == sweptHosts));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType,
+      const DeepCollectionEquality().hash(_multicastInterfaces), usedBroadcast,
+      const DeepCollectionEquality().hash(_broadcastTargets),
+      const DeepCollectionEquality().hash(_errors), cancelled, usedSweep,
+      sweptHosts);
+
+  @override
+  String toString() {
+    return 'ScanDiagnostics(multicastInterfaces: $multicastInterfaces, usedBroadcast: $usedBroadcast, broadcastTargets: $broadcastTargets, errors: $errors, cancelled: $cancelled, usedSweep: $usedSweep, sweptHosts: $sweptHosts)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$ScanDiagnosticsCopyWith<$Res>
+    implements $ScanDiagnosticsCopyWith<$Res> {
+  factory _$ScanDiagnosticsCopyWith(
+          _ScanDiagnostics value, $Res Function(_ScanDiagnostics) _then) =
+      __$ScanDiagnosticsCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {List<String> multicastInterfaces,
+      bool usedBroadcast,
+      List<String> broadcastTargets,
+      List<String> errors,
+      bool cancelled,
+      bool usedSweep,
+      int sweptHosts});
+}
+
+/// @nodoc
+class __$ScanDiagnosticsCopyWithImpl<$Res>
+    implements _$ScanDiagnosticsCopyWith<$Res> {
+  __$ScanDiagnosticsCopyWithImpl(this._self, this._then);
+
+  final _ScanDiagnostics _self;
+  final $Res Function(_ScanDiagnostics) _then;
+
+  /// Create a copy of ScanDiagnostics
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? multicastInterfaces = null,
+    Object? usedBroadcast = null,
+    Object? broadcastTargets = null,
+    Object? errors = null,
+    Object? cancelled = null,
+    Object? usedSweep = null,
+    Object? sweptHosts = null,
+  }) {
+    return _then(_ScanDiagnostics(
+      multicastInterfaces: null == multicastInterfaces
+          ? _self._multicastInterfaces
+          : multicastInterfaces // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      usedBroadcast: null == usedBroadcast
+          ? _self.usedBroadcast
+          : usedBroadcast // ignore: cast_nullable_to_non_nullable
+              as bool,
+      broadcastTargets: null == broadcastTargets
+          ? _self._broadcastTargets
+          : broadcastTargets // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      errors: null == errors
+          ? _self._errors
+          : errors // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      cancelled: null == cancelled
+          ? _self.cancelled
+          : cancelled // ignore: cast_nullable_to_non_nullable
+              as bool,
+      usedSweep: null == usedSweep
+          ? _self.usedSweep
+          : usedSweep // ignore: cast_nullable_to_non_nullable
+              as bool,
+      sweptHosts: null == sweptHosts
+          ? _self.sweptHosts
+          : sweptHosts // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$ScanEvent {
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is ScanEvent);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  String toString() {
+    return 'ScanEvent()';
+  }
+}
+
+/// @nodoc
+class $ScanEventCopyWith<$Res> {
+  $ScanEventCopyWith(ScanEvent _, $Res Function(ScanEvent) __);
+}
+
+/// Adds pattern-matching-related methods to [ScanEvent].
+extension ScanEventPatterns on ScanEvent {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_DeviceFound value)? deviceFound,
+    TResult Function(ScanEvent_Completed value)? completed,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ScanEvent_DeviceFound() when deviceFound != null:
+        return deviceFound(_that);
+      case ScanEvent_Completed() when completed != null:
+        return completed(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_DeviceFound value) deviceFound,
+    required TResult Function(ScanEvent_Completed value) completed,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ScanEvent_DeviceFound():
+        return deviceFound(_that);
+      case ScanEvent_Completed():
+        return completed(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_DeviceFound value)? deviceFound,
+    TResult? Function(ScanEvent_Completed value)? completed,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ScanEvent_DeviceFound() when deviceFound != null:
+        return deviceFound(_that);
+      case ScanEvent_Completed() when completed != null:
+        return completed(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ProjectorInfo field0)? deviceFound,
+    TResult Function(int total, ScanDiagnostics diagnostics)? completed,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ScanEvent_DeviceFound() when deviceFound != null:
+        return deviceFound(_that.field0);
+      case ScanEvent_Completed() when completed != null:
+        return completed(_that.total, _that.diagnostics);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ProjectorInfo field0) deviceFound,
+    required TResult Function(int total, ScanDiagnostics diagnostics) completed,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ScanEvent_DeviceFound():
+        return deviceFound(_that.field0);
+      case ScanEvent_Completed():
+        return completed(_that.total, _that.diagnostics);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ProjectorInfo field0)? deviceFound,
+    TResult? Function(int total, ScanDiagnostics diagnostics)? completed,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ScanEvent_DeviceFound() when deviceFound != null:
+        return deviceFound(_that.field0);
+      case ScanEvent_Completed() when completed != null:
+        return completed(_that.total, _that.diagnostics);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class ScanEvent_DeviceFound extends ScanEvent {
+  const ScanEvent_DeviceFound(this.field0) : super._();
+
+  final ProjectorInfo field0;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ScanEvent_DeviceFoundCopyWith<ScanEvent_DeviceFound> get copyWith =>
+      _$ScanEvent_DeviceFoundCopyWithImpl<ScanEvent_DeviceFound>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ScanEvent_DeviceFound &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'ScanEvent.deviceFound(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ScanEvent_DeviceFoundCopyWith<$Res>
+    implements $ScanEventCopyWith<$Res> {
+  factory $ScanEvent_DeviceFoundCopyWith(
+          ScanEvent_DeviceFound value, $Res Function(ScanEvent_DeviceFound) _then) =
+      _$ScanEvent_DeviceFoundCopyWithImpl;
+  @useResult
+  $Res call({ProjectorInfo field0});
+
+  $ProjectorInfoCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class _$ScanEvent_DeviceFoundCopyWithImpl<$Res>
+    implements $ScanEvent_DeviceFoundCopyWith<$Res> {
+  _$ScanEvent_DeviceFoundCopyWithImpl(this._self, this._then);
+
+  final ScanEvent_DeviceFound _self;
+  final $Res Function(ScanEvent_DeviceFound) _then;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(ScanEvent_DeviceFound(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+    ));
+  }
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get field0 {
+    return $ProjectorInfoCopyWith<$Res>(_self.field0, (value) {
+      return _then(_self.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class ScanEvent_Completed extends ScanEvent {
+  const ScanEvent_Completed({required this.total, required this.diagnostics})
+      : super._();
+
+  final int total;
+  final ScanDiagnostics diagnostics;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ScanEvent_CompletedCopyWith<ScanEvent_Completed> get copyWith =>
+      _$ScanEvent_CompletedCopyWithImpl<ScanEvent_Completed>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ScanEvent_Completed &&
+            (identical(other.total, total) || other.total == total) &&
+            (identical(other.diagnostics, diagnostics) ||
+                other.diagnostics == diagnostics));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, total, diagnostics);
+
+  @override
+  String toString() {
+    return 'ScanEvent.completed(total: $total, diagnostics: $diagnostics)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ScanEvent_CompletedCopyWith<$Res>
+    implements $ScanEventCopyWith<$Res> {
+  factory $ScanEvent_CompletedCopyWith(
+          ScanEvent_Completed value, $Res Function(ScanEvent_Completed) _then) =
+      _$ScanEvent_CompletedCopyWithImpl;
+  @useResult
+  $Res call({int total, ScanDiagnostics diagnostics});
+
+  $ScanDiagnosticsCopyWith<$Res> get diagnostics;
+}
+
+/// @nodoc
+class _$ScanEvent_CompletedCopyWithImpl<$Res>
+    implements $ScanEvent_CompletedCopyWith<$Res> {
+  _$ScanEvent_CompletedCopyWithImpl(this._self, this._then);
+
+  final ScanEvent_Completed _self;
+  final $Res Function(ScanEvent_Completed) _then;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? total = null,
+    Object? diagnostics = null,
+  }) {
+    return _then(ScanEvent_Completed(
+      total: null == total
+          ? _self.total
+          : total // ignore: cast_nullable_to_non_nullable
+              as int,
+      diagnostics: null == diagnostics
+          ? _self.diagnostics
+          : diagnostics // ignore: cast_nullable_to_non_nullable
+              as ScanDiagnostics,
+    ));
+  }
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ScanDiagnosticsCopyWith<$Res> get diagnostics {
+    return $ScanDiagnosticsCopyWith<$Res>(_self.diagnostics, (value) {
+      return _then(_self.copyWith(diagnostics: value));
+    });
+  }
+}
+
+/// @nodoc
+mixin _$ScanOptions {
+  BigInt get timeoutSecs;
+  List<String> get searchTargets;
+  List<String> get interfaces;
+  bool get enableIpv6;
+  bool get renderersOnly;
+  int get sendCount;
+  BigInt get sendIntervalMs;
+  int? get mxSecs;
+  int? get multicastTtl;
+  bool get subnetSweep;
+  int? get sweepConcurrency;
+  int? get sweepRatePerSec;
+
+  /// Create a copy of ScanOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ScanOptionsCopyWith<ScanOptions> get copyWith =>
+      _$ScanOptionsCopyWithImpl<ScanOptions>(this as ScanOptions, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ScanOptions &&
+            (identical(other.timeoutSecs, timeoutSecs) ||
+                other.timeoutSecs == timeoutSecs) &&
+            const DeepCollectionEquality()
+                .equals(other.searchTargets, searchTargets) &&
+            const DeepCollectionEquality()
+                .equals(other.interfaces, interfaces) &&
+            (identical(other.enableIpv6, enableIpv6) ||
+                other.enableIpv6 == enableIpv6) &&
+            (identical(other.renderersOnly, renderersOnly) ||
+                other.renderersOnly == renderersOnly) &&
+            (identical(other.sendCount, sendCount) ||
+                other.sendCount == sendCount) &&
+            (identical(other.sendIntervalMs, sendIntervalMs) ||
+                other.sendIntervalMs == sendIntervalMs) &&
+            (identical(other.mxSecs, mxSecs) || other.mxSecs == mxSecs) &&
+            (identical(other.multicastTtl, multicastTtl) ||
+                other.multicastTtl == multicastTtl) &&
+            (identical(other.subnetSweep, subnetSweep) ||
+                other.subnetSweep == subnetSweep) &&
+            (identical(other.sweepConcurrency, sweepConcurrency) ||
+                other.sweepConcurrency == sweepConcurrency) &&
+            (identical(other.sweepRatePerSec, sweepRatePerSec) ||
+                other.sweepRatePerSec == sweepRatePerSec));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, timeoutSecs,
+      const DeepCollectionEquality().hash(searchTargets),
+      const DeepCollectionEquality().hash(interfaces), enableIpv6,
+      renderersOnly, sendCount, sendIntervalMs, mxSecs, multicastTtl,
+      subnetSweep, sweepConcurrency, sweepRatePerSec);
+
+  @override
+  String toString() {
+    return 'ScanOptions(timeoutSecs: $timeoutSecs, searchTargets: $searchTargets, interfaces: $interfaces, enableIpv6: $enableIpv6, renderersOnly: $renderersOnly, sendCount: $sendCount, sendIntervalMs: $sendIntervalMs, mxSecs: $mxSecs, multicastTtl: $multicastTtl, subnetSweep: $subnetSweep, sweepConcurrency: $sweepConcurrency, sweepRatePerSec: $sweepRatePerSec)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ScanOptionsCopyWith<$Res> {
+  factory $ScanOptionsCopyWith(
+          ScanOptions value, $Res Function(ScanOptions) _then) =
+      _$ScanOptionsCopyWithImpl;
+  @useResult
+  $Res call(
+      {BigInt timeoutSecs,
+      List<String> searchTargets,
+      List<String> interfaces,
+      bool enableIpv6,
+      bool renderersOnly,
+      int sendCount,
+      BigInt sendIntervalMs,
+      int? mxSecs,
+      int? multicastTtl,
+      bool subnetSweep,
+      int? sweepConcurrency,
+      int? sweepRatePerSec});
+}
+
+/// @nodoc
+class _$ScanOptionsCopyWithImpl<$Res> implements $ScanOptionsCopyWith<$Res> {
+  _$ScanOptionsCopyWithImpl(this._self, this._then);
+
+  final ScanOptions _self;
+  final $Res Function(ScanOptions) _then;
+
+  /// Create a copy of ScanOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? timeoutSecs = null,
+    Object? searchTargets = null,
+    Object? interfaces = null,
+    Object? enableIpv6 = null,
+    Object? renderersOnly = null,
+    Object? sendCount = null,
+    Object? sendIntervalMs = null,
+    Object? mxSecs = freezed,
+    Object? multicastTtl = freezed,
+    Object? subnetSweep = null,
+    Object? sweepConcurrency = freezed,
+    Object? sweepRatePerSec = freezed,
+  }) {
+    return _then(_self.copyWith(
+      timeoutSecs: null == timeoutSecs
+          ? _self.timeoutSecs
+          : timeoutSecs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      searchTargets: null == searchTargets
+          ? _self.searchTargets
+          : searchTargets // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      interfaces: null == interfaces
+          ? _self.interfaces
+          : interfaces // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      enableIpv6: null == enableIpv6
+          ? _self.enableIpv6
+          : enableIpv6 // ignore: cast_nullable_to_non_nullable
+              as bool,
+      renderersOnly: null == renderersOnly
+          ? _self.renderersOnly
+          : renderersOnly // ignore: cast_nullable_to_non_nullable
+              as bool,
+      sendCount: null == sendCount
+          ? _self.sendCount
+          : sendCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      sendIntervalMs: null == sendIntervalMs
+          ? _self.sendIntervalMs
+          : sendIntervalMs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      mxSecs: freezed == mxSecs
+          ? _self.mxSecs
+          : mxSecs // ignore: cast_nullable_to_non_nullable
+              as int?,
+      multicastTtl: freezed == multicastTtl
+          ? _self.multicastTtl
+          : multicastTtl // ignore: cast_nullable_to_non_nullable
+              as int?,
+      subnetSweep: null == subnetSweep
+          ? _self.subnetSweep
+          : subnetSweep // ignore: cast_nullable_to_non_nullable
+              as bool,
+      sweepConcurrency: freezed == sweepConcurrency
+          ? _self.sweepConcurrency
+          : sweepConcurrency // ignore: cast_nullable_to_non_nullable
+              as int?,
+      sweepRatePerSec: freezed == sweepRatePerSec
+          ? _self.sweepRatePerSec
+          : sweepRatePerSec // ignore: cast_nullable_to_non_nullable
+              as int?,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [ScanOptions].
+extension ScanOptionsPatterns on ScanOptions {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_ScanOptions value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ScanOptions() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_ScanOptions value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanOptions():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_ScanOptions value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanOptions() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(BigInt timeoutSecs, List<String> searchTargets,
+            List<String> interfaces, bool enableIpv6, bool renderersOnly,
+            int sendCount, BigInt sendIntervalMs, int? mxSecs,
+            int? multicastTtl, bool subnetSweep, int? sweepConcurrency,
+            int? sweepRatePerSec)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ScanOptions() when $default != null:
+        return $default(_that.timeoutSecs, _that.searchTargets,
+            _that.interfaces, _that.enableIpv6, _that.renderersOnly,
+            _that.sendCount, _that.sendIntervalMs, _that.mxSecs,
+            _that.multicastTtl, _that.subnetSweep, _that.sweepConcurrency,
+            _that.sweepRatePerSec);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(BigInt timeoutSecs, List<String> searchTargets,
+            List<String> interfaces, bool enableIpv6, bool renderersOnly,
+            int sendCount, BigInt sendIntervalMs, int? mxSecs,
+            int? multicastTtl, bool subnetSweep, int? sweepConcurrency,
+            int? sweepRatePerSec)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanOptions():
+        return $default(_that.timeoutSecs, _that.searchTargets,
+            _that.interfaces, _that.enableIpv6, _that.renderersOnly,
+            _that.sendCount, _that.sendIntervalMs, _that.mxSecs,
+            _that.multicastTtl, _that.subnetSweep, _that.sweepConcurrency,
+            _that.sweepRatePerSec);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(BigInt timeoutSecs, List<String> searchTargets,
+            List<String> interfaces, bool enableIpv6, bool renderersOnly,
+            int sendCount, BigInt sendIntervalMs, int? mxSecs,
+            int? multicastTtl, bool subnetSweep, int? sweepConcurrency,
+            int? sweepRatePerSec)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanOptions() when $default != null:
+        return $default(_that.timeoutSecs, _that.searchTargets,
+            _that.interfaces, _that.enableIpv6, _that.renderersOnly,
+            _that.sendCount, _that.sendIntervalMs, _that.mxSecs,
+            _that.multicastTtl, _that.subnetSweep, _that.sweepConcurrency,
+            _that.sweepRatePerSec);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _ScanOptions extends ScanOptions {
+  const _ScanOptions(
+      {required this.timeoutSecs,
+      required final List<String> searchTargets,
+      required final List<String> interfaces,
+      required this.enableIpv6,
+      required this.renderersOnly,
+      required this.sendCount,
+      required this.sendIntervalMs,
+      this.mxSecs,
+      this.multicastTtl,
+      required this.subnetSweep,
+      this.sweepConcurrency,
+      this.sweepRatePerSec})
+      : _searchTargets = searchTargets,
+        _interfaces = interfaces,
+        super._();
+
+  @override
+  final BigInt timeoutSecs;
+  final List<String> _searchTargets;
+  @override
+  List<String> get searchTargets {
+    if (_searchTargets is EqualUnmodifiableListView) return _searchTargets;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_searchTargets);
+  }
+
+  final List<String> _interfaces;
+  @override
+  List<String> get interfaces {
+    if (_interfaces is EqualUnmodifiableListView) return _interfaces;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_interfaces);
+  }
+
+  @override
+  final bool enableIpv6;
+  @override
+  final bool renderersOnly;
+  @override
+  final int sendCount;
+  @override
+  final BigInt sendIntervalMs;
+  @override
+  final int? mxSecs;
+  @override
+  final int? multicastTtl;
+  @override
+  final bool subnetSweep;
+  @override
+  final int? sweepConcurrency;
+  @override
+  final int? sweepRatePerSec;
+
+  /// Create a copy of ScanOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$ScanOptionsCopyWith<_ScanOptions> get copyWith =>
+      __$ScanOptionsCopyWithImpl<_ScanOptions>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _ScanOptions &&
+            (identical(other.timeoutSecs, timeoutSecs) ||
+                other.timeoutSecs == timeoutSecs) &&
+            const DeepCollectionEquality()
+                .equals(other._searchTargets, _searchTargets) &&
+            const DeepCollectionEquality()
+                .equals(other._interfaces, _interfaces) &&
+            (identical(other.enableIpv6, enableIpv6) ||
+                other.enableIpv6 == enableIpv6) &&
+            (identical(other.renderersOnly, renderersOnly) ||
+                other.renderersOnly == renderersOnly) &&
+            (identical(other.sendCount, sendCount) ||
+                other.sendCount == sendCount) &&
+            (identical(other.sendIntervalMs, sendIntervalMs) ||
+                other.sendIntervalMs == sendIntervalMs) &&
+            (identical(other.mxSecs, mxSecs) || other.mxSecs == mxSecs) &&
+            (identical(other.multicastTtl, multicastTtl) ||
+                other.multicastTtl == multicastTtl) &&
+            (identical(other.subnetSweep, subnetSweep) ||
+                other.subnetSweep == subnetSweep) &&
+            (identical(other.sweepConcurrency, sweepConcurrency) ||
+                other.sweepConcurrency == sweepConcurrency) &&
+            (identical(other.sweepRatePerSec, sweepRatePerSec) ||
+                other.sweepRatePerSec == sweepRatePerSec));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, timeoutSecs,
+      const DeepCollectionEquality().hash(_searchTargets),
+      const DeepCollectionEquality().hash(_interfaces), enableIpv6,
+      renderersOnly, sendCount, sendIntervalMs, mxSecs, multicastTtl,
+      subnetSweep, sweepConcurrency, sweepRatePerSec);
+
+  @override
+  String toString() {
+    return 'ScanOptions(timeoutSecs: $timeoutSecs, searchTargets: $searchTargets, interfaces: $interfaces, enableIpv6: $enableIpv6, renderersOnly: $renderersOnly, sendCount: $sendCount, sendIntervalMs: $sendIntervalMs, mxSecs: $mxSecs, multicastTtl: $multicastTtl, subnetSweep: $subnetSweep, sweepConcurrency: $sweepConcurrency, sweepRatePerSec: $sweepRatePerSec)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$ScanOptionsCopyWith<$Res>
+    implements $ScanOptionsCopyWith<$Res> {
+  factory _$ScanOptionsCopyWith(
+          _ScanOptions value, $Res Function(_ScanOptions) _then) =
+      __$ScanOptionsCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {BigInt timeoutSecs,
+      List<String> searchTargets,
+      List<String> interfaces,
+      bool enableIpv6,
+      bool renderersOnly,
+      int sendCount,
+      BigInt sendIntervalMs,
+      int? mxSecs,
+      int? multicastTtl,
+      bool subnetSweep,
+      int? sweepConcurrency,
+      int? sweepRatePerSec});
+}
+
+/// @nodoc
+class __$ScanOptionsCopyWithImpl<$Res> implements _$ScanOptionsCopyWith<$Res> {
+  __$ScanOptionsCopyWithImpl(this._self, this._then);
+
+  final _ScanOptions _self;
+  final $Res Function(_ScanOptions) _then;
+
+  /// Create a copy of ScanOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? timeoutSecs = null,
+    Object? searchTargets = null,
+    Object? interfaces = null,
+    Object? enableIpv6 = null,
+    Object? renderersOnly = null,
+    Object? sendCount = null,
+    Object? sendIntervalMs = null,
+    Object? mxSecs = freezed,
+    Object? multicastTtl = freezed,
+    Object? subnetSweep = null,
+    Object? sweepConcurrency = freezed,
+    Object? sweepRatePerSec = freezed,
+  }) {
+    return _then(_ScanOptions(
+      timeoutSecs: null == timeoutSecs
+          ? _self.timeoutSecs
+          : timeoutSecs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      searchTargets: null == searchTargets
+          ? _self._searchTargets
+          : searchTargets // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      interfaces: null == interfaces
+          ? _self._interfaces
+          : interfaces // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      enableIpv6: null == enableIpv6
+          ? _self.enableIpv6
+          : enableIpv6 // ignore: cast_nullable_to_non_nullable
+              as bool,
+      renderersOnly: null == renderersOnly
+          ? _self.renderersOnly
+          : renderersOnly // ignore: cast_nullable_to_non_nullable
+              as bool,
+      sendCount: null == sendCount
+          ? _self.sendCount
+          : sendCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      sendIntervalMs: null == sendIntervalMs
+          ? _self.sendIntervalMs
+          : sendIntervalMs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      mxSecs: freezed == mxSecs
+          ? _self.mxSecs
+          : mxSecs // ignore: cast_nullable_to_non_nullable
+              as int?,
+      multicastTtl: freezed == multicastTtl
+          ? _self.multicastTtl
+          : multicastTtl // ignore: cast_nullable_to_non_nullable
+              as int?,
+      subnetSweep: null == subnetSweep
+          ? _self.subnetSweep
+          : subnetSweep // ignore: cast_nullable_to_non_nullable
+              as bool,
+      sweepConcurrency: freezed == sweepConcurrency
+          ? _self.sweepConcurrency
+          : sweepConcurrency // ignore: cast_nullable_to_non_nullable
+              as int?,
+      sweepRatePerSec: freezed == sweepRatePerSec
+          ? _self.sweepRatePerSec
+          : sweepRatePerSec // ignore: cast_nullable_to_non_nullable
+              as int?,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$ScanReport {
+  List<ProjectorInfo> get devices;
+  ScanDiagnostics get diagnostics;
+
+  /// Create a copy of ScanReport
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ScanReportCopyWith<ScanReport> get copyWith =>
+      _$ScanReportCopyWithImpl<ScanReport>(this as ScanReport, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ScanReport &&
+            const DeepCollectionEquality().equals(other.devices, devices) &&
+            (identical(other.diagnostics, diagnostics) ||
+                other.diagnostics == diagnostics));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType,
+      const DeepCollectionEquality().hash(devices), diagnostics);
+
+  @override
+  String toString() {
+    return 'ScanReport(devices: $devices, diagnostics: $diagnostics)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ScanReportCopyWith<$Res> {
+  factory $ScanReportCopyWith(
+          ScanReport value, $Res Function(ScanReport) _then) =
+      _$ScanReportCopyWithImpl;
+  @useResult
+  $Res call({List<ProjectorInfo> devices, ScanDiagnostics diagnostics});
+
+  $ScanDiagnosticsCopyWith<$Res> get diagnostics;
+}
+
+/// @nodoc
+class _$ScanReportCopyWithImpl<$Res> implements $ScanReportCopyWith<$Res> {
+  _$ScanReportCopyWithImpl(this._self, this._then);
+
+  final ScanReport _self;
+  final $Res Function(ScanReport) _then;
+
+  /// Create a copy of ScanReport
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? devices = null,
+    Object? diagnostics = null,
+  }) {
+    return _then(_self.copyWith(
+      devices: null == devices
+          ? _self.devices
+          : devices // ignore: cast_nullable_to_non_nullable
+              as List<ProjectorInfo>,
+      diagnostics: null == diagnostics
+          ? _self.diagnostics
+          : diagnostics // ignore: cast_nullable_to_non_nullable
+              as ScanDiagnostics,
+    ));
+  }
+
+  /// Create a copy of ScanReport
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ScanDiagnosticsCopyWith<$Res> get diagnostics {
+    return $ScanDiagnosticsCopyWith<$Res>(_self.diagnostics, (value) {
+      return _then(_self.copyWith(diagnostics: value));
+    });
+  }
+}
+
+/// Adds pattern-matching-related methods to [ScanReport].
+extension ScanReportPatterns on ScanReport {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_ScanReport value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ScanReport() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_ScanReport value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanReport():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_ScanReport value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanReport() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(List<ProjectorInfo> devices, ScanDiagnostics diagnostics)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ScanReport() when $default != null:
+        return $default(_that.devices, _that.diagnostics);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(List<ProjectorInfo> devices, ScanDiagnostics diagnostics)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanReport():
+        return $default(_that.devices, _that.diagnostics);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(List<ProjectorInfo> devices, ScanDiagnostics diagnostics)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ScanReport() when $default != null:
+        return $default(_that.devices, _that.diagnostics);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _ScanReport implements ScanReport {
+  const _ScanReport(
+      {required final List<ProjectorInfo> devices, required this.diagnostics})
+      : _devices = devices;
+
+  final List<ProjectorInfo> _devices;
+  @override
+  List<ProjectorInfo> get devices {
+    if (_devices is EqualUnmodifiableListView) return _devices;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_devices);
+  }
+
+  @override
+  final ScanDiagnostics diagnostics;
+
+  /// Create a copy of ScanReport
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$ScanReportCopyWith<_ScanReport> get copyWith =>
+      __$ScanReportCopyWithImpl<_ScanReport>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _ScanReport &&
+            const DeepCollectionEquality().equals(other._devices, _devices) &&
+            (identical(other.diagnostics, diagnostics) ||
+                other.diagnostics == diagnostics));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType,
+      const DeepCollectionEquality().hash(_devices), diagnostics);
+
+  @override
+  String toString() {
+    return 'ScanReport(devices: $devices, diagnostics: $diagnostics)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$ScanReportCopyWith<$Res>
+    implements $ScanReportCopyWith<$Res> {
+  factory _$ScanReportCopyWith(
+          _ScanReport value, $Res Function(_ScanReport) _then) =
+      __$ScanReportCopyWithImpl;
+  @override
+  @useResult
+  $Res call({List<ProjectorInfo> devices, ScanDiagnostics diagnostics});
+
+  @override
+  $ScanDiagnosticsCopyWith<$Res> get diagnostics;
+}
+
+/// @nodoc
+class __$ScanReportCopyWithImpl<$Res> implements _$ScanReportCopyWith<$Res> {
+  __$ScanReportCopyWithImpl(this._self, this._then);
+
+  final _ScanReport _self;
+  final $Res Function(_ScanReport) _then;
+
+  /// Create a copy of ScanReport
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? devices = null,
+    Object? diagnostics = null,
+  }) {
+    return _then(_ScanReport(
+      devices: null == devices
+          ? _self._devices
+          : devices // ignore: cast_nullable_to_non_nullable
+              as List<ProjectorInfo>,
+      diagnostics: null == diagnostics
+          ? _self.diagnostics
+          : diagnostics // ignore: cast_nullable_to_non_nullable
+              as ScanDiagnostics,
+    ));
+  }
+
+  /// Create a copy of ScanReport
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ScanDiagnosticsCopyWith<$Res> get diagnostics {
+    return $ScanDiagnosticsCopyWith<$Res>(_self.diagnostics, (value) {
+      return _then(_self.copyWith(diagnostics: value));
+    });
+  }
+}
+
+/// @nodoc
+mixin _$WakeOptions {
+  String? get targetAddress;
+  int get port;
+  bool get directedBroadcast;
+  int get repeatCount;
+  BigInt get repeatIntervalMs;
+  String? get secureOnPassword;
+
+  /// Create a copy of WakeOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $WakeOptionsCopyWith<WakeOptions> get copyWith =>
+      _$WakeOptionsCopyWithImpl<WakeOptions>(this as WakeOptions, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is WakeOptions &&
+            (identical(other.targetAddress, targetAddress) ||
+                other.targetAddress == targetAddress) &&
+            (identical(other.port, port) || other.port == port) &&
+            (identical(other.directedBroadcast, directedBroadcast) ||
+                other.directedBroadcast == directedBroadcast) &&
+            (identical(other.repeatCount, repeatCount) ||
+                other.repeatCount == repeatCount) &&
+            (identical(other.repeatIntervalMs, repeatIntervalMs) ||
+                other.repeatIntervalMs == repeatIntervalMs) &&
+            (identical(other.secureOnPassword, secureOnPassword) ||
+                other.secureOnPassword == secureOnPassword));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, targetAddress, port,
+      directedBroadcast, repeatCount, repeatIntervalMs, secureOnPassword);
+
+  @override
+  String toString() {
+    return 'WakeOptions(targetAddress: $targetAddress, port: $port, directedBroadcast: $directedBroadcast, repeatCount: $repeatCount, repeatIntervalMs: $repeatIntervalMs, secureOnPassword: $secureOnPassword)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $WakeOptionsCopyWith<$Res> {
+  factory $WakeOptionsCopyWith(
+          WakeOptions value, $Res Function(WakeOptions) _then) =
+      _$WakeOptionsCopyWithImpl;
+  @useResult
+  $Res call(
+      {String? targetAddress,
+      int port,
+      bool directedBroadcast,
+      int repeatCount,
+      BigInt repeatIntervalMs,
+      String? secureOnPassword});
+}
+
+/// @nodoc
+class _$WakeOptionsCopyWithImpl<$Res> implements $WakeOptionsCopyWith<$Res> {
+  _$WakeOptionsCopyWithImpl(this._self, this._then);
+
+  final WakeOptions _self;
+  final $Res Function(WakeOptions) _then;
+
+  /// Create a copy of WakeOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? targetAddress = freezed,
+    Object? port = null,
+    Object? directedBroadcast = null,
+    Object? repeatCount = null,
+    Object? repeatIntervalMs = null,
+    Object? secureOnPassword = freezed,
+  }) {
+    return _then(_self.copyWith(
+      targetAddress: freezed == targetAddress
+          ? _self.targetAddress
+          : targetAddress // ignore: cast_nullable_to_non_nullable
+              as String?,
+      port: null == port
+          ? _self.port
+          : port // ignore: cast_nullable_to_non_nullable
+              as int,
+      directedBroadcast: null == directedBroadcast
+          ? _self.directedBroadcast
+          : directedBroadcast // ignore: cast_nullable_to_non_nullable
+              as bool,
+      repeatCount: null == repeatCount
+          ? _self.repeatCount
+          : repeatCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      repeatIntervalMs: null == repeatIntervalMs
+          ? _self.repeatIntervalMs
+          : repeatIntervalMs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      secureOnPassword: freezed == secureOnPassword
+          ? _self.secureOnPassword
+          : secureOnPassword // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [WakeOptions].
+extension WakeOptionsPatterns on WakeOptions {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_WakeOptions value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _WakeOptions() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_WakeOptions value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _WakeOptions():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_WakeOptions value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _WakeOptions() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String? targetAddress, int port, bool directedBroadcast,
+            int repeatCount, BigInt repeatIntervalMs, String? secureOnPassword)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _WakeOptions() when $default != null:
+        return $default(_that.targetAddress, _that.port,
+            _that.directedBroadcast, _that.repeatCount, _that.repeatIntervalMs,
+            _that.secureOnPassword);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String? targetAddress, int port, bool directedBroadcast,
+            int repeatCount, BigInt repeatIntervalMs, String? secureOnPassword)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _WakeOptions():
+        return $default(_that.targetAddress, _that.port,
+            _that.directedBroadcast, _that.repeatCount, _that.repeatIntervalMs,
+            _that.secureOnPassword);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String? targetAddress, int port, bool directedBroadcast,
+            int repeatCount, BigInt repeatIntervalMs, String? secureOnPassword)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _WakeOptions() when $default != null:
+        return $default(_that.targetAddress, _that.port,
+            _that.directedBroadcast, _that.repeatCount, _that.repeatIntervalMs,
+            _that.secureOnPassword);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _WakeOptions implements WakeOptions {
+  const _WakeOptions(
+      {this.targetAddress,
+      required this.port,
+      required this.directedBroadcast,
+      required this.repeatCount,
+      required this.repeatIntervalMs,
+      this.secureOnPassword});
+
+  @override
+  final String? targetAddress;
+  @override
+  final int port;
+  @override
+  final bool directedBroadcast;
+  @override
+  final int repeatCount;
+  @override
+  final BigInt repeatIntervalMs;
+  @override
+  final String? secureOnPassword;
+
+  /// Create a copy of WakeOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$WakeOptionsCopyWith<_WakeOptions> get copyWith =>
+      __$WakeOptionsCopyWithImpl<_WakeOptions>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _WakeOptions &&
+            (identical(other.targetAddress, targetAddress) ||
+                other.targetAddress == targetAddress) &&
+            (identical(other.port, port) || other.port == port) &&
+            (identical(other.directedBroadcast, directedBroadcast) ||
+                other.directedBroadcast == directedBroadcast) &&
+            (identical(other.repeatCount, repeatCount) ||
+                other.repeatCount == repeatCount) &&
+            (identical(other.repeatIntervalMs, repeatIntervalMs) ||
+                other.repeatIntervalMs == repeatIntervalMs) &&
+            (identical(other.secureOnPassword, secureOnPassword) ||
+                other.secureOnPassword == secureOnPassword));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, targetAddress, port,
+      directedBroadcast, repeatCount, repeatIntervalMs, secureOnPassword);
+
+  @override
+  String toString() {
+    return 'WakeOptions(targetAddress: $targetAddress, port: $port, directedBroadcast: $directedBroadcast, repeatCount: $repeatCount, repeatIntervalMs: $repeatIntervalMs, secureOnPassword: $secureOnPassword)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$WakeOptionsCopyWith<$Res>
+    implements $WakeOptionsCopyWith<$Res> {
+  factory _$WakeOptionsCopyWith(
+          _WakeOptions value, $Res Function(_WakeOptions) _then) =
+      __$WakeOptionsCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {String? targetAddress,
+      int port,
+      bool directedBroadcast,
+      int repeatCount,
+      BigInt repeatIntervalMs,
+      String? secureOnPassword});
+}
+
+/// @nodoc
+class __$WakeOptionsCopyWithImpl<$Res> implements _$WakeOptionsCopyWith<$Res> {
+  __$WakeOptionsCopyWithImpl(this._self, this._then);
+
+  final _WakeOptions _self;
+  final $Res Function(_WakeOptions) _then;
+
+  /// Create a copy of WakeOptions
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? targetAddress = freezed,
+    Object? port = null,
+    Object? directedBroadcast = null,
+    Object? repeatCount = null,
+    Object? repeatIntervalMs = null,
+    Object? secureOnPassword = freezed,
+  }) {
+    return _then(_WakeOptions(
+      targetAddress: freezed == targetAddress
+          ? _self.targetAddress
+          : targetAddress // ignore: cast_nullable_to_non_nullable
+              as String?,
+      port: null == port
+          ? _self.port
+          : port // ignore: cast_nullable_to_non_nullable
+              as int,
+      directedBroadcast: null == directedBroadcast
+          ? _self.directedBroadcast
+          : directedBroadcast // ignore: cast_nullable_to_non_nullable
+              as bool,
+      repeatCount: null == repeatCount
+          ? _self.repeatCount
+          : repeatCount // ignore: cast_nullable_to_non_nullable
+              as int,
+      repeatIntervalMs: null == repeatIntervalMs
+          ? _self.repeatIntervalMs
+          : repeatIntervalMs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      secureOnPassword: freezed == secureOnPassword
+          ? _self.secureOnPassword
+          : secureOnPassword // ignore: cast_nullable_to_non_nullable
+              as String?,
     ));
   }
 }
@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'description.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `all_device_types`, `all_services`, `all_udns`, `capabilities`, `device_type_is`, `find_service`, `kind`, `list_items`, `parse_description`, `parse_device`, `parse_tree`, `resolve_url`, `resolve_urls`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 设备能力
+@freezed
+sealed class DeviceCapabilities with _$DeviceCapabilities {
+  const DeviceCapabilities._();
+  const factory DeviceCapabilities({
+    required bool canPlay,
+    required bool canControlVolume,
+    required bool canSubscribe,
+  }) = _DeviceCapabilities;
+
+  static Future<DeviceCapabilities> default_() =>
+      RustLib.instance.api.crateApiDescriptionDeviceCapabilitiesDefault();
+}
+
+/// UPnP 设备描述文件 (description.xml) 中的设备信息
+@freezed
+sealed class DeviceDescription with _$DeviceDescription {
+  const factory DeviceDescription({
+    required String deviceType,
+    required String friendlyName,
+    required String manufacturer,
+    String? manufacturerUrl,
+    String? modelDescription,
+    required String modelName,
+    String? modelNumber,
+    String? modelUrl,
+    String? serialNumber,
+    required String udn,
+    String? presentationUrl,
+    String? macAddress,
+    required List<DeviceIcon> icons,
+    required List<ServiceInfo> services,
+    required List<DeviceDescription> devices,
+  }) = _DeviceDescription;
+}
+
+@freezed
+sealed class DeviceIcon with _$DeviceIcon {
+  const factory DeviceIcon({
+    required String mimeType,
+    required int width,
+    required int height,
+    required int depth,
+    required String url,
+  }) = _DeviceIcon;
+}
+
+/// 按 deviceType 和服务列表推断的设备类别
+enum DeviceKind {
+  mediaRenderer,
+  mediaServer,
+  internetGatewayDevice,
+  other,
+  ;
+}
+
+@freezed
+sealed class ServiceInfo with _$ServiceInfo {
+  const factory ServiceInfo({
+    required String serviceType,
+    required String serviceId,
+    required String scpdUrl,
+    required String controlUrl,
+    required String eventSubUrl,
+  }) = _ServiceInfo;
+}
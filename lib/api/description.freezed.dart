@@ -0,0 +1,1696 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'description.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$DeviceCapabilities {
+  bool get canPlay;
+  bool get canControlVolume;
+  bool get canSubscribe;
+
+  /// Create a copy of DeviceCapabilities
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceCapabilitiesCopyWith<DeviceCapabilities> get copyWith =>
+      _$DeviceCapabilitiesCopyWithImpl<DeviceCapabilities>(
+          this as DeviceCapabilities, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceCapabilities &&
+            (identical(other.canPlay, canPlay) || other.canPlay == canPlay) &&
+            (identical(other.canControlVolume, canControlVolume) ||
+                other.canControlVolume == canControlVolume) &&
+            (identical(other.canSubscribe, canSubscribe) ||
+                other.canSubscribe == canSubscribe));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, canPlay, canControlVolume,
+      canSubscribe);
+
+  @override
+  String toString() {
+    return 'DeviceCapabilities(canPlay: $canPlay, canControlVolume: $canControlVolume, canSubscribe: $canSubscribe)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceCapabilitiesCopyWith<$Res> {
+  factory $DeviceCapabilitiesCopyWith(
+          DeviceCapabilities value, $Res Function(DeviceCapabilities) _then) =
+      _$DeviceCapabilitiesCopyWithImpl;
+  @useResult
+  $Res call({bool canPlay, bool canControlVolume, bool canSubscribe});
+}
+
+/// @nodoc
+class _$DeviceCapabilitiesCopyWithImpl<$Res>
+    implements $DeviceCapabilitiesCopyWith<$Res> {
+  _$DeviceCapabilitiesCopyWithImpl(this._self, this._then);
+
+  final DeviceCapabilities _self;
+  final $Res Function(DeviceCapabilities) _then;
+
+  /// Create a copy of DeviceCapabilities
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? canPlay = null,
+    Object? canControlVolume = null,
+    Object? canSubscribe = null,
+  }) {
+    return _then(_self.copyWith(
+      canPlay: null == canPlay
+          ? _self.canPlay
+          : canPlay // ignore: cast_nullable_to_non_nullable
+              as bool,
+      canControlVolume: null == canControlVolume
+          ? _self.canControlVolume
+          : canControlVolume // ignore: cast_nullable_to_non_nullable
+              as bool,
+      canSubscribe: null == canSubscribe
+          ? _self.canSubscribe
+          : canSubscribe // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [DeviceCapabilities].
+extension DeviceCapabilitiesPatterns on DeviceCapabilities {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_DeviceCapabilities value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceCapabilities() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_DeviceCapabilities value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceCapabilities():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_DeviceCapabilities value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceCapabilities() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(bool canPlay, bool canControlVolume, bool canSubscribe)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceCapabilities() when $default != null:
+        return $default(_that.canPlay, _that.canControlVolume,
+            _that.canSubscribe);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(bool canPlay, bool canControlVolume, bool canSubscribe)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceCapabilities():
+        return $default(_that.canPlay, _that.canControlVolume,
+            _that.canSubscribe);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(bool canPlay, bool canControlVolume, bool canSubscribe)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceCapabilities() when $default != null:
+        return $default(_that.canPlay, _that.canControlVolume,
+            _that.canSubscribe);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _DeviceCapabilities extends DeviceCapabilities {
+  const _DeviceCapabilities(
+      {required this.canPlay,
+      required this.canControlVolume,
+      required this.canSubscribe})
+      : super._();
+
+  @override
+  final bool canPlay;
+  @override
+  final bool canControlVolume;
+  @override
+  final bool canSubscribe;
+
+  /// Create a copy of DeviceCapabilities
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$DeviceCapabilitiesCopyWith<_DeviceCapabilities> get copyWith =>
+      __$DeviceCapabilitiesCopyWithImpl<_DeviceCapabilities>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _DeviceCapabilities &&
+            (identical(other.canPlay, canPlay) || other.canPlay == canPlay) &&
+            (identical(other.canControlVolume, canControlVolume) ||
+                other.canControlVolume == canControlVolume) &&
+            (identical(other.canSubscribe, canSubscribe) ||
+                other.canSubscribe == canSubscribe));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, canPlay, canControlVolume,
+      canSubscribe);
+
+  @override
+  String toString() {
+    return 'DeviceCapabilities(canPlay: $canPlay, canControlVolume: $canControlVolume, canSubscribe: $canSubscribe)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$DeviceCapabilitiesCopyWith<$Res>
+    implements $DeviceCapabilitiesCopyWith<$Res> {
+  factory _$DeviceCapabilitiesCopyWith(
+          _DeviceCapabilities value, $Res Function(_DeviceCapabilities) _then) =
+      __$DeviceCapabilitiesCopyWithImpl;
+  @override
+  @useResult
+  $Res call({bool canPlay, bool canControlVolume, bool canSubscribe});
+}
+
+/// @nodoc
+class __$DeviceCapabilitiesCopyWithImpl<$Res>
+    implements _$DeviceCapabilitiesCopyWith<$Res> {
+  __$DeviceCapabilitiesCopyWithImpl(this._self, this._then);
+
+  final _DeviceCapabilities _self;
+  final $Res Function(_DeviceCapabilities) _then;
+
+  /// Create a copy of DeviceCapabilities
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? canPlay = null,
+    Object? canControlVolume = null,
+    Object? canSubscribe = null,
+  }) {
+    return _then(_DeviceCapabilities(
+      canPlay: null == canPlay
+          ? _self.canPlay
+          : canPlay // ignore: cast_nullable_to_non_nullable
+              as bool,
+      canControlVolume: null == canControlVolume
+          ? _self.canControlVolume
+          : canControlVolume // ignore: cast_nullable_to_non_nullable
+              as bool,
+      canSubscribe: null == canSubscribe
+          ? _self.canSubscribe
+          : canSubscribe // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$DeviceDescription {
+  String get deviceType;
+  String get friendlyName;
+  String get manufacturer;
+  String? get manufacturerUrl;
+  String? get modelDescription;
+  String get modelName;
+  String? get modelNumber;
+  String? get modelUrl;
+  String? get serialNumber;
+  String get udn;
+  String? get presentationUrl;
+  String? get macAddress;
+  List<DeviceIcon> get icons;
+  List<ServiceInfo> get services;
+  List<DeviceDescription> get devices;
+
+  /// Create a copy of DeviceDescription
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceDescriptionCopyWith<DeviceDescription> get copyWith =>
+      _$DeviceDescriptionCopyWithImpl<DeviceDescription>(
+          this as DeviceDescription, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceDescription &&
+            (identical(other.deviceType, deviceType) ||
+                other.deviceType == deviceType) &&
+            (identical(other.friendlyName, friendlyName) ||
+                other.friendlyName == friendlyName) &&
+            (identical(other.manufacturer, manufacturer) ||
+                other.manufacturer == manufacturer) &&
+            (identical(other.manufacturerUrl, manufacturerUrl) ||
+                other.manufacturerUrl == manufacturerUrl) &&
+            (identical(other.modelDescription, modelDescription) ||
+                other.modelDescription == modelDescription) &&
+            (identical(other.modelName, modelName) ||
+                other.modelName == modelName) &&
+            (identical(other.modelNumber, modelNumber) ||
+                other.modelNumber == modelNumber) &&
+            (identical(other.modelUrl, modelUrl) ||
+                other.modelUrl == modelUrl) &&
+            (identical(other.serialNumber, serialNumber) ||
+                other.serialNumber == serialNumber) &&
+            (identical(other.udn, udn) || other.udn == udn) &&
+            (identical(other.presentationUrl, presentationUrl) ||
+                other.presentationUrl == presentationUrl) &&
+            (identical(other.macAddress, macAddress) ||
+                other.macAddress == macAddress) &&
+            const DeepCollectionEquality().equals(other.icons, icons) &&
+            const DeepCollectionEquality().equals(other.services, services) &&
+            const DeepCollectionEquality().equals(other.devices, devices));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, deviceType, friendlyName,
+      manufacturer, manufacturerUrl, modelDescription, modelName, modelNumber,
+      modelUrl, serialNumber, udn, presentationUrl, macAddress,
+      const DeepCollectionEquality().hash(icons),
+      const DeepCollectionEquality().hash(services),
+      const DeepCollectionEquality().hash(devices));
+
+  @override
+  String toString() {
+    return 'DeviceDescription(deviceType: $deviceType, friendlyName: $friendlyName, manufacturer: $manufacturer, manufacturerUrl: $manufacturerUrl, modelDescription: $modelDescription, modelName: $modelName, modelNumber: $modelNumber, modelUrl: $modelUrl, serialNumber: $serialNumber, udn: $udn, presentationUrl: $presentationUrl, macAddress: $macAddress, icons: $icons, services: $services, devices: $devices)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceDescriptionCopyWith<$Res> {
+  factory $DeviceDescriptionCopyWith(
+          DeviceDescription value, $Res Function(DeviceDescription) _then) =
+      _$DeviceDescriptionCopyWithImpl;
+  @useResult
+  $Res call(
+      {String deviceType,
+      String friendlyName,
+      String manufacturer,
+      String? manufacturerUrl,
+      String? modelDescription,
+      String modelName,
+      String? modelNumber,
+      String? modelUrl,
+      String? serialNumber,
+      String udn,
+      String? presentationUrl,
+      String? macAddress,
+      List<DeviceIcon> icons,
+      List<ServiceInfo> services,
+      List<DeviceDescription> devices});
+}
+
+/// @nodoc
+class _$DeviceDescriptionCopyWithImpl<$Res>
+    implements $DeviceDescriptionCopyWith<$Res> {
+  _$DeviceDescriptionCopyWithImpl(this._self, this._then);
+
+  final DeviceDescription _self;
+  final $Res Function(DeviceDescription) _then;
+
+  /// Create a copy of DeviceDescription
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? deviceType = null,
+    Object? friendlyName = null,
+    Object? manufacturer = null,
+    Object? manufacturerUrl = freezed,
+    Object? modelDescription = freezed,
+    Object? modelName = null,
+    Object? modelNumber = freezed,
+    Object? modelUrl = freezed,
+    Object? serialNumber = freezed,
+    Object? udn = null,
+    Object? presentationUrl = freezed,
+    Object? macAddress = freezed,
+    Object? icons = null,
+    Object? services = null,
+    Object? devices = null,
+  }) {
+    return _then(_self.copyWith(
+      deviceType: null == deviceType
+          ? _self.deviceType
+          : deviceType // ignore: cast_nullable_to_non_nullable
+              as String,
+      friendlyName: null == friendlyName
+          ? _self.friendlyName
+          : friendlyName // ignore: cast_nullable_to_non_nullable
+              as String,
+      manufacturer: null == manufacturer
+          ? _self.manufacturer
+          : manufacturer // ignore: cast_nullable_to_non_nullable
+              as String,
+      manufacturerUrl: freezed == manufacturerUrl
+          ? _self.manufacturerUrl
+          : manufacturerUrl // ignore: cast_nullable_to_non_nullable
+              as String?,
+      modelDescription: freezed == modelDescription
+          ? _self.modelDescription
+          : modelDescription // ignore: cast_nullable_to_non_nullable
+              as String?,
+      modelName: null == modelName
+          ? _self.modelName
+          : modelName // ignore: cast_nullable_to_non_nullable
+              as String,
+      modelNumber: freezed == modelNumber
+          ? _self.modelNumber
+          : modelNumber // ignore: cast_nullable_to_non_nullable
+              as String?,
+      modelUrl: freezed == modelUrl
+          ? _self.modelUrl
+          : modelUrl // ignore: cast_nullable_to_non_nullable
+              as String?,
+      serialNumber: freezed == serialNumber
+          ? _self.serialNumber
+          : serialNumber // ignore: cast_nullable_to_non_nullable
+              as String?,
+      udn: null == udn
+          ? _self.udn
+          : udn // ignore: cast_nullable_to_non_nullable
+              as String,
+      presentationUrl: freezed == presentationUrl
+          ? _self.presentationUrl
+          : presentationUrl // ignore: cast_nullable_to_non_nullable
+              as String?,
+      macAddress: freezed == macAddress
+          ? _self.macAddress
+          : macAddress // ignore: cast_nullable_to_non_nullable
+              as String?,
+      icons: null == icons
+          ? _self.icons
+          : icons // ignore: cast_nullable_to_non_nullable
+              as List<DeviceIcon>,
+      services: null == services
+          ? _self.services
+          : services // ignore: cast_nullable_to_non_nullable
+              as List<ServiceInfo>,
+      devices: null == devices
+          ? _self.devices
+          : devices // ignore: cast_nullable_to_non_nullable
+              as List<DeviceDescription>,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [DeviceDescription].
+extension DeviceDescriptionPatterns on DeviceDescription {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_DeviceDescription value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceDescription() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_DeviceDescription value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceDescription():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_DeviceDescription value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceDescription() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String deviceType, String friendlyName,
+            String manufacturer, String? manufacturerUrl,
+            String? modelDescription, String modelName, String? modelNumber,
+            String? modelUrl, String? serialNumber, String udn,
+            String? presentationUrl, String? macAddress, List<DeviceIcon> icons,
+            List<ServiceInfo> services, List<DeviceDescription> devices)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceDescription() when $default != null:
+        return $default(_that.deviceType, _that.friendlyName,
+            _that.manufacturer, _that.manufacturerUrl, _that.modelDescription,
+            _that.modelName, _that.modelNumber, _that.modelUrl,
+            _that.serialNumber, _that.udn, _that.presentationUrl,
+            _that.macAddress, _that.icons, _that.services, _that.devices);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String deviceType, String friendlyName,
+            String manufacturer, String? manufacturerUrl,
+            String? modelDescription, String modelName, String? modelNumber,
+            String? modelUrl, String? serialNumber, String udn,
+            String? presentationUrl, String? macAddress, List<DeviceIcon> icons,
+            List<ServiceInfo> services, List<DeviceDescription> devices)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceDescription():
+        return $default(_that.deviceType, _that.friendlyName,
+            _that.manufacturer, _that.manufacturerUrl, _that.modelDescription,
+            _that.modelName, _that.modelNumber, _that.modelUrl,
+            _that.serialNumber, _that.udn, _that.presentationUrl,
+            _that.macAddress, _that.icons, _that.services, _that.devices);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String deviceType, String friendlyName,
+            String manufacturer, String? manufacturerUrl,
+            String? modelDescription, String modelName, String? modelNumber,
+            String? modelUrl, String? serialNumber, String udn,
+            String? presentationUrl, String? macAddress, List<DeviceIcon> icons,
+            List<ServiceInfo> services, List<DeviceDescription> devices)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceDescription() when $default != null:
+        return $default(_that.deviceType, _that.friendlyName,
+            _that.manufacturer, _that.manufacturerUrl, _that.modelDescription,
+            _that.modelName, _that.modelNumber, _that.modelUrl,
+            _that.serialNumber, _that.udn, _that.presentationUrl,
+            _that.macAddress, _that.icons, _that.services, _that.devices);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _DeviceDescription implements DeviceDescription {
+  const _DeviceDescription(
+      {required this.deviceType,
+      required this.friendlyName,
+      required this.manufacturer,
+      this.manufacturerUrl,
+      this.modelDescription,
+      required this.modelName,
+      this.modelNumber,
+      this.modelUrl,
+      this.serialNumber,
+      required this.udn,
+      this.presentationUrl,
+      this.macAddress,
+      required final List<DeviceIcon> icons,
+      required final List<ServiceInfo> services,
+      required final List<DeviceDescription> devices})
+      : _icons = icons,
+        _services = services,
+        _devices = devices;
+
+  @override
+  final String deviceType;
+  @override
+  final String friendlyName;
+  @override
+  final String manufacturer;
+  @override
+  final String? manufacturerUrl;
+  @override
+  final String? modelDescription;
+  @override
+  final String modelName;
+  @override
+  final String? modelNumber;
+  @override
+  final String? modelUrl;
+  @override
+  final String? serialNumber;
+  @override
+  final String udn;
+  @override
+  final String? presentationUrl;
+  @override
+  final String? macAddress;
+  final List<DeviceIcon> _icons;
+  @override
+  List<DeviceIcon> get icons {
+    if (_icons is EqualUnmodifiableListView) return _icons;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_icons);
+  }
+
+  final List<ServiceInfo> _services;
+  @override
+  List<ServiceInfo> get services {
+    if (_services is EqualUnmodifiableListView) return _services;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_services);
+  }
+
+  final List<DeviceDescription> _devices;
+  @override
+  List<DeviceDescription> get devices {
+    if (_devices is EqualUnmodifiableListView) return _devices;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_devices);
+  }
+
+  /// Create a copy of DeviceDescription
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$DeviceDescriptionCopyWith<_DeviceDescription> get copyWith =>
+      __$DeviceDescriptionCopyWithImpl<_DeviceDescription>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _DeviceDescription &&
+            (identical(other.deviceType, deviceType) ||
+                other.deviceType == deviceType) &&
+            (identical(other.friendlyName, friendlyName) ||
+                other.friendlyName == friendlyName) &&
+            (identical(other.manufacturer, manufacturer) ||
+                other.manufacturer == manufacturer) &&
+            (identical(other.manufacturerUrl, manufacturerUrl) ||
+                other.manufacturerUrl == manufacturerUrl) &&
+            (identical(other.modelDescription, modelDescription) ||
+                other.modelDescription == modelDescription) &&
+            (identical(other.modelName, modelName) ||
+                other.modelName == modelName) &&
+            (identical(other.modelNumber, modelNumber) ||
+                other.modelNumber == modelNumber) &&
+            (identical(other.modelUrl, modelUrl) ||
+                other.modelUrl == modelUrl) &&
+            (identical(other.serialNumber, serialNumber) ||
+                other.serialNumber == serialNumber) &&
+            (identical(other.udn, udn) || other.udn == udn) &&
+            (identical(other.presentationUrl, presentationUrl) ||
+                other.presentationUrl == presentationUrl) &&
+            (identical(other.macAddress, macAddress) ||
+                other.macAddress == macAddress) &&
+            const DeepCollectionEquality().equals(other._icons, _icons) &&
+            const DeepCollectionEquality().equals(other._services, _services) &&
+            const DeepCollectionEquality().equals(other._devices, _devices));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, deviceType, friendlyName,
+      manufacturer, manufacturerUrl, modelDescription, modelName, modelNumber,
+      modelUrl, serialNumber, udn, presentationUrl, macAddress,
+      const DeepCollectionEquality().hash(_icons),
+      const DeepCollectionEquality().hash(_services),
+      const DeepCollectionEquality().hash(_devices));
+
+  @override
+  String toString() {
+    return 'DeviceDescription(deviceType: $deviceType, friendlyName: $friendlyName, manufacturer: $manufacturer, manufacturerUrl: $manufacturerUrl, modelDescription: $modelDescription, modelName: $modelName, modelNumber: $modelNumber, modelUrl: $modelUrl, serialNumber: $serialNumber, udn: $udn, presentationUrl: $presentationUrl, macAddress: $macAddress, icons: $icons, services: $services, devices: $devices)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$DeviceDescriptionCopyWith<$Res>
+    implements $DeviceDescriptionCopyWith<$Res> {
+  factory _$DeviceDescriptionCopyWith(
+          _DeviceDescription value, $Res Function(_DeviceDescription) _then) =
+      __$DeviceDescriptionCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {String deviceType,
+      String friendlyName,
+      String manufacturer,
+      String? manufacturerUrl,
+      String? modelDescription,
+      String modelName,
+      String? modelNumber,
+      String? modelUrl,
+      String? serialNumber,
+      String udn,
+      String? presentationUrl,
+      String? macAddress,
+      List<DeviceIcon> icons,
+      List<ServiceInfo> services,
+      List<DeviceDescription> devices});
+}
+
+/// @nodoc
+class __$DeviceDescriptionCopyWithImpl<$Res>
+    implements _$DeviceDescriptionCopyWith<$Res> {
+  __$DeviceDescriptionCopyWithImpl(this._self, this._then);
+
+  final _DeviceDescription _self;
+  final $Res Function(_DeviceDescription) _then;
+
+  /// Create a copy of DeviceDescription
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? deviceType = null,
+    Object? friendlyName = null,
+    Object? manufacturer = null,
+    Object? manufacturerUrl = freezed,
+    Object? modelDescription = freezed,
+    Object? modelName = null,
+    Object? modelNumber = freezed,
+    Object? modelUrl = freezed,
+    Object? serialNumber = freezed,
+    Object? udn = null,
+    Object? presentationUrl = freezed,
+    Object? macAddress = freezed,
+    Object? icons = null,
+    Object? services = null,
+    Object? devices = null,
+  }) {
+    return _then(_DeviceDescription(
+      deviceType: null == deviceType
+          ? _self.deviceType
+          : deviceType // ignore: cast_nullable_to_non_nullable
+              as String,
+      friendlyName: null == friendlyName
+          ? _self.friendlyName
+          : friendlyName // ignore: cast_nullable_to_non_nullable
+              as String,
+      manufacturer: null == manufacturer
+          ? _self.manufacturer
+          : manufacturer // ignore: cast_nullable_to_non_nullable
+              as String,
+      manufacturerUrl: freezed == manufacturerUrl
+          ? _self.manufacturerUrl
+          : manufacturerUrl // ignore: cast_nullable_to_non_nullable
+              as String?,
+      modelDescription: freezed == modelDescription
+          ? _self.modelDescription
+          : modelDescription // ignore: cast_nullable_to_non_nullable
+              as String?,
+      modelName: null == modelName
+          ? _self.modelName
+          : modelName // ignore: cast_nullable_to_non_nullable
+              as String,
+      modelNumber: freezed == modelNumber
+          ? _self.modelNumber
+          : modelNumber // ignore: cast_nullable_to_non_nullable
+              as String?,
+      modelUrl: freezed == modelUrl
+          ? _self.modelUrl
+          : modelUrl // ignore: cast_nullable_to_non_nullable
+              as String?,
+      serialNumber: freezed == serialNumber
+          ? _self.serialNumber
+          : serialNumber // ignore: cast_nullable_to_non_nullable
+              as String?,
+      udn: null == udn
+          ? _self.udn
+          : udn // ignore: cast_nullable_to_non_nullable
+              as String,
+      presentationUrl: freezed == presentationUrl
+          ? _self.presentationUrl
+          : presentationUrl // ignore: cast_nullable_to_non_nullable
+              as String?,
+      macAddress: freezed == macAddress
+          ? _self.macAddress
+          : macAddress // ignore: cast_nullable_to_non_nullable
+              as String?,
+      icons: null == icons
+          ? _self._icons
+          : icons // ignore: cast_nullable_to_non_nullable
+              as List<DeviceIcon>,
+      services: null == services
+          ? _self._services
+          : services // ignore: cast_nullable_to_non_nullable
+              as List<ServiceInfo>,
+      devices: null == devices
+          ? _self._devices
+          : devices // ignore: cast_nullable_to_non_nullable
+              as List<DeviceDescription>,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$DeviceIcon {
+  String get mimeType;
+  int get width;
+  int get height;
+  int get depth;
+  String get url;
+
+  /// Create a copy of DeviceIcon
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceIconCopyWith<DeviceIcon> get copyWith =>
+      _$DeviceIconCopyWithImpl<DeviceIcon>(this as DeviceIcon, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceIcon &&
+            (identical(other.mimeType, mimeType) ||
+                other.mimeType == mimeType) &&
+            (identical(other.width, width) || other.width == width) &&
+            (identical(other.height, height) || other.height == height) &&
+            (identical(other.depth, depth) || other.depth == depth) &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, mimeType, width, height, depth,
+      url);
+
+  @override
+  String toString() {
+    return 'DeviceIcon(mimeType: $mimeType, width: $width, height: $height, depth: $depth, url: $url)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceIconCopyWith<$Res> {
+  factory $DeviceIconCopyWith(
+          DeviceIcon value, $Res Function(DeviceIcon) _then) =
+      _$DeviceIconCopyWithImpl;
+  @useResult
+  $Res call({String mimeType, int width, int height, int depth, String url});
+}
+
+/// @nodoc
+class _$DeviceIconCopyWithImpl<$Res> implements $DeviceIconCopyWith<$Res> {
+  _$DeviceIconCopyWithImpl(this._self, this._then);
+
+  final DeviceIcon _self;
+  final $Res Function(DeviceIcon) _then;
+
+  /// Create a copy of DeviceIcon
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? mimeType = null,
+    Object? width = null,
+    Object? height = null,
+    Object? depth = null,
+    Object? url = null,
+  }) {
+    return _then(_self.copyWith(
+      mimeType: null == mimeType
+          ? _self.mimeType
+          : mimeType // ignore: cast_nullable_to_non_nullable
+              as String,
+      width: null == width
+          ? _self.width
+          : width // ignore: cast_nullable_to_non_nullable
+              as int,
+      height: null == height
+          ? _self.height
+          : height // ignore: cast_nullable_to_non_nullable
+              as int,
+      depth: null == depth
+          ? _self.depth
+          : depth // ignore: cast_nullable_to_non_nullable
+              as int,
+      url: null == url
+          ? _self.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [DeviceIcon].
+extension DeviceIconPatterns on DeviceIcon {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_DeviceIcon value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceIcon() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_DeviceIcon value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceIcon():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_DeviceIcon value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceIcon() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String mimeType, int width, int height, int depth,
+            String url)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceIcon() when $default != null:
+        return $default(_that.mimeType, _that.width, _that.height, _that.depth,
+            _that.url);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String mimeType, int width, int height, int depth,
+            String url)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceIcon():
+        return $default(_that.mimeType, _that.width, _that.height, _that.depth,
+            _that.url);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String mimeType, int width, int height, int depth,
+            String url)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceIcon() when $default != null:
+        return $default(_that.mimeType, _that.width, _that.height, _that.depth,
+            _that.url);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _DeviceIcon implements DeviceIcon {
+  const _DeviceIcon(
+      {required this.mimeType,
+      required this.width,
+      required this.height,
+      required this.depth,
+      required this.url});
+
+  @override
+  final String mimeType;
+  @override
+  final int width;
+  @override
+  final int height;
+  @override
+  final int depth;
+  @override
+  final String url;
+
+  /// Create a copy of DeviceIcon
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$DeviceIconCopyWith<_DeviceIcon> get copyWith =>
+      __$DeviceIconCopyWithImpl<_DeviceIcon>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _DeviceIcon &&
+            (identical(other.mimeType, mimeType) ||
+                other.mimeType == mimeType) &&
+            (identical(other.width, width) || other.width == width) &&
+            (identical(other.height, height) || other.height == height) &&
+            (identical(other.depth, depth) || other.depth == depth) &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, mimeType, width, height, depth,
+      url);
+
+  @override
+  String toString() {
+    return 'DeviceIcon(mimeType: $mimeType, width: $width, height: $height, depth: $depth, url: $url)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$DeviceIconCopyWith<$Res>
+    implements $DeviceIconCopyWith<$Res> {
+  factory _$DeviceIconCopyWith(
+          _DeviceIcon value, $Res Function(_DeviceIcon) _then) =
+      __$DeviceIconCopyWithImpl;
+  @override
+  @useResult
+  $Res call({String mimeType, int width, int height, int depth, String url});
+}
+
+/// @nodoc
+class __$DeviceIconCopyWithImpl<$Res> implements _$DeviceIconCopyWith<$Res> {
+  __$DeviceIconCopyWithImpl(this._self, this._then);
+
+  final _DeviceIcon _self;
+  final $Res Function(_DeviceIcon) _then;
+
+  /// Create a copy of DeviceIcon
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? mimeType = null,
+    Object? width = null,
+    Object? height = null,
+    Object? depth = null,
+    Object? url = null,
+  }) {
+    return _then(_DeviceIcon(
+      mimeType: null == mimeType
+          ? _self.mimeType
+          : mimeType // ignore: cast_nullable_to_non_nullable
+              as String,
+      width: null == width
+          ? _self.width
+          : width // ignore: cast_nullable_to_non_nullable
+              as int,
+      height: null == height
+          ? _self.height
+          : height // ignore: cast_nullable_to_non_nullable
+              as int,
+      depth: null == depth
+          ? _self.depth
+          : depth // ignore: cast_nullable_to_non_nullable
+              as int,
+      url: null == url
+          ? _self.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$ServiceInfo {
+  String get serviceType;
+  String get serviceId;
+  String get scpdUrl;
+  String get controlUrl;
+  String get eventSubUrl;
+
+  /// Create a copy of ServiceInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ServiceInfoCopyWith<ServiceInfo> get copyWith =>
+      _$ServiceInfoCopyWithImpl<ServiceInfo>(this as ServiceInfo, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ServiceInfo &&
+            (identical(other.serviceType, serviceType) ||
+                other.serviceType == serviceType) &&
+            (identical(other.serviceId, serviceId) ||
+                other.serviceId == serviceId) &&
+            (identical(other.scpdUrl, scpdUrl) || other.scpdUrl == scpdUrl) &&
+            (identical(other.controlUrl, controlUrl) ||
+                other.controlUrl == controlUrl) &&
+            (identical(other.eventSubUrl, eventSubUrl) ||
+                other.eventSubUrl == eventSubUrl));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, serviceType, serviceId, scpdUrl,
+      controlUrl, eventSubUrl);
+
+  @override
+  String toString() {
+    return 'ServiceInfo(serviceType: $serviceType, serviceId: $serviceId, scpdUrl: $scpdUrl, controlUrl: $controlUrl, eventSubUrl: $eventSubUrl)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ServiceInfoCopyWith<$Res> {
+  factory $ServiceInfoCopyWith(
+          ServiceInfo value, $Res Function(ServiceInfo) _then) =
+      _$ServiceInfoCopyWithImpl;
+  @useResult
+  $Res call(
+      {String serviceType,
+      String serviceId,
+      String scpdUrl,
+      String controlUrl,
+      String eventSubUrl});
+}
+
+/// @nodoc
+class _$ServiceInfoCopyWithImpl<$Res> implements $ServiceInfoCopyWith<$Res> {
+  _$ServiceInfoCopyWithImpl(this._self, this._then);
+
+  final ServiceInfo _self;
+  final $Res Function(ServiceInfo) _then;
+
+  /// Create a copy of ServiceInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? serviceType = null,
+    Object? serviceId = null,
+    Object? scpdUrl = null,
+    Object? controlUrl = null,
+    Object? eventSubUrl = null,
+  }) {
+    return _then(_self.copyWith(
+      serviceType: null == serviceType
+          ? _self.serviceType
+          : serviceType // ignore: cast_nullable_to_non_nullable
+              as String,
+      serviceId: null == serviceId
+          ? _self.serviceId
+          : serviceId // ignore: cast_nullable_to_non_nullable
+              as String,
+      scpdUrl: null == scpdUrl
+          ? _self.scpdUrl
+          : scpdUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+      controlUrl: null == controlUrl
+          ? _self.controlUrl
+          : controlUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+      eventSubUrl: null == eventSubUrl
+          ? _self.eventSubUrl
+          : eventSubUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [ServiceInfo].
+extension ServiceInfoPatterns on ServiceInfo {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_ServiceInfo value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceInfo() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_ServiceInfo value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceInfo():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_ServiceInfo value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceInfo() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String serviceType, String serviceId, String scpdUrl,
+            String controlUrl, String eventSubUrl)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceInfo() when $default != null:
+        return $default(_that.serviceType, _that.serviceId, _that.scpdUrl,
+            _that.controlUrl, _that.eventSubUrl);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String serviceType, String serviceId, String scpdUrl,
+            String controlUrl, String eventSubUrl)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceInfo():
+        return $default(_that.serviceType, _that.serviceId, _that.scpdUrl,
+            _that.controlUrl, _that.eventSubUrl);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String serviceType, String serviceId, String scpdUrl,
+            String controlUrl, String eventSubUrl)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceInfo() when $default != null:
+        return $default(_that.serviceType, _that.serviceId, _that.scpdUrl,
+            _that.controlUrl, _that.eventSubUrl);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _ServiceInfo implements ServiceInfo {
+  const _ServiceInfo(
+      {required this.serviceType,
+      required this.serviceId,
+      required this.scpdUrl,
+      required this.controlUrl,
+      required this.eventSubUrl});
+
+  @override
+  final String serviceType;
+  @override
+  final String serviceId;
+  @override
+  final String scpdUrl;
+  @override
+  final String controlUrl;
+  @override
+  final String eventSubUrl;
+
+  /// Create a copy of ServiceInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$ServiceInfoCopyWith<_ServiceInfo> get copyWith =>
+      __$ServiceInfoCopyWithImpl<_ServiceInfo>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _ServiceInfo &&
+            (identical(other.serviceType, serviceType) ||
+                other.serviceType == serviceType) &&
+            (identical(other.serviceId, serviceId) ||
+                other.serviceId == serviceId) &&
+            (identical(other.scpdUrl, scpdUrl) || other.scpdUrl == scpdUrl) &&
+            (identical(other.controlUrl, controlUrl) ||
+                other.controlUrl == controlUrl) &&
+            (identical(other.eventSubUrl, eventSubUrl) ||
+                other.eventSubUrl == eventSubUrl));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, serviceType, serviceId, scpdUrl,
+      controlUrl, eventSubUrl);
+
+  @override
+  String toString() {
+    return 'ServiceInfo(serviceType: $serviceType, serviceId: $serviceId, scpdUrl: $scpdUrl, controlUrl: $controlUrl, eventSubUrl: $eventSubUrl)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$ServiceInfoCopyWith<$Res>
+    implements $ServiceInfoCopyWith<$Res> {
+  factory _$ServiceInfoCopyWith(
+          _ServiceInfo value, $Res Function(_ServiceInfo) _then) =
+      __$ServiceInfoCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {String serviceType,
+      String serviceId,
+      String scpdUrl,
+      String controlUrl,
+      String eventSubUrl});
+}
+
+/// @nodoc
+class __$ServiceInfoCopyWithImpl<$Res> implements _$ServiceInfoCopyWith<$Res> {
+  __$ServiceInfoCopyWithImpl(this._self, this._then);
+
+  final _ServiceInfo _self;
+  final $Res Function(_ServiceInfo) _then;
+
+  /// Create a copy of ServiceInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? serviceType = null,
+    Object? serviceId = null,
+    Object? scpdUrl = null,
+    Object? controlUrl = null,
+    Object? eventSubUrl = null,
+  }) {
+    return _then(_ServiceInfo(
+      serviceType: null == serviceType
+          ? _self.serviceType
+          : serviceType // ignore: cast_nullable_to_non_nullable
+              as String,
+      serviceId: null == serviceId
+          ? _self.serviceId
+          : serviceId // ignore: cast_nullable_to_non_nullable
+              as String,
+      scpdUrl: null == scpdUrl
+          ? _self.scpdUrl
+          : scpdUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+      controlUrl: null == controlUrl
+          ? _self.controlUrl
+          : controlUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+      eventSubUrl: null == eventSubUrl
+          ? _self.eventSubUrl
+          : eventSubUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+// dart format on
@@ -0,0 +1,66 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cast.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'ssdp.dart';
+part 'monitor.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_description`, `check_device`, `check_ssdp`, `elapsed_ms`, `track_boot_state`, `update_state`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceMonitor>>
+/// 定期检查一组设备的可达性，推送上线/离线变化。一个 DeviceMonitor 只对应一次监控，停止后需重新创建
+abstract class DeviceMonitor implements RustOpaqueInterface {
+  /// interval_secs 为检查间隔 (最小 1 秒)
+  factory DeviceMonitor(
+          {required List<ProjectorInfo> devices,
+          required BigInt intervalSecs}) =>
+      RustLib.instance.api.crateApiMonitorDeviceMonitorNew(
+          devices: devices, intervalSecs: intervalSecs);
+
+  /// 开始监控，直到调用 stop() 为止；首次检查会推送每个设备的初始状态，
+  /// 设备重启或描述变化时推送刷新后的设备信息
+  Stream<ReachabilityEvent> run();
+
+  /// 停止监控；在 run() 开始前调用时 run() 会立即返回
+  void stop();
+}
+
+/// 设备可达性检查结果
+@freezed
+sealed class DeviceStatus with _$DeviceStatus {
+  const factory DeviceStatus({
+    required bool online,
+    int? httpLatencyMs,
+    int? ssdpLatencyMs,
+    String? error,
+    SsdpMessage? ssdp,
+  }) = _DeviceStatus;
+}
+
+@freezed
+sealed class ReachabilityEvent with _$ReachabilityEvent {
+  const ReachabilityEvent._();
+
+  const factory ReachabilityEvent.online({
+    required ProjectorInfo device,
+    required DeviceStatus status,
+  }) = ReachabilityEvent_Online;
+  const factory ReachabilityEvent.offline({
+    required ProjectorInfo device,
+    required DeviceStatus status,
+  }) = ReachabilityEvent_Offline;
+  const factory ReachabilityEvent.rebooted({
+    required ProjectorInfo device,
+    required DeviceStatus status,
+  }) = ReachabilityEvent_Rebooted;
+  const factory ReachabilityEvent.descriptionChanged({
+    required ProjectorInfo device,
+    required DeviceStatus status,
+  }) = ReachabilityEvent_DescriptionChanged;
+}
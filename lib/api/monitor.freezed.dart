@@ -0,0 +1,1138 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'monitor.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$DeviceStatus {
+  bool get online;
+  int? get httpLatencyMs;
+  int? get ssdpLatencyMs;
+  String? get error;
+  SsdpMessage? get ssdp;
+
+  /// Create a copy of DeviceStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceStatusCopyWith<DeviceStatus> get copyWith =>
+      _$DeviceStatusCopyWithImpl<DeviceStatus>(
+          this as DeviceStatus, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceStatus &&
+            (identical(other.online, online) || other.online == online) &&
+            (identical(other.httpLatencyMs, httpLatencyMs) ||
+                other.httpLatencyMs == httpLatencyMs) &&
+            (identical(other.ssdpLatencyMs, ssdpLatencyMs) ||
+                other.ssdpLatencyMs == ssdpLatencyMs) &&
+            (identical(other.error, error) || other.error == error) &&
+            (identical(other.ssdp, ssdp) || other.ssdp == ssdp));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, online, httpLatencyMs,
+      ssdpLatencyMs, error, ssdp);
+
+  @override
+  String toString() {
+    return 'DeviceStatus(online: $online, httpLatencyMs: $httpLatencyMs, ssdpLatencyMs: $ssdpLatencyMs, error: $error, ssdp: $ssdp)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceStatusCopyWith<$Res> {
+  factory $DeviceStatusCopyWith(
+          DeviceStatus value, $Res Function(DeviceStatus) _then) =
+      _$DeviceStatusCopyWithImpl;
+  @useResult
+  $Res call(
+      {bool online,
+      int? httpLatencyMs,
+      int? ssdpLatencyMs,
+      String? error,
+      SsdpMessage? ssdp});
+
+  $SsdpMessageCopyWith<$Res>? get ssdp;
+}
+
+/// @nodoc
+class _$DeviceStatusCopyWithImpl<$Res> implements $DeviceStatusCopyWith<$Res> {
+  _$DeviceStatusCopyWithImpl(this._self, this._then);
+
+  final DeviceStatus _self;
+  final $Res Function(DeviceStatus) _then;
+
+  /// Create a copy of DeviceStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? online = null,
+    Object? httpLatencyMs = freezed,
+    Object? ssdpLatencyMs = freezed,
+    Object? error = freezed,
+    Object? ssdp = freezed,
+  }) {
+    return _then(_self.copyWith(
+      online: null == online
+          ? _self.online
+          : online // ignore: cast_nullable_to_non_nullable
+              as bool,
+      httpLatencyMs: freezed == httpLatencyMs
+          ? _self.httpLatencyMs
+          : httpLatencyMs // ignore: cast_nullable_to_non_nullable
+              as int?,
+      ssdpLatencyMs: freezed == ssdpLatencyMs
+          ? _self.ssdpLatencyMs
+          : ssdpLatencyMs // ignore: cast_nullable_to_non_nullable
+              as int?,
+      error: freezed == error
+          ? _self.error
+          : error // ignore: cast_nullable_to_non_nullable
+              as String?,
+      ssdp: freezed == ssdp
+          ? _self.ssdp
+          : ssdp // ignore: cast_nullable_to_non_nullable
+              as SsdpMessage?,
+    ));
+  }
+
+  /// Create a copy of DeviceStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $SsdpMessageCopyWith<$Res>? get ssdp {
+    if (_self.ssdp == null) {
+      return null;
+    }
+
+    return $SsdpMessageCopyWith<$Res>(_self.ssdp!, (value) {
+      return _then(_self.copyWith(ssdp: value));
+    });
+  }
+}
+
+/// Adds pattern-matching-related methods to [DeviceStatus].
+extension DeviceStatusPatterns on DeviceStatus {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_DeviceStatus value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceStatus() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_DeviceStatus value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceStatus():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_DeviceStatus value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceStatus() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(bool online, int? httpLatencyMs, int? ssdpLatencyMs,
+            String? error, SsdpMessage? ssdp)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceStatus() when $default != null:
+        return $default(_that.online, _that.httpLatencyMs, _that.ssdpLatencyMs,
+            _that.error, _that.ssdp);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(bool online, int? httpLatencyMs, int? ssdpLatencyMs,
+            String? error, SsdpMessage? ssdp)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceStatus():
+        return $default(_that.online, _that.httpLatencyMs, _that.ssdpLatencyMs,
+            _that.error, _that.ssdp);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(bool online, int? httpLatencyMs, int? ssdpLatencyMs,
+            String? error, SsdpMessage? ssdp)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _DeviceStatus() when $default != null:
+        return $default(_that.online, _that.httpLatencyMs, _that.ssdpLatencyMs,
+            _that.error, _that.ssdp);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _DeviceStatus implements DeviceStatus {
+  const _DeviceStatus(
+      {required this.online,
+      this.httpLatencyMs,
+      this.ssdpLatencyMs,
+      this.error,
+      this.ssdp});
+
+  @override
+  final bool online;
+  @override
+  final int? httpLatencyMs;
+  @override
+  final int? ssdpLatencyMs;
+  @override
+  final String? error;
+  @override
+  final SsdpMessage? ssdp;
+
+  /// Create a copy of DeviceStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$DeviceStatusCopyWith<_DeviceStatus> get copyWith =>
+      __$DeviceStatusCopyWithImpl<_DeviceStatus>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _DeviceStatus &&
+            (identical(other.online, online) || other.online == online) &&
+            (identical(other.httpLatencyMs, httpLatencyMs) ||
+                other.httpLatencyMs == httpLatencyMs) &&
+            (identical(other.ssdpLatencyMs, ssdpLatencyMs) ||
+                other.ssdpLatencyMs == ssdpLatencyMs) &&
+            (identical(other.error, error) || other.error == error) &&
+            (identical(other.ssdp, ssdp) || other.ssdp == ssdp));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, online, httpLatencyMs,
+      ssdpLatencyMs, error, ssdp);
+
+  @override
+  String toString() {
+    return 'DeviceStatus(online: $online, httpLatencyMs: $httpLatencyMs, ssdpLatencyMs: $ssdpLatencyMs, error: $error, ssdp: $ssdp)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$DeviceStatusCopyWith<$Res>
+    implements $DeviceStatusCopyWith<$Res> {
+  factory _$DeviceStatusCopyWith(
+          _DeviceStatus value, $Res Function(_DeviceStatus) _then) =
+      __$DeviceStatusCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {bool online,
+      int? httpLatencyMs,
+      int? ssdpLatencyMs,
+      String? error,
+      SsdpMessage? ssdp});
+
+  @override
+  $SsdpMessageCopyWith<$Res>? get ssdp;
+}
+
+/// @nodoc
+class __$DeviceStatusCopyWithImpl<$Res>
+    implements _$DeviceStatusCopyWith<$Res> {
+  __$DeviceStatusCopyWithImpl(this._self, this._then);
+
+  final _DeviceStatus _self;
+  final $Res Function(_DeviceStatus) _then;
+
+  /// Create a copy of DeviceStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? online = null,
+    Object? httpLatencyMs = freezed,
+    Object? ssdpLatencyMs = freezed,
+    Object? error = freezed,
+    Object? ssdp = freezed,
+  }) {
+    return _then(_DeviceStatus(
+      online: null == online
+          ? _self.online
+          : online // ignore: cast_nullable_to_non_nullable
+              as bool,
+      httpLatencyMs: freezed == httpLatencyMs
+          ? _self.httpLatencyMs
+          : httpLatencyMs // ignore: cast_nullable_to_non_nullable
+              as int?,
+      ssdpLatencyMs: freezed == ssdpLatencyMs
+          ? _self.ssdpLatencyMs
+          : ssdpLatencyMs // ignore: cast_nullable_to_non_nullable
+              as int?,
+      error: freezed == error
+          ? _self.error
+          : error // ignore: cast_nullable_to_non_nullable
+              as String?,
+      ssdp: freezed == ssdp
+          ? _self.ssdp
+          : ssdp // ignore: cast_nullable_to_non_nullable
+              as SsdpMessage?,
+    ));
+  }
+
+  /// Create a copy of DeviceStatus
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $SsdpMessageCopyWith<$Res>? get ssdp {
+    if (_self.ssdp == null) {
+      return null;
+    }
+
+    return $SsdpMessageCopyWith<$Res>(_self.ssdp!, (value) {
+      return _then(_self.copyWith(ssdp: value));
+    });
+  }
+}
+
+/// @nodoc
+mixin _$ReachabilityEvent {
+  ProjectorInfo get device;
+  DeviceStatus get status;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ReachabilityEventCopyWith<ReachabilityEvent> get copyWith =>
+      _$ReachabilityEventCopyWithImpl<ReachabilityEvent>(
+          this as ReachabilityEvent, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ReachabilityEvent &&
+            (identical(other.device, device) || other.device == device) &&
+            (identical(other.status, status) || other.status == status));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, device, status);
+
+  @override
+  String toString() {
+    return 'ReachabilityEvent(device: $device, status: $status)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ReachabilityEventCopyWith<$Res> {
+  factory $ReachabilityEventCopyWith(
+          ReachabilityEvent value, $Res Function(ReachabilityEvent) _then) =
+      _$ReachabilityEventCopyWithImpl;
+  @useResult
+  $Res call({ProjectorInfo device, DeviceStatus status});
+
+  $ProjectorInfoCopyWith<$Res> get device;
+  $DeviceStatusCopyWith<$Res> get status;
+}
+
+/// @nodoc
+class _$ReachabilityEventCopyWithImpl<$Res>
+    implements $ReachabilityEventCopyWith<$Res> {
+  _$ReachabilityEventCopyWithImpl(this._self, this._then);
+
+  final ReachabilityEvent _self;
+  final $Res Function(ReachabilityEvent) _then;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? device = null,
+    Object? status = null,
+  }) {
+    return _then(_self.copyWith(
+      device: null == device
+          ? _self.device
+          : device // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+      status: null == status
+          ? _self.status
+          : status // ignore: cast_nullable_to_non_nullable
+              as DeviceStatus,
+    ));
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get device {
+    return $ProjectorInfoCopyWith<$Res>(_self.device, (value) {
+      return _then(_self.copyWith(device: value));
+    });
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DeviceStatusCopyWith<$Res> get status {
+    return $DeviceStatusCopyWith<$Res>(_self.status, (value) {
+      return _then(_self.copyWith(status: value));
+    });
+  }
+}
+
+/// Adds pattern-matching-related methods to [ReachabilityEvent].
+extension ReachabilityEventPatterns on ReachabilityEvent {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ReachabilityEvent_Online value)? online,
+    TResult Function(ReachabilityEvent_Offline value)? offline,
+    TResult Function(ReachabilityEvent_Rebooted value)? rebooted,
+    TResult Function(ReachabilityEvent_DescriptionChanged value)?
+        descriptionChanged,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ReachabilityEvent_Online() when online != null:
+        return online(_that);
+      case ReachabilityEvent_Offline() when offline != null:
+        return offline(_that);
+      case ReachabilityEvent_Rebooted() when rebooted != null:
+        return rebooted(_that);
+      case ReachabilityEvent_DescriptionChanged() when descriptionChanged != null:
+        return descriptionChanged(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ReachabilityEvent_Online value) online,
+    required TResult Function(ReachabilityEvent_Offline value) offline,
+    required TResult Function(ReachabilityEvent_Rebooted value) rebooted,
+    required TResult Function(ReachabilityEvent_DescriptionChanged value)
+        descriptionChanged,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ReachabilityEvent_Online():
+        return online(_that);
+      case ReachabilityEvent_Offline():
+        return offline(_that);
+      case ReachabilityEvent_Rebooted():
+        return rebooted(_that);
+      case ReachabilityEvent_DescriptionChanged():
+        return descriptionChanged(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ReachabilityEvent_Online value)? online,
+    TResult? Function(ReachabilityEvent_Offline value)? offline,
+    TResult? Function(ReachabilityEvent_Rebooted value)? rebooted,
+    TResult? Function(ReachabilityEvent_DescriptionChanged value)?
+        descriptionChanged,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ReachabilityEvent_Online() when online != null:
+        return online(_that);
+      case ReachabilityEvent_Offline() when offline != null:
+        return offline(_that);
+      case ReachabilityEvent_Rebooted() when rebooted != null:
+        return rebooted(_that);
+      case ReachabilityEvent_DescriptionChanged() when descriptionChanged != null:
+        return descriptionChanged(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ProjectorInfo device, DeviceStatus status)? online,
+    TResult Function(ProjectorInfo device, DeviceStatus status)? offline,
+    TResult Function(ProjectorInfo device, DeviceStatus status)? rebooted,
+    TResult Function(ProjectorInfo device, DeviceStatus status)?
+        descriptionChanged,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ReachabilityEvent_Online() when online != null:
+        return online(_that.device, _that.status);
+      case ReachabilityEvent_Offline() when offline != null:
+        return offline(_that.device, _that.status);
+      case ReachabilityEvent_Rebooted() when rebooted != null:
+        return rebooted(_that.device, _that.status);
+      case ReachabilityEvent_DescriptionChanged() when descriptionChanged != null:
+        return descriptionChanged(_that.device, _that.status);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ProjectorInfo device, DeviceStatus status) online,
+    required TResult Function(ProjectorInfo device, DeviceStatus status) offline,
+    required TResult Function(ProjectorInfo device, DeviceStatus status) rebooted,
+    required TResult Function(ProjectorInfo device, DeviceStatus status)
+        descriptionChanged,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ReachabilityEvent_Online():
+        return online(_that.device, _that.status);
+      case ReachabilityEvent_Offline():
+        return offline(_that.device, _that.status);
+      case ReachabilityEvent_Rebooted():
+        return rebooted(_that.device, _that.status);
+      case ReachabilityEvent_DescriptionChanged():
+        return descriptionChanged(_that.device, _that.status);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ProjectorInfo device, DeviceStatus status)? online,
+    TResult? Function(ProjectorInfo device, DeviceStatus status)? offline,
+    TResult? Function(ProjectorInfo device, DeviceStatus status)? rebooted,
+    TResult? Function(ProjectorInfo device, DeviceStatus status)?
+        descriptionChanged,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case ReachabilityEvent_Online() when online != null:
+        return online(_that.device, _that.status);
+      case ReachabilityEvent_Offline() when offline != null:
+        return offline(_that.device, _that.status);
+      case ReachabilityEvent_Rebooted() when rebooted != null:
+        return rebooted(_that.device, _that.status);
+      case ReachabilityEvent_DescriptionChanged() when descriptionChanged != null:
+        return descriptionChanged(_that.device, _that.status);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class ReachabilityEvent_Online extends ReachabilityEvent {
+  const ReachabilityEvent_Online({required this.device, required this.status})
+      : super._();
+
+  @override
+  final ProjectorInfo device;
+  @override
+  final DeviceStatus status;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ReachabilityEvent_OnlineCopyWith<ReachabilityEvent_Online> get copyWith =>
+      _$ReachabilityEvent_OnlineCopyWithImpl<ReachabilityEvent_Online>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ReachabilityEvent_Online &&
+            (identical(other.device, device) || other.device == device) &&
+            (identical(other.status, status) || other.status == status));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, device, status);
+
+  @override
+  String toString() {
+    return 'ReachabilityEvent.online(device: $device, status: $status)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ReachabilityEvent_OnlineCopyWith<$Res>
+    implements $ReachabilityEventCopyWith<$Res> {
+  factory $ReachabilityEvent_OnlineCopyWith(
+          ReachabilityEvent_Online value, $Res Function(ReachabilityEvent_Online) _then) =
+      _$ReachabilityEvent_OnlineCopyWithImpl;
+  @override
+  @useResult
+  $Res call({ProjectorInfo device, DeviceStatus status});
+
+  @override
+  $ProjectorInfoCopyWith<$Res> get device;
+  @override
+  $DeviceStatusCopyWith<$Res> get status;
+}
+
+/// @nodoc
+class _$ReachabilityEvent_OnlineCopyWithImpl<$Res>
+    implements $ReachabilityEvent_OnlineCopyWith<$Res> {
+  _$ReachabilityEvent_OnlineCopyWithImpl(this._self, this._then);
+
+  final ReachabilityEvent_Online _self;
+  final $Res Function(ReachabilityEvent_Online) _then;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? device = null,
+    Object? status = null,
+  }) {
+    return _then(ReachabilityEvent_Online(
+      device: null == device
+          ? _self.device
+          : device // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+      status: null == status
+          ? _self.status
+          : status // ignore: cast_nullable_to_non_nullable
+              as DeviceStatus,
+    ));
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get device {
+    return $ProjectorInfoCopyWith<$Res>(_self.device, (value) {
+      return _then(_self.copyWith(device: value));
+    });
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DeviceStatusCopyWith<$Res> get status {
+    return $DeviceStatusCopyWith<$Res>(_self.status, (value) {
+      return _then(_self.copyWith(status: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class ReachabilityEvent_Offline extends ReachabilityEvent {
+  const ReachabilityEvent_Offline({required this.device, required this.status})
+      : super._();
+
+  @override
+  final ProjectorInfo device;
+  @override
+  final DeviceStatus status;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ReachabilityEvent_OfflineCopyWith<ReachabilityEvent_Offline> get copyWith =>
+      _$ReachabilityEvent_OfflineCopyWithImpl<ReachabilityEvent_Offline>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ReachabilityEvent_Offline &&
+            (identical(other.device, device) || other.device == device) &&
+            (identical(other.status, status) || other.status == status));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, device, status);
+
+  @override
+  String toString() {
+    return 'ReachabilityEvent.offline(device: $device, status: $status)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ReachabilityEvent_OfflineCopyWith<$Res>
+    implements $ReachabilityEventCopyWith<$Res> {
+  factory $ReachabilityEvent_OfflineCopyWith(
+          ReachabilityEvent_Offline value, $Res Function(ReachabilityEvent_Offline) _then) =
+      _$ReachabilityEvent_OfflineCopyWithImpl;
+  @override
+  @useResult
+  $Res call({ProjectorInfo device, DeviceStatus status});
+
+  @override
+  $ProjectorInfoCopyWith<$Res> get device;
+  @override
+  $DeviceStatusCopyWith<$Res> get status;
+}
+
+/// @nodoc
+class _$ReachabilityEvent_OfflineCopyWithImpl<$Res>
+    implements $ReachabilityEvent_OfflineCopyWith<$Res> {
+  _$ReachabilityEvent_OfflineCopyWithImpl(this._self, this._then);
+
+  final ReachabilityEvent_Offline _self;
+  final $Res Function(ReachabilityEvent_Offline) _then;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? device = null,
+    Object? status = null,
+  }) {
+    return _then(ReachabilityEvent_Offline(
+      device: null == device
+          ? _self.device
+          : device // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+      status: null == status
+          ? _self.status
+          : status // ignore: cast_nullable_to_non_nullable
+              as DeviceStatus,
+    ));
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get device {
+    return $ProjectorInfoCopyWith<$Res>(_self.device, (value) {
+      return _then(_self.copyWith(device: value));
+    });
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DeviceStatusCopyWith<$Res> get status {
+    return $DeviceStatusCopyWith<$Res>(_self.status, (value) {
+      return _then(_self.copyWith(status: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class ReachabilityEvent_Rebooted extends ReachabilityEvent {
+  const ReachabilityEvent_Rebooted({required this.device, required this.status})
+      : super._();
+
+  @override
+  final ProjectorInfo device;
+  @override
+  final DeviceStatus status;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ReachabilityEvent_RebootedCopyWith<ReachabilityEvent_Rebooted> get copyWith =>
+      _$ReachabilityEvent_RebootedCopyWithImpl<ReachabilityEvent_Rebooted>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ReachabilityEvent_Rebooted &&
+            (identical(other.device, device) || other.device == device) &&
+            (identical(other.status, status) || other.status == status));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, device, status);
+
+  @override
+  String toString() {
+    return 'ReachabilityEvent.rebooted(device: $device, status: $status)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ReachabilityEvent_RebootedCopyWith<$Res>
+    implements $ReachabilityEventCopyWith<$Res> {
+  factory $ReachabilityEvent_RebootedCopyWith(
+          ReachabilityEvent_Rebooted value, $Res Function(ReachabilityEvent_Rebooted) _then) =
+      _$ReachabilityEvent_RebootedCopyWithImpl;
+  @override
+  @useResult
+  $Res call({ProjectorInfo device, DeviceStatus status});
+
+  @override
+  $ProjectorInfoCopyWith<$Res> get device;
+  @override
+  $DeviceStatusCopyWith<$Res> get status;
+}
+
+/// @nodoc
+class _$ReachabilityEvent_RebootedCopyWithImpl<$Res>
+    implements $ReachabilityEvent_RebootedCopyWith<$Res> {
+  _$ReachabilityEvent_RebootedCopyWithImpl(this._self, this._then);
+
+  final ReachabilityEvent_Rebooted _self;
+  final $Res Function(ReachabilityEvent_Rebooted) _then;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? device = null,
+    Object? status = null,
+  }) {
+    return _then(ReachabilityEvent_Rebooted(
+      device: null == device
+          ? _self.device
+          : device // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+      status: null == status
+          ? _self.status
+          : status // ignore: cast_nullable_to_non_nullable
+              as DeviceStatus,
+    ));
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get device {
+    return $ProjectorInfoCopyWith<$Res>(_self.device, (value) {
+      return _then(_self.copyWith(device: value));
+    });
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DeviceStatusCopyWith<$Res> get status {
+    return $DeviceStatusCopyWith<$Res>(_self.status, (value) {
+      return _then(_self.copyWith(status: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class ReachabilityEvent_DescriptionChanged extends ReachabilityEvent {
+  const ReachabilityEvent_DescriptionChanged(
+      {required this.device, required this.status})
+      : super._();
+
+  @override
+  final ProjectorInfo device;
+  @override
+  final DeviceStatus status;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ReachabilityEvent_DescriptionChangedCopyWith<ReachabilityEvent_DescriptionChanged> get copyWith =>
+      _$ReachabilityEvent_DescriptionChangedCopyWithImpl<ReachabilityEvent_DescriptionChanged>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ReachabilityEvent_DescriptionChanged &&
+            (identical(other.device, device) || other.device == device) &&
+            (identical(other.status, status) || other.status == status));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, device, status);
+
+  @override
+  String toString() {
+    return 'ReachabilityEvent.descriptionChanged(device: $device, status: $status)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ReachabilityEvent_DescriptionChangedCopyWith<$Res>
+    implements $ReachabilityEventCopyWith<$Res> {
+  factory $ReachabilityEvent_DescriptionChangedCopyWith(
+          ReachabilityEvent_DescriptionChanged value, $Res Function(ReachabilityEvent_DescriptionChanged) _then) =
+      _$ReachabilityEvent_DescriptionChangedCopyWithImpl;
+  @override
+  @useResult
+  $Res call({ProjectorInfo device, DeviceStatus status});
+
+  @override
+  $ProjectorInfoCopyWith<$Res> get device;
+  @override
+  $DeviceStatusCopyWith<$Res> get status;
+}
+
+/// @nodoc
+class _$ReachabilityEvent_DescriptionChangedCopyWithImpl<$Res>
+    implements $ReachabilityEvent_DescriptionChangedCopyWith<$Res> {
+  _$ReachabilityEvent_DescriptionChangedCopyWithImpl(this._self, this._then);
+
+  final ReachabilityEvent_DescriptionChanged _self;
+  final $Res Function(ReachabilityEvent_DescriptionChanged) _then;
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? device = null,
+    Object? status = null,
+  }) {
+    return _then(ReachabilityEvent_DescriptionChanged(
+      device: null == device
+          ? _self.device
+          : device // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+      status: null == status
+          ? _self.status
+          : status // ignore: cast_nullable_to_non_nullable
+              as DeviceStatus,
+    ));
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get device {
+    return $ProjectorInfoCopyWith<$Res>(_self.device, (value) {
+      return _then(_self.copyWith(device: value));
+    });
+  }
+
+  /// Create a copy of ReachabilityEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DeviceStatusCopyWith<$Res> get status {
+    return $DeviceStatusCopyWith<$Res>(_self.status, (value) {
+      return _then(_self.copyWith(status: value));
+    });
+  }
+}
+
+// dart format on
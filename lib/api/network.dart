@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'network.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `align4`, `directed_broadcast`, `fallback_neighbor_mac`, `format_mac`, `is_eligible`, `is_link_local_v6`, `is_vpn_ip`, `link_local_scope`, `lookup_neighbor_mac`, `neighbor_mac`, `normalize_mac`, `parse_arp_table`, `parse_mac`, `parse_ndmsg`, `parse_netlink_neighbors`, `parse_route_dump`, `parse_zone`, `read_neighbors`, `remember_link_local_scope`, `select_ipv6_interfaces`, `select_scan_interfaces`, `set_link_local_scope`, `url_ipv6`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 列出本机所有网络接口地址 (IPv4 与 IPv6)
+Future<List<NetworkInterface>> listNetworkInterfaces() =>
+    RustLib.instance.api.crateApiNetworkListNetworkInterfaces();
+
+@freezed
+sealed class NetworkInterface with _$NetworkInterface {
+  const factory NetworkInterface({
+    required String name,
+    int? index,
+    required String ip,
+    required int prefixLen,
+    required bool isIpv6,
+    required bool isUp,
+    required bool isLoopback,
+    required bool eligible,
+  }) = _NetworkInterface;
+}
@@ -0,0 +1,444 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'network.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$NetworkInterface {
+  String get name;
+  int? get index;
+  String get ip;
+  int get prefixLen;
+  bool get isIpv6;
+  bool get isUp;
+  bool get isLoopback;
+  bool get eligible;
+
+  /// Create a copy of NetworkInterface
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $NetworkInterfaceCopyWith<NetworkInterface> get copyWith =>
+      _$NetworkInterfaceCopyWithImpl<NetworkInterface>(
+          this as NetworkInterface, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is NetworkInterface &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.index, index) || other.index == index) &&
+            (identical(other.ip, ip) || other.ip == ip) &&
+            (identical(other.prefixLen, prefixLen) ||
+                other.prefixLen == prefixLen) &&
+            (identical(other.isIpv6, isIpv6) || other.isIpv6 == isIpv6) &&
+            (identical(other.isUp, isUp) || other.isUp == isUp) &&
+            (identical(other.isLoopback, isLoopback) ||
+                other.isLoopback == isLoopback) &&
+            (identical(other.eligible, eligible) ||
+                other.eligible == eligible));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, index, ip, prefixLen,
+      isIpv6, isUp, isLoopback, eligible);
+
+  @override
+  String toString() {
+    return 'NetworkInterface(name: $name, index: $index, ip: $ip, prefixLen: $prefixLen, isIpv6: $isIpv6, isUp: $isUp, isLoopback: $isLoopback, eligible: $eligible)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $NetworkInterfaceCopyWith<$Res> {
+  factory $NetworkInterfaceCopyWith(
+          NetworkInterface value, $Res Function(NetworkInterface) _then) =
+      _$NetworkInterfaceCopyWithImpl;
+  @useResult
+  $Res call(
+      {String name,
+      int? index,
+      String ip,
+      int prefixLen,
+      bool isIpv6,
+      bool isUp,
+      bool isLoopback,
+      bool eligible});
+}
+
+/// @nodoc
+class _$NetworkInterfaceCopyWithImpl<$Res>
+    implements $NetworkInterfaceCopyWith<$Res> {
+  _$NetworkInterfaceCopyWithImpl(this._self, this._then);
+
+  final NetworkInterface _self;
+  final $Res Function(NetworkInterface) _then;
+
+  /// Create a copy of NetworkInterface
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? index = freezed,
+    Object? ip = null,
+    Object? prefixLen = null,
+    Object? isIpv6 = null,
+    Object? isUp = null,
+    Object? isLoopback = null,
+    Object? eligible = null,
+  }) {
+    return _then(_self.copyWith(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      index: freezed == index
+          ? _self.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int?,
+      ip: null == ip
+          ? _self.ip
+          : ip // ignore: cast_nullable_to_non_nullable
+              as String,
+      prefixLen: null == prefixLen
+          ? _self.prefixLen
+          : prefixLen // ignore: cast_nullable_to_non_nullable
+              as int,
+      isIpv6: null == isIpv6
+          ? _self.isIpv6
+          : isIpv6 // ignore: cast_nullable_to_non_nullable
+              as bool,
+      isUp: null == isUp
+          ? _self.isUp
+          : isUp // ignore: cast_nullable_to_non_nullable
+              as bool,
+      isLoopback: null == isLoopback
+          ? _self.isLoopback
+          : isLoopback // ignore: cast_nullable_to_non_nullable
+              as bool,
+      eligible: null == eligible
+          ? _self.eligible
+          : eligible // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [NetworkInterface].
+extension NetworkInterfacePatterns on NetworkInterface {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_NetworkInterface value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _NetworkInterface() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_NetworkInterface value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _NetworkInterface():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_NetworkInterface value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _NetworkInterface() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String name, int? index, String ip, int prefixLen,
+            bool isIpv6, bool isUp, bool isLoopback, bool eligible)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _NetworkInterface() when $default != null:
+        return $default(_that.name, _that.index, _that.ip, _that.prefixLen,
+            _that.isIpv6, _that.isUp, _that.isLoopback, _that.eligible);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String name, int? index, String ip, int prefixLen,
+            bool isIpv6, bool isUp, bool isLoopback, bool eligible)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _NetworkInterface():
+        return $default(_that.name, _that.index, _that.ip, _that.prefixLen,
+            _that.isIpv6, _that.isUp, _that.isLoopback, _that.eligible);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String name, int? index, String ip, int prefixLen,
+            bool isIpv6, bool isUp, bool isLoopback, bool eligible)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _NetworkInterface() when $default != null:
+        return $default(_that.name, _that.index, _that.ip, _that.prefixLen,
+            _that.isIpv6, _that.isUp, _that.isLoopback, _that.eligible);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _NetworkInterface implements NetworkInterface {
+  const _NetworkInterface(
+      {required this.name,
+      this.index,
+      required this.ip,
+      required this.prefixLen,
+      required this.isIpv6,
+      required this.isUp,
+      required this.isLoopback,
+      required this.eligible});
+
+  @override
+  final String name;
+  @override
+  final int? index;
+  @override
+  final String ip;
+  @override
+  final int prefixLen;
+  @override
+  final bool isIpv6;
+  @override
+  final bool isUp;
+  @override
+  final bool isLoopback;
+  @override
+  final bool eligible;
+
+  /// Create a copy of NetworkInterface
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$NetworkInterfaceCopyWith<_NetworkInterface> get copyWith =>
+      __$NetworkInterfaceCopyWithImpl<_NetworkInterface>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _NetworkInterface &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.index, index) || other.index == index) &&
+            (identical(other.ip, ip) || other.ip == ip) &&
+            (identical(other.prefixLen, prefixLen) ||
+                other.prefixLen == prefixLen) &&
+            (identical(other.isIpv6, isIpv6) || other.isIpv6 == isIpv6) &&
+            (identical(other.isUp, isUp) || other.isUp == isUp) &&
+            (identical(other.isLoopback, isLoopback) ||
+                other.isLoopback == isLoopback) &&
+            (identical(other.eligible, eligible) ||
+                other.eligible == eligible));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, index, ip, prefixLen,
+      isIpv6, isUp, isLoopback, eligible);
+
+  @override
+  String toString() {
+    return 'NetworkInterface(name: $name, index: $index, ip: $ip, prefixLen: $prefixLen, isIpv6: $isIpv6, isUp: $isUp, isLoopback: $isLoopback, eligible: $eligible)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$NetworkInterfaceCopyWith<$Res>
+    implements $NetworkInterfaceCopyWith<$Res> {
+  factory _$NetworkInterfaceCopyWith(
+          _NetworkInterface value, $Res Function(_NetworkInterface) _then) =
+      __$NetworkInterfaceCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {String name,
+      int? index,
+      String ip,
+      int prefixLen,
+      bool isIpv6,
+      bool isUp,
+      bool isLoopback,
+      bool eligible});
+}
+
+/// @nodoc
+class __$NetworkInterfaceCopyWithImpl<$Res>
+    implements _$NetworkInterfaceCopyWith<$Res> {
+  __$NetworkInterfaceCopyWithImpl(this._self, this._then);
+
+  final _NetworkInterface _self;
+  final $Res Function(_NetworkInterface) _then;
+
+  /// Create a copy of NetworkInterface
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? name = null,
+    Object? index = freezed,
+    Object? ip = null,
+    Object? prefixLen = null,
+    Object? isIpv6 = null,
+    Object? isUp = null,
+    Object? isLoopback = null,
+    Object? eligible = null,
+  }) {
+    return _then(_NetworkInterface(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      index: freezed == index
+          ? _self.index
+          : index // ignore: cast_nullable_to_non_nullable
+              as int?,
+      ip: null == ip
+          ? _self.ip
+          : ip // ignore: cast_nullable_to_non_nullable
+              as String,
+      prefixLen: null == prefixLen
+          ? _self.prefixLen
+          : prefixLen // ignore: cast_nullable_to_non_nullable
+              as int,
+      isIpv6: null == isIpv6
+          ? _self.isIpv6
+          : isIpv6 // ignore: cast_nullable_to_non_nullable
+              as bool,
+      isUp: null == isUp
+          ? _self.isUp
+          : isUp // ignore: cast_nullable_to_non_nullable
+              as bool,
+      isLoopback: null == isLoopback
+          ? _self.isLoopback
+          : isLoopback // ignore: cast_nullable_to_non_nullable
+              as bool,
+      eligible: null == eligible
+          ? _self.eligible
+          : eligible // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+// dart format on
@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'cast.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'notify.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `complete_fetch`, `handle_datagram`, `handle_notify`, `recv_optional`, `remove_expired`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `fmt`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NotifyListener>>
+/// 监听 239.255.255.250:1900 上的 NOTIFY 广播，维护在线设备表。一个 NotifyListener 只对应一次监听，停止后需重新创建
+abstract class NotifyListener implements RustOpaqueInterface {
+  /// 当前在线设备快照
+  List<ProjectorInfo> devices();
+
+  /// notify_targets 为关注的 NT (如 urn:schemas-upnp-org:device:MediaRenderer:1)，为空时接收所有设备
+  factory NotifyListener({required List<String> notifyTargets}) =>
+      RustLib.instance.api.crateApiNotifyNotifyListenerNew(
+          notifyTargets: notifyTargets);
+
+  /// 开始监听，直到调用 stop() 为止；设备变化通过 sink 推送
+  Stream<DeviceEvent> run();
+
+  /// 停止监听；在 run() 开始前调用时 run() 会立即返回
+  void stop();
+}
+
+@freezed
+sealed class DeviceEvent with _$DeviceEvent {
+  const DeviceEvent._();
+
+  const factory DeviceEvent.added(
+    ProjectorInfo field0,
+  ) = DeviceEvent_Added;
+  const factory DeviceEvent.updated(
+    ProjectorInfo field0,
+  ) = DeviceEvent_Updated;
+  const factory DeviceEvent.removed(
+    ProjectorInfo field0,
+  ) = DeviceEvent_Removed;
+  const factory DeviceEvent.rebooted(
+    ProjectorInfo field0,
+  ) = DeviceEvent_Rebooted;
+  const factory DeviceEvent.descriptionChanged(
+    ProjectorInfo field0,
+  ) = DeviceEvent_DescriptionChanged;
+}
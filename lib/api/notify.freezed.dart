@@ -0,0 +1,719 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'notify.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$DeviceEvent {
+  ProjectorInfo get field0;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEventCopyWith<DeviceEvent> get copyWith =>
+      _$DeviceEventCopyWithImpl<DeviceEvent>(this as DeviceEvent, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'DeviceEvent(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEventCopyWith<$Res> {
+  factory $DeviceEventCopyWith(
+          DeviceEvent value, $Res Function(DeviceEvent) _then) =
+      _$DeviceEventCopyWithImpl;
+  @useResult
+  $Res call({ProjectorInfo field0});
+
+  $ProjectorInfoCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class _$DeviceEventCopyWithImpl<$Res> implements $DeviceEventCopyWith<$Res> {
+  _$DeviceEventCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent _self;
+  final $Res Function(DeviceEvent) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_self.copyWith(
+      field0: null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+    ));
+  }
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get field0 {
+    return $ProjectorInfoCopyWith<$Res>(_self.field0, (value) {
+      return _then(_self.copyWith(field0: value));
+    });
+  }
+}
+
+/// Adds pattern-matching-related methods to [DeviceEvent].
+extension DeviceEventPatterns on DeviceEvent {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DeviceEvent_Added value)? added,
+    TResult Function(DeviceEvent_Updated value)? updated,
+    TResult Function(DeviceEvent_Removed value)? removed,
+    TResult Function(DeviceEvent_Rebooted value)? rebooted,
+    TResult Function(DeviceEvent_DescriptionChanged value)? descriptionChanged,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added() when added != null:
+        return added(_that);
+      case DeviceEvent_Updated() when updated != null:
+        return updated(_that);
+      case DeviceEvent_Removed() when removed != null:
+        return removed(_that);
+      case DeviceEvent_Rebooted() when rebooted != null:
+        return rebooted(_that);
+      case DeviceEvent_DescriptionChanged() when descriptionChanged != null:
+        return descriptionChanged(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DeviceEvent_Added value) added,
+    required TResult Function(DeviceEvent_Updated value) updated,
+    required TResult Function(DeviceEvent_Removed value) removed,
+    required TResult Function(DeviceEvent_Rebooted value) rebooted,
+    required TResult Function(DeviceEvent_DescriptionChanged value) descriptionChanged,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added():
+        return added(_that);
+      case DeviceEvent_Updated():
+        return updated(_that);
+      case DeviceEvent_Removed():
+        return removed(_that);
+      case DeviceEvent_Rebooted():
+        return rebooted(_that);
+      case DeviceEvent_DescriptionChanged():
+        return descriptionChanged(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DeviceEvent_Added value)? added,
+    TResult? Function(DeviceEvent_Updated value)? updated,
+    TResult? Function(DeviceEvent_Removed value)? removed,
+    TResult? Function(DeviceEvent_Rebooted value)? rebooted,
+    TResult? Function(DeviceEvent_DescriptionChanged value)? descriptionChanged,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added() when added != null:
+        return added(_that);
+      case DeviceEvent_Updated() when updated != null:
+        return updated(_that);
+      case DeviceEvent_Removed() when removed != null:
+        return removed(_that);
+      case DeviceEvent_Rebooted() when rebooted != null:
+        return rebooted(_that);
+      case DeviceEvent_DescriptionChanged() when descriptionChanged != null:
+        return descriptionChanged(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ProjectorInfo field0)? added,
+    TResult Function(ProjectorInfo field0)? updated,
+    TResult Function(ProjectorInfo field0)? removed,
+    TResult Function(ProjectorInfo field0)? rebooted,
+    TResult Function(ProjectorInfo field0)? descriptionChanged,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added() when added != null:
+        return added(_that.field0);
+      case DeviceEvent_Updated() when updated != null:
+        return updated(_that.field0);
+      case DeviceEvent_Removed() when removed != null:
+        return removed(_that.field0);
+      case DeviceEvent_Rebooted() when rebooted != null:
+        return rebooted(_that.field0);
+      case DeviceEvent_DescriptionChanged() when descriptionChanged != null:
+        return descriptionChanged(_that.field0);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ProjectorInfo field0) added,
+    required TResult Function(ProjectorInfo field0) updated,
+    required TResult Function(ProjectorInfo field0) removed,
+    required TResult Function(ProjectorInfo field0) rebooted,
+    required TResult Function(ProjectorInfo field0) descriptionChanged,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added():
+        return added(_that.field0);
+      case DeviceEvent_Updated():
+        return updated(_that.field0);
+      case DeviceEvent_Removed():
+        return removed(_that.field0);
+      case DeviceEvent_Rebooted():
+        return rebooted(_that.field0);
+      case DeviceEvent_DescriptionChanged():
+        return descriptionChanged(_that.field0);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ProjectorInfo field0)? added,
+    TResult? Function(ProjectorInfo field0)? updated,
+    TResult? Function(ProjectorInfo field0)? removed,
+    TResult? Function(ProjectorInfo field0)? rebooted,
+    TResult? Function(ProjectorInfo field0)? descriptionChanged,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceEvent_Added() when added != null:
+        return added(_that.field0);
+      case DeviceEvent_Updated() when updated != null:
+        return updated(_that.field0);
+      case DeviceEvent_Removed() when removed != null:
+        return removed(_that.field0);
+      case DeviceEvent_Rebooted() when rebooted != null:
+        return rebooted(_that.field0);
+      case DeviceEvent_DescriptionChanged() when descriptionChanged != null:
+        return descriptionChanged(_that.field0);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class DeviceEvent_Added extends DeviceEvent {
+  const DeviceEvent_Added(this.field0) : super._();
+
+  @override
+  final ProjectorInfo field0;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEvent_AddedCopyWith<DeviceEvent_Added> get copyWith =>
+      _$DeviceEvent_AddedCopyWithImpl<DeviceEvent_Added>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent_Added &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'DeviceEvent.added(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEvent_AddedCopyWith<$Res>
+    implements $DeviceEventCopyWith<$Res> {
+  factory $DeviceEvent_AddedCopyWith(
+          DeviceEvent_Added value, $Res Function(DeviceEvent_Added) _then) =
+      _$DeviceEvent_AddedCopyWithImpl;
+  @override
+  @useResult
+  $Res call({ProjectorInfo field0});
+
+  @override
+  $ProjectorInfoCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class _$DeviceEvent_AddedCopyWithImpl<$Res>
+    implements $DeviceEvent_AddedCopyWith<$Res> {
+  _$DeviceEvent_AddedCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent_Added _self;
+  final $Res Function(DeviceEvent_Added) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(DeviceEvent_Added(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+    ));
+  }
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get field0 {
+    return $ProjectorInfoCopyWith<$Res>(_self.field0, (value) {
+      return _then(_self.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class DeviceEvent_Updated extends DeviceEvent {
+  const DeviceEvent_Updated(this.field0) : super._();
+
+  @override
+  final ProjectorInfo field0;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEvent_UpdatedCopyWith<DeviceEvent_Updated> get copyWith =>
+      _$DeviceEvent_UpdatedCopyWithImpl<DeviceEvent_Updated>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent_Updated &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'DeviceEvent.updated(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEvent_UpdatedCopyWith<$Res>
+    implements $DeviceEventCopyWith<$Res> {
+  factory $DeviceEvent_UpdatedCopyWith(
+          DeviceEvent_Updated value, $Res Function(DeviceEvent_Updated) _then) =
+      _$DeviceEvent_UpdatedCopyWithImpl;
+  @override
+  @useResult
+  $Res call({ProjectorInfo field0});
+
+  @override
+  $ProjectorInfoCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class _$DeviceEvent_UpdatedCopyWithImpl<$Res>
+    implements $DeviceEvent_UpdatedCopyWith<$Res> {
+  _$DeviceEvent_UpdatedCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent_Updated _self;
+  final $Res Function(DeviceEvent_Updated) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(DeviceEvent_Updated(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+    ));
+  }
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get field0 {
+    return $ProjectorInfoCopyWith<$Res>(_self.field0, (value) {
+      return _then(_self.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class DeviceEvent_Removed extends DeviceEvent {
+  const DeviceEvent_Removed(this.field0) : super._();
+
+  @override
+  final ProjectorInfo field0;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEvent_RemovedCopyWith<DeviceEvent_Removed> get copyWith =>
+      _$DeviceEvent_RemovedCopyWithImpl<DeviceEvent_Removed>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent_Removed &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'DeviceEvent.removed(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEvent_RemovedCopyWith<$Res>
+    implements $DeviceEventCopyWith<$Res> {
+  factory $DeviceEvent_RemovedCopyWith(
+          DeviceEvent_Removed value, $Res Function(DeviceEvent_Removed) _then) =
+      _$DeviceEvent_RemovedCopyWithImpl;
+  @override
+  @useResult
+  $Res call({ProjectorInfo field0});
+
+  @override
+  $ProjectorInfoCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class _$DeviceEvent_RemovedCopyWithImpl<$Res>
+    implements $DeviceEvent_RemovedCopyWith<$Res> {
+  _$DeviceEvent_RemovedCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent_Removed _self;
+  final $Res Function(DeviceEvent_Removed) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(DeviceEvent_Removed(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+    ));
+  }
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get field0 {
+    return $ProjectorInfoCopyWith<$Res>(_self.field0, (value) {
+      return _then(_self.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class DeviceEvent_Rebooted extends DeviceEvent {
+  const DeviceEvent_Rebooted(this.field0) : super._();
+
+  @override
+  final ProjectorInfo field0;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEvent_RebootedCopyWith<DeviceEvent_Rebooted> get copyWith =>
+      _$DeviceEvent_RebootedCopyWithImpl<DeviceEvent_Rebooted>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent_Rebooted &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'DeviceEvent.rebooted(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEvent_RebootedCopyWith<$Res>
+    implements $DeviceEventCopyWith<$Res> {
+  factory $DeviceEvent_RebootedCopyWith(
+          DeviceEvent_Rebooted value, $Res Function(DeviceEvent_Rebooted) _then) =
+      _$DeviceEvent_RebootedCopyWithImpl;
+  @override
+  @useResult
+  $Res call({ProjectorInfo field0});
+
+  @override
+  $ProjectorInfoCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class _$DeviceEvent_RebootedCopyWithImpl<$Res>
+    implements $DeviceEvent_RebootedCopyWith<$Res> {
+  _$DeviceEvent_RebootedCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent_Rebooted _self;
+  final $Res Function(DeviceEvent_Rebooted) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(DeviceEvent_Rebooted(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+    ));
+  }
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get field0 {
+    return $ProjectorInfoCopyWith<$Res>(_self.field0, (value) {
+      return _then(_self.copyWith(field0: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class DeviceEvent_DescriptionChanged extends DeviceEvent {
+  const DeviceEvent_DescriptionChanged(this.field0) : super._();
+
+  @override
+  final ProjectorInfo field0;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceEvent_DescriptionChangedCopyWith<DeviceEvent_DescriptionChanged> get copyWith =>
+      _$DeviceEvent_DescriptionChangedCopyWithImpl<DeviceEvent_DescriptionChanged>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceEvent_DescriptionChanged &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'DeviceEvent.descriptionChanged(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceEvent_DescriptionChangedCopyWith<$Res>
+    implements $DeviceEventCopyWith<$Res> {
+  factory $DeviceEvent_DescriptionChangedCopyWith(
+          DeviceEvent_DescriptionChanged value, $Res Function(DeviceEvent_DescriptionChanged) _then) =
+      _$DeviceEvent_DescriptionChangedCopyWithImpl;
+  @override
+  @useResult
+  $Res call({ProjectorInfo field0});
+
+  @override
+  $ProjectorInfoCopyWith<$Res> get field0;
+}
+
+/// @nodoc
+class _$DeviceEvent_DescriptionChangedCopyWithImpl<$Res>
+    implements $DeviceEvent_DescriptionChangedCopyWith<$Res> {
+  _$DeviceEvent_DescriptionChangedCopyWithImpl(this._self, this._then);
+
+  final DeviceEvent_DescriptionChanged _self;
+  final $Res Function(DeviceEvent_DescriptionChanged) _then;
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(DeviceEvent_DescriptionChanged(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ProjectorInfo,
+    ));
+  }
+
+  /// Create a copy of DeviceEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $ProjectorInfoCopyWith<$Res> get field0 {
+    return $ProjectorInfoCopyWith<$Res>(_self.field0, (value) {
+      return _then(_self.copyWith(field0: value));
+    });
+  }
+}
+
+// dart format on
@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'scpd.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `download_scpd`, `ensure_action_supported`, `fetch_service_description`, `find_action`, `forget_service_descriptions`, `parse_action`, `parse_scpd`, `parse_state_variable`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+@freezed
+sealed class ActionArgument with _$ActionArgument {
+  const factory ActionArgument({
+    required String name,
+    required ArgumentDirection direction,
+    required String relatedStateVariable,
+  }) = _ActionArgument;
+}
+
+@freezed
+sealed class ActionInfo with _$ActionInfo {
+  const factory ActionInfo({
+    required String name,
+    required List<ActionArgument> arguments,
+  }) = _ActionInfo;
+}
+
+@freezed
+sealed class AllowedValueRange with _$AllowedValueRange {
+  const factory AllowedValueRange({
+    required String minimum,
+    required String maximum,
+    String? step,
+  }) = _AllowedValueRange;
+}
+
+enum ArgumentDirection {
+  in_,
+  out,
+  ;
+}
+
+/// 服务描述 (SCPD)：支持的动作及状态变量
+@freezed
+sealed class ServiceDescription with _$ServiceDescription {
+  const ServiceDescription._();
+  const factory ServiceDescription({
+    required String serviceType,
+    required String scpdUrl,
+    required List<ActionInfo> actions,
+    required List<StateVariable> stateVariables,
+  }) = _ServiceDescription;
+
+  /// 服务是否实现了指定动作
+  bool supportsAction({required String action}) => RustLib.instance.api
+      .crateApiScpdServiceDescriptionSupportsAction(that: this, action: action);
+}
+
+@freezed
+sealed class StateVariable with _$StateVariable {
+  const factory StateVariable({
+    required String name,
+    required String dataType,
+    required bool sendEvents,
+    String? defaultValue,
+    required List<String> allowedValues,
+    AllowedValueRange? allowedValueRange,
+  }) = _StateVariable;
+}
@@ -0,0 +1,1780 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'scpd.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$ActionArgument {
+  String get name;
+  ArgumentDirection get direction;
+  String get relatedStateVariable;
+
+  /// Create a copy of ActionArgument
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ActionArgumentCopyWith<ActionArgument> get copyWith =>
+      _$ActionArgumentCopyWithImpl<ActionArgument>(
+          this as ActionArgument, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ActionArgument &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.direction, direction) ||
+                other.direction == direction) &&
+            (identical(other.relatedStateVariable, relatedStateVariable) ||
+                other.relatedStateVariable == relatedStateVariable));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, direction,
+      relatedStateVariable);
+
+  @override
+  String toString() {
+    return 'ActionArgument(name: $name, direction: $direction, relatedStateVariable: $relatedStateVariable)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ActionArgumentCopyWith<$Res> {
+  factory $ActionArgumentCopyWith(
+          ActionArgument value, $Res Function(ActionArgument) _then) =
+      _$ActionArgumentCopyWithImpl;
+  @useResult
+  $Res call(
+      {String name, ArgumentDirection direction, String relatedStateVariable});
+}
+
+/// @nodoc
+class _$ActionArgumentCopyWithImpl<$Res>
+    implements $ActionArgumentCopyWith<$Res> {
+  _$ActionArgumentCopyWithImpl(this._self, this._then);
+
+  final ActionArgument _self;
+  final $Res Function(ActionArgument) _then;
+
+  /// Create a copy of ActionArgument
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? direction = null,
+    Object? relatedStateVariable = null,
+  }) {
+    return _then(_self.copyWith(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      direction: null == direction
+          ? _self.direction
+          : direction // ignore: cast_nullable_to_non_nullable
+              as ArgumentDirection,
+      relatedStateVariable: null == relatedStateVariable
+          ? _self.relatedStateVariable
+          : relatedStateVariable // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [ActionArgument].
+extension ActionArgumentPatterns on ActionArgument {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_ActionArgument value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ActionArgument() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_ActionArgument value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ActionArgument():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_ActionArgument value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ActionArgument() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String name, ArgumentDirection direction,
+            String relatedStateVariable)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ActionArgument() when $default != null:
+        return $default(_that.name, _that.direction,
+            _that.relatedStateVariable);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String name, ArgumentDirection direction,
+            String relatedStateVariable)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ActionArgument():
+        return $default(_that.name, _that.direction,
+            _that.relatedStateVariable);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String name, ArgumentDirection direction,
+            String relatedStateVariable)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ActionArgument() when $default != null:
+        return $default(_that.name, _that.direction,
+            _that.relatedStateVariable);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _ActionArgument implements ActionArgument {
+  const _ActionArgument(
+      {required this.name,
+      required this.direction,
+      required this.relatedStateVariable});
+
+  @override
+  final String name;
+  @override
+  final ArgumentDirection direction;
+  @override
+  final String relatedStateVariable;
+
+  /// Create a copy of ActionArgument
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$ActionArgumentCopyWith<_ActionArgument> get copyWith =>
+      __$ActionArgumentCopyWithImpl<_ActionArgument>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _ActionArgument &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.direction, direction) ||
+                other.direction == direction) &&
+            (identical(other.relatedStateVariable, relatedStateVariable) ||
+                other.relatedStateVariable == relatedStateVariable));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, direction,
+      relatedStateVariable);
+
+  @override
+  String toString() {
+    return 'ActionArgument(name: $name, direction: $direction, relatedStateVariable: $relatedStateVariable)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$ActionArgumentCopyWith<$Res>
+    implements $ActionArgumentCopyWith<$Res> {
+  factory _$ActionArgumentCopyWith(
+          _ActionArgument value, $Res Function(_ActionArgument) _then) =
+      __$ActionArgumentCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {String name, ArgumentDirection direction, String relatedStateVariable});
+}
+
+/// @nodoc
+class __$ActionArgumentCopyWithImpl<$Res>
+    implements _$ActionArgumentCopyWith<$Res> {
+  __$ActionArgumentCopyWithImpl(this._self, this._then);
+
+  final _ActionArgument _self;
+  final $Res Function(_ActionArgument) _then;
+
+  /// Create a copy of ActionArgument
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? name = null,
+    Object? direction = null,
+    Object? relatedStateVariable = null,
+  }) {
+    return _then(_ActionArgument(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      direction: null == direction
+          ? _self.direction
+          : direction // ignore: cast_nullable_to_non_nullable
+              as ArgumentDirection,
+      relatedStateVariable: null == relatedStateVariable
+          ? _self.relatedStateVariable
+          : relatedStateVariable // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$ActionInfo {
+  String get name;
+  List<ActionArgument> get arguments;
+
+  /// Create a copy of ActionInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ActionInfoCopyWith<ActionInfo> get copyWith =>
+      _$ActionInfoCopyWithImpl<ActionInfo>(this as ActionInfo, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ActionInfo &&
+            (identical(other.name, name) || other.name == name) &&
+            const DeepCollectionEquality().equals(other.arguments, arguments));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name,
+      const DeepCollectionEquality().hash(arguments));
+
+  @override
+  String toString() {
+    return 'ActionInfo(name: $name, arguments: $arguments)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ActionInfoCopyWith<$Res> {
+  factory $ActionInfoCopyWith(
+          ActionInfo value, $Res Function(ActionInfo) _then) =
+      _$ActionInfoCopyWithImpl;
+  @useResult
+  $Res call({String name, List<ActionArgument> arguments});
+}
+
+/// @nodoc
+class _$ActionInfoCopyWithImpl<$Res> implements $ActionInfoCopyWith<$Res> {
+  _$ActionInfoCopyWithImpl(this._self, this._then);
+
+  final ActionInfo _self;
+  final $Res Function(ActionInfo) _then;
+
+  /// Create a copy of ActionInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? arguments = null,
+  }) {
+    return _then(_self.copyWith(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      arguments: null == arguments
+          ? _self.arguments
+          : arguments // ignore: cast_nullable_to_non_nullable
+              as List<ActionArgument>,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [ActionInfo].
+extension ActionInfoPatterns on ActionInfo {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_ActionInfo value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ActionInfo() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_ActionInfo value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ActionInfo():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_ActionInfo value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ActionInfo() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String name, List<ActionArgument> arguments)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ActionInfo() when $default != null:
+        return $default(_that.name, _that.arguments);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String name, List<ActionArgument> arguments) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ActionInfo():
+        return $default(_that.name, _that.arguments);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String name, List<ActionArgument> arguments)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ActionInfo() when $default != null:
+        return $default(_that.name, _that.arguments);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _ActionInfo implements ActionInfo {
+  const _ActionInfo(
+      {required this.name, required final List<ActionArgument> arguments})
+      : _arguments = arguments;
+
+  @override
+  final String name;
+  final List<ActionArgument> _arguments;
+  @override
+  List<ActionArgument> get arguments {
+    if (_arguments is EqualUnmodifiableListView) return _arguments;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_arguments);
+  }
+
+  /// Create a copy of ActionInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$ActionInfoCopyWith<_ActionInfo> get copyWith =>
+      __$ActionInfoCopyWithImpl<_ActionInfo>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _ActionInfo &&
+            (identical(other.name, name) || other.name == name) &&
+            const DeepCollectionEquality()
+                .equals(other._arguments, _arguments));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name,
+      const DeepCollectionEquality().hash(_arguments));
+
+  @override
+  String toString() {
+    return 'ActionInfo(name: $name, arguments: $arguments)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$ActionInfoCopyWith<$Res>
+    implements $ActionInfoCopyWith<$Res> {
+  factory _$ActionInfoCopyWith(
+          _ActionInfo value, $Res Function(_ActionInfo) _then) =
+      __$ActionInfoCopyWithImpl;
+  @override
+  @useResult
+  $Res call({String name, List<ActionArgument> arguments});
+}
+
+/// @nodoc
+class __$ActionInfoCopyWithImpl<$Res> implements _$ActionInfoCopyWith<$Res> {
+  __$ActionInfoCopyWithImpl(this._self, this._then);
+
+  final _ActionInfo _self;
+  final $Res Function(_ActionInfo) _then;
+
+  /// Create a copy of ActionInfo
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? name = null,
+    Object? arguments = null,
+  }) {
+    return _then(_ActionInfo(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      arguments: null == arguments
+          ? _self._arguments
+          : arguments // ignore: cast_nullable_to_non_nullable
+              as List<ActionArgument>,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$AllowedValueRange {
+  String get minimum;
+  String get maximum;
+  String? get step;
+
+  /// Create a copy of AllowedValueRange
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $AllowedValueRangeCopyWith<AllowedValueRange> get copyWith =>
+      _$AllowedValueRangeCopyWithImpl<AllowedValueRange>(
+          this as AllowedValueRange, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is AllowedValueRange &&
+            (identical(other.minimum, minimum) || other.minimum == minimum) &&
+            (identical(other.maximum, maximum) || other.maximum == maximum) &&
+            (identical(other.step, step) || other.step == step));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, minimum, maximum, step);
+
+  @override
+  String toString() {
+    return 'AllowedValueRange(minimum: $minimum, maximum: $maximum, step: $step)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $AllowedValueRangeCopyWith<$Res> {
+  factory $AllowedValueRangeCopyWith(
+          AllowedValueRange value, $Res Function(AllowedValueRange) _then) =
+      _$AllowedValueRangeCopyWithImpl;
+  @useResult
+  $Res call({String minimum, String maximum, String? step});
+}
+
+/// @nodoc
+class _$AllowedValueRangeCopyWithImpl<$Res>
+    implements $AllowedValueRangeCopyWith<$Res> {
+  _$AllowedValueRangeCopyWithImpl(this._self, this._then);
+
+  final AllowedValueRange _self;
+  final $Res Function(AllowedValueRange) _then;
+
+  /// Create a copy of AllowedValueRange
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? minimum = null,
+    Object? maximum = null,
+    Object? step = freezed,
+  }) {
+    return _then(_self.copyWith(
+      minimum: null == minimum
+          ? _self.minimum
+          : minimum // ignore: cast_nullable_to_non_nullable
+              as String,
+      maximum: null == maximum
+          ? _self.maximum
+          : maximum // ignore: cast_nullable_to_non_nullable
+              as String,
+      step: freezed == step
+          ? _self.step
+          : step // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [AllowedValueRange].
+extension AllowedValueRangePatterns on AllowedValueRange {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_AllowedValueRange value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _AllowedValueRange() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_AllowedValueRange value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _AllowedValueRange():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_AllowedValueRange value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _AllowedValueRange() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String minimum, String maximum, String? step)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _AllowedValueRange() when $default != null:
+        return $default(_that.minimum, _that.maximum, _that.step);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String minimum, String maximum, String? step) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _AllowedValueRange():
+        return $default(_that.minimum, _that.maximum, _that.step);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String minimum, String maximum, String? step)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _AllowedValueRange() when $default != null:
+        return $default(_that.minimum, _that.maximum, _that.step);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _AllowedValueRange implements AllowedValueRange {
+  const _AllowedValueRange(
+      {required this.minimum, required this.maximum, this.step});
+
+  @override
+  final String minimum;
+  @override
+  final String maximum;
+  @override
+  final String? step;
+
+  /// Create a copy of AllowedValueRange
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$AllowedValueRangeCopyWith<_AllowedValueRange> get copyWith =>
+      __$AllowedValueRangeCopyWithImpl<_AllowedValueRange>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _AllowedValueRange &&
+            (identical(other.minimum, minimum) || other.minimum == minimum) &&
+            (identical(other.maximum, maximum) || other.maximum == maximum) &&
+            (identical(other.step, step) || other.step == step));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, minimum, maximum, step);
+
+  @override
+  String toString() {
+    return 'AllowedValueRange(minimum: $minimum, maximum: $maximum, step: $step)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$AllowedValueRangeCopyWith<$Res>
+    implements $AllowedValueRangeCopyWith<$Res> {
+  factory _$AllowedValueRangeCopyWith(
+          _AllowedValueRange value, $Res Function(_AllowedValueRange) _then) =
+      __$AllowedValueRangeCopyWithImpl;
+  @override
+  @useResult
+  $Res call({String minimum, String maximum, String? step});
+}
+
+/// @nodoc
+class __$AllowedValueRangeCopyWithImpl<$Res>
+    implements _$AllowedValueRangeCopyWith<$Res> {
+  __$AllowedValueRangeCopyWithImpl(this._self, this._then);
+
+  final _AllowedValueRange _self;
+  final $Res Function(_AllowedValueRange) _then;
+
+  /// Create a copy of AllowedValueRange
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? minimum = null,
+    Object? maximum = null,
+    Object? step = freezed,
+  }) {
+    return _then(_AllowedValueRange(
+      minimum: null == minimum
+          ? _self.minimum
+          : minimum // ignore: cast_nullable_to_non_nullable
+              as String,
+      maximum: null == maximum
+          ? _self.maximum
+          : maximum // ignore: cast_nullable_to_non_nullable
+              as String,
+      step: freezed == step
+          ? _self.step
+          : step // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$ServiceDescription {
+  String get serviceType;
+  String get scpdUrl;
+  List<ActionInfo> get actions;
+  List<StateVariable> get stateVariables;
+
+  /// Create a copy of ServiceDescription
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $ServiceDescriptionCopyWith<ServiceDescription> get copyWith =>
+      _$ServiceDescriptionCopyWithImpl<ServiceDescription>(
+          this as ServiceDescription, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is ServiceDescription &&
+            (identical(other.serviceType, serviceType) ||
+                other.serviceType == serviceType) &&
+            (identical(other.scpdUrl, scpdUrl) || other.scpdUrl == scpdUrl) &&
+            const DeepCollectionEquality().equals(other.actions, actions) &&
+            const DeepCollectionEquality()
+                .equals(other.stateVariables, stateVariables));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, serviceType, scpdUrl,
+      const DeepCollectionEquality().hash(actions),
+      const DeepCollectionEquality().hash(stateVariables));
+
+  @override
+  String toString() {
+    return 'ServiceDescription(serviceType: $serviceType, scpdUrl: $scpdUrl, actions: $actions, stateVariables: $stateVariables)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $ServiceDescriptionCopyWith<$Res> {
+  factory $ServiceDescriptionCopyWith(
+          ServiceDescription value, $Res Function(ServiceDescription) _then) =
+      _$ServiceDescriptionCopyWithImpl;
+  @useResult
+  $Res call(
+      {String serviceType,
+      String scpdUrl,
+      List<ActionInfo> actions,
+      List<StateVariable> stateVariables});
+}
+
+/// @nodoc
+class _$ServiceDescriptionCopyWithImpl<$Res>
+    implements $ServiceDescriptionCopyWith<$Res> {
+  _$ServiceDescriptionCopyWithImpl(this._self, this._then);
+
+  final ServiceDescription _self;
+  final $Res Function(ServiceDescription) _then;
+
+  /// Create a copy of ServiceDescription
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? serviceType = null,
+    Object? scpdUrl = null,
+    Object? actions = null,
+    Object? stateVariables = null,
+  }) {
+    return _then(_self.copyWith(
+      serviceType: null == serviceType
+          ? _self.serviceType
+          : serviceType // ignore: cast_nullable_to_non_nullable
+              as String,
+      scpdUrl: null == scpdUrl
+          ? _self.scpdUrl
+          : scpdUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+      actions: null == actions
+          ? _self.actions
+          : actions // ignore: cast_nullable_to_non_nullable
+              as List<ActionInfo>,
+      stateVariables: null == stateVariables
+          ? _self.stateVariables
+          : stateVariables // ignore: cast_nullable_to_non_nullable
+              as List<StateVariable>,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [ServiceDescription].
+extension ServiceDescriptionPatterns on ServiceDescription {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_ServiceDescription value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceDescription() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_ServiceDescription value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceDescription():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_ServiceDescription value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceDescription() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String serviceType, String scpdUrl,
+            List<ActionInfo> actions, List<StateVariable> stateVariables)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceDescription() when $default != null:
+        return $default(_that.serviceType, _that.scpdUrl, _that.actions,
+            _that.stateVariables);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String serviceType, String scpdUrl,
+            List<ActionInfo> actions, List<StateVariable> stateVariables)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceDescription():
+        return $default(_that.serviceType, _that.scpdUrl, _that.actions,
+            _that.stateVariables);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String serviceType, String scpdUrl,
+            List<ActionInfo> actions, List<StateVariable> stateVariables)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _ServiceDescription() when $default != null:
+        return $default(_that.serviceType, _that.scpdUrl, _that.actions,
+            _that.stateVariables);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _ServiceDescription extends ServiceDescription {
+  const _ServiceDescription(
+      {required this.serviceType,
+      required this.scpdUrl,
+      required final List<ActionInfo> actions,
+      required final List<StateVariable> stateVariables})
+      : _actions = actions,
+        _stateVariables = stateVariables,
+        super._();
+
+  @override
+  final String serviceType;
+  @override
+  final String scpdUrl;
+  final List<ActionInfo> _actions;
+  @override
+  List<ActionInfo> get actions {
+    if (_actions is EqualUnmodifiableListView) return _actions;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_actions);
+  }
+
+  final List<StateVariable> _stateVariables;
+  @override
+  List<StateVariable> get stateVariables {
+    if (_stateVariables is EqualUnmodifiableListView) return _stateVariables;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_stateVariables);
+  }
+
+  /// Create a copy of ServiceDescription
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$ServiceDescriptionCopyWith<_ServiceDescription> get copyWith =>
+      __$ServiceDescriptionCopyWithImpl<_ServiceDescription>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _ServiceDescription &&
+            (identical(other.serviceType, serviceType) ||
+                other.serviceType == serviceType) &&
+            (identical(other.scpdUrl, scpdUrl) || other.scpdUrl == scpdUrl) &&
+            const DeepCollectionEquality().equals(other._actions, _actions) &&
+            const DeepCollectionEquality()
+                .equals(other._stateVariables, _stateVariables));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, serviceType, scpdUrl,
+      const DeepCollectionEquality().hash(_actions),
+      const DeepCollectionEquality().hash(_stateVariables));
+
+  @override
+  String toString() {
+    return 'ServiceDescription(serviceType: $serviceType, scpdUrl: $scpdUrl, actions: $actions, stateVariables: $stateVariables)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$ServiceDescriptionCopyWith<$Res>
+    implements $ServiceDescriptionCopyWith<$Res> {
+  factory _$ServiceDescriptionCopyWith(
+          _ServiceDescription value, $Res Function(_ServiceDescription) _then) =
+      __$ServiceDescriptionCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {String serviceType,
+      String scpdUrl,
+      List<ActionInfo> actions,
+      List<StateVariable> stateVariables});
+}
+
+/// @nodoc
+class __$ServiceDescriptionCopyWithImpl<$Res>
+    implements _$ServiceDescriptionCopyWith<$Res> {
+  __$ServiceDescriptionCopyWithImpl(this._self, this._then);
+
+  final _ServiceDescription _self;
+  final $Res Function(_ServiceDescription) _then;
+
+  /// Create a copy of ServiceDescription
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? serviceType = null,
+    Object? scpdUrl = null,
+    Object? actions = null,
+    Object? stateVariables = null,
+  }) {
+    return _then(_ServiceDescription(
+      serviceType: null == serviceType
+          ? _self.serviceType
+          : serviceType // ignore: cast_nullable_to_non_nullable
+              as String,
+      scpdUrl: null == scpdUrl
+          ? _self.scpdUrl
+          : scpdUrl // ignore: cast_nullable_to_non_nullable
+              as String,
+      actions: null == actions
+          ? _self._actions
+          : actions // ignore: cast_nullable_to_non_nullable
+              as List<ActionInfo>,
+      stateVariables: null == stateVariables
+          ? _self._stateVariables
+          : stateVariables // ignore: cast_nullable_to_non_nullable
+              as List<StateVariable>,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$StateVariable {
+  String get name;
+  String get dataType;
+  bool get sendEvents;
+  String? get defaultValue;
+  List<String> get allowedValues;
+  AllowedValueRange? get allowedValueRange;
+
+  /// Create a copy of StateVariable
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $StateVariableCopyWith<StateVariable> get copyWith =>
+      _$StateVariableCopyWithImpl<StateVariable>(
+          this as StateVariable, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is StateVariable &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.dataType, dataType) ||
+                other.dataType == dataType) &&
+            (identical(other.sendEvents, sendEvents) ||
+                other.sendEvents == sendEvents) &&
+            (identical(other.defaultValue, defaultValue) ||
+                other.defaultValue == defaultValue) &&
+            const DeepCollectionEquality()
+                .equals(other.allowedValues, allowedValues) &&
+            (identical(other.allowedValueRange, allowedValueRange) ||
+                other.allowedValueRange == allowedValueRange));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, dataType, sendEvents,
+      defaultValue, const DeepCollectionEquality().hash(allowedValues),
+      allowedValueRange);
+
+  @override
+  String toString() {
+    return 'StateVariable(name: $name, dataType: $dataType, sendEvents: $sendEvents, defaultValue: $defaultValue, allowedValues: $allowedValues, allowedValueRange: $allowedValueRange)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $StateVariableCopyWith<$Res> {
+  factory $StateVariableCopyWith(
+          StateVariable value, $Res Function(StateVariable) _then) =
+      _$StateVariableCopyWithImpl;
+  @useResult
+  $Res call(
+      {String name,
+      String dataType,
+      bool sendEvents,
+      String? defaultValue,
+      List<String> allowedValues,
+      AllowedValueRange? allowedValueRange});
+
+  $AllowedValueRangeCopyWith<$Res>? get allowedValueRange;
+}
+
+/// @nodoc
+class _$StateVariableCopyWithImpl<$Res>
+    implements $StateVariableCopyWith<$Res> {
+  _$StateVariableCopyWithImpl(this._self, this._then);
+
+  final StateVariable _self;
+  final $Res Function(StateVariable) _then;
+
+  /// Create a copy of StateVariable
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? dataType = null,
+    Object? sendEvents = null,
+    Object? defaultValue = freezed,
+    Object? allowedValues = null,
+    Object? allowedValueRange = freezed,
+  }) {
+    return _then(_self.copyWith(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      dataType: null == dataType
+          ? _self.dataType
+          : dataType // ignore: cast_nullable_to_non_nullable
+              as String,
+      sendEvents: null == sendEvents
+          ? _self.sendEvents
+          : sendEvents // ignore: cast_nullable_to_non_nullable
+              as bool,
+      defaultValue: freezed == defaultValue
+          ? _self.defaultValue
+          : defaultValue // ignore: cast_nullable_to_non_nullable
+              as String?,
+      allowedValues: null == allowedValues
+          ? _self.allowedValues
+          : allowedValues // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      allowedValueRange: freezed == allowedValueRange
+          ? _self.allowedValueRange
+          : allowedValueRange // ignore: cast_nullable_to_non_nullable
+              as AllowedValueRange?,
+    ));
+  }
+
+  /// Create a copy of StateVariable
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $AllowedValueRangeCopyWith<$Res>? get allowedValueRange {
+    if (_self.allowedValueRange == null) {
+      return null;
+    }
+
+    return $AllowedValueRangeCopyWith<$Res>(_self.allowedValueRange!, (value) {
+      return _then(_self.copyWith(allowedValueRange: value));
+    });
+  }
+}
+
+/// Adds pattern-matching-related methods to [StateVariable].
+extension StateVariablePatterns on StateVariable {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_StateVariable value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _StateVariable() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_StateVariable value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _StateVariable():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_StateVariable value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _StateVariable() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String name, String dataType, bool sendEvents,
+            String? defaultValue, List<String> allowedValues,
+            AllowedValueRange? allowedValueRange)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _StateVariable() when $default != null:
+        return $default(_that.name, _that.dataType, _that.sendEvents,
+            _that.defaultValue, _that.allowedValues, _that.allowedValueRange);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String name, String dataType, bool sendEvents,
+            String? defaultValue, List<String> allowedValues,
+            AllowedValueRange? allowedValueRange)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _StateVariable():
+        return $default(_that.name, _that.dataType, _that.sendEvents,
+            _that.defaultValue, _that.allowedValues, _that.allowedValueRange);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String name, String dataType, bool sendEvents,
+            String? defaultValue, List<String> allowedValues,
+            AllowedValueRange? allowedValueRange)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _StateVariable() when $default != null:
+        return $default(_that.name, _that.dataType, _that.sendEvents,
+            _that.defaultValue, _that.allowedValues, _that.allowedValueRange);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _StateVariable implements StateVariable {
+  const _StateVariable(
+      {required this.name,
+      required this.dataType,
+      required this.sendEvents,
+      this.defaultValue,
+      required final List<String> allowedValues,
+      this.allowedValueRange})
+      : _allowedValues = allowedValues;
+
+  @override
+  final String name;
+  @override
+  final String dataType;
+  @override
+  final bool sendEvents;
+  @override
+  final String? defaultValue;
+  final List<String> _allowedValues;
+  @override
+  List<String> get allowedValues {
+    if (_allowedValues is EqualUnmodifiableListView) return _allowedValues;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_allowedValues);
+  }
+
+  @override
+  final AllowedValueRange? allowedValueRange;
+
+  /// Create a copy of StateVariable
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$StateVariableCopyWith<_StateVariable> get copyWith =>
+      __$StateVariableCopyWithImpl<_StateVariable>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _StateVariable &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.dataType, dataType) ||
+                other.dataType == dataType) &&
+            (identical(other.sendEvents, sendEvents) ||
+                other.sendEvents == sendEvents) &&
+            (identical(other.defaultValue, defaultValue) ||
+                other.defaultValue == defaultValue) &&
+            const DeepCollectionEquality()
+                .equals(other._allowedValues, _allowedValues) &&
+            (identical(other.allowedValueRange, allowedValueRange) ||
+                other.allowedValueRange == allowedValueRange));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, dataType, sendEvents,
+      defaultValue, const DeepCollectionEquality().hash(_allowedValues),
+      allowedValueRange);
+
+  @override
+  String toString() {
+    return 'StateVariable(name: $name, dataType: $dataType, sendEvents: $sendEvents, defaultValue: $defaultValue, allowedValues: $allowedValues, allowedValueRange: $allowedValueRange)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$StateVariableCopyWith<$Res>
+    implements $StateVariableCopyWith<$Res> {
+  factory _$StateVariableCopyWith(
+          _StateVariable value, $Res Function(_StateVariable) _then) =
+      __$StateVariableCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {String name,
+      String dataType,
+      bool sendEvents,
+      String? defaultValue,
+      List<String> allowedValues,
+      AllowedValueRange? allowedValueRange});
+
+  @override
+  $AllowedValueRangeCopyWith<$Res>? get allowedValueRange;
+}
+
+/// @nodoc
+class __$StateVariableCopyWithImpl<$Res>
+    implements _$StateVariableCopyWith<$Res> {
+  __$StateVariableCopyWithImpl(this._self, this._then);
+
+  final _StateVariable _self;
+  final $Res Function(_StateVariable) _then;
+
+  /// Create a copy of StateVariable
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? name = null,
+    Object? dataType = null,
+    Object? sendEvents = null,
+    Object? defaultValue = freezed,
+    Object? allowedValues = null,
+    Object? allowedValueRange = freezed,
+  }) {
+    return _then(_StateVariable(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      dataType: null == dataType
+          ? _self.dataType
+          : dataType // ignore: cast_nullable_to_non_nullable
+              as String,
+      sendEvents: null == sendEvents
+          ? _self.sendEvents
+          : sendEvents // ignore: cast_nullable_to_non_nullable
+              as bool,
+      defaultValue: freezed == defaultValue
+          ? _self.defaultValue
+          : defaultValue // ignore: cast_nullable_to_non_nullable
+              as String?,
+      allowedValues: null == allowedValues
+          ? _self._allowedValues
+          : allowedValues // ignore: cast_nullable_to_non_nullable
+              as List<String>,
+      allowedValueRange: freezed == allowedValueRange
+          ? _self.allowedValueRange
+          : allowedValueRange // ignore: cast_nullable_to_non_nullable
+              as AllowedValueRange?,
+    ));
+  }
+
+  /// Create a copy of StateVariable
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $AllowedValueRangeCopyWith<$Res>? get allowedValueRange {
+    if (_self.allowedValueRange == null) {
+      return null;
+    }
+
+    return $AllowedValueRangeCopyWith<$Res>(_self.allowedValueRange!, (value) {
+      return _then(_self.copyWith(allowedValueRange: value));
+    });
+  }
+}
+
+// dart format on
@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'ssdp.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `change_since`, `find_header`, `parse_max_age`, `parse_number`, `parse_ssdp_message`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+@freezed
+sealed class SsdpHeader with _$SsdpHeader {
+  const factory SsdpHeader({
+    required String name,
+    required String value,
+  }) = _SsdpHeader;
+}
+
+/// 解析后的 SSDP 消息 (M-SEARCH 响应或 NOTIFY)
+@freezed
+sealed class SsdpMessage with _$SsdpMessage {
+  const SsdpMessage._();
+  const factory SsdpMessage({
+    required String startLine,
+    String? method,
+    int? statusCode,
+    String? location,
+    String? searchTarget,
+    String? notificationType,
+    String? notificationSubType,
+    String? usn,
+    String? server,
+    int? maxAge,
+    int? bootId,
+    int? nextBootId,
+    int? configId,
+    int? searchPort,
+    required List<SsdpHeader> headers,
+  }) = _SsdpMessage;
+
+  /// 按名称查找头部 (不区分大小写)，用于读取厂商自定义头部 (如 X-User-Agent)
+  String? header({required String name}) => RustLib.instance.api
+      .crateApiSsdpSsdpMessageHeader(that: this, name: name);
+}
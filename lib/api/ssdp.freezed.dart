@@ -0,0 +1,903 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'ssdp.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$SsdpHeader {
+  String get name;
+  String get value;
+
+  /// Create a copy of SsdpHeader
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $SsdpHeaderCopyWith<SsdpHeader> get copyWith =>
+      _$SsdpHeaderCopyWithImpl<SsdpHeader>(this as SsdpHeader, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is SsdpHeader &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.value, value) || other.value == value));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, value);
+
+  @override
+  String toString() {
+    return 'SsdpHeader(name: $name, value: $value)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $SsdpHeaderCopyWith<$Res> {
+  factory $SsdpHeaderCopyWith(
+          SsdpHeader value, $Res Function(SsdpHeader) _then) =
+      _$SsdpHeaderCopyWithImpl;
+  @useResult
+  $Res call({String name, String value});
+}
+
+/// @nodoc
+class _$SsdpHeaderCopyWithImpl<$Res> implements $SsdpHeaderCopyWith<$Res> {
+  _$SsdpHeaderCopyWithImpl(this._self, this._then);
+
+  final SsdpHeader _self;
+  final $Res Function(SsdpHeader) _then;
+
+  /// Create a copy of SsdpHeader
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? name = null,
+    Object? value = null,
+  }) {
+    return _then(_self.copyWith(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      value: null == value
+          ? _self.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [SsdpHeader].
+extension SsdpHeaderPatterns on SsdpHeader {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_SsdpHeader value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpHeader() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_SsdpHeader value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpHeader():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_SsdpHeader value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpHeader() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String name, String value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpHeader() when $default != null:
+        return $default(_that.name, _that.value);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String name, String value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpHeader():
+        return $default(_that.name, _that.value);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String name, String value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpHeader() when $default != null:
+        return $default(_that.name, _that.value);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _SsdpHeader implements SsdpHeader {
+  const _SsdpHeader({required this.name, required this.value});
+
+  @override
+  final String name;
+  @override
+  final String value;
+
+  /// Create a copy of SsdpHeader
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$SsdpHeaderCopyWith<_SsdpHeader> get copyWith =>
+      __$SsdpHeaderCopyWithImpl<_SsdpHeader>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _SsdpHeader &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.value, value) || other.value == value));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, name, value);
+
+  @override
+  String toString() {
+    return 'SsdpHeader(name: $name, value: $value)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$SsdpHeaderCopyWith<$Res>
+    implements $SsdpHeaderCopyWith<$Res> {
+  factory _$SsdpHeaderCopyWith(
+          _SsdpHeader value, $Res Function(_SsdpHeader) _then) =
+      __$SsdpHeaderCopyWithImpl;
+  @override
+  @useResult
+  $Res call({String name, String value});
+}
+
+/// @nodoc
+class __$SsdpHeaderCopyWithImpl<$Res> implements _$SsdpHeaderCopyWith<$Res> {
+  __$SsdpHeaderCopyWithImpl(this._self, this._then);
+
+  final _SsdpHeader _self;
+  final $Res Function(_SsdpHeader) _then;
+
+  /// Create a copy of SsdpHeader
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? name = null,
+    Object? value = null,
+  }) {
+    return _then(_SsdpHeader(
+      name: null == name
+          ? _self.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      value: null == value
+          ? _self.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+mixin _$SsdpMessage {
+  String get startLine;
+  String? get method;
+  int? get statusCode;
+  String? get location;
+  String? get searchTarget;
+  String? get notificationType;
+  String? get notificationSubType;
+  String? get usn;
+  String? get server;
+  int? get maxAge;
+  int? get bootId;
+  int? get nextBootId;
+  int? get configId;
+  int? get searchPort;
+  List<SsdpHeader> get headers;
+
+  /// Create a copy of SsdpMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $SsdpMessageCopyWith<SsdpMessage> get copyWith =>
+      _$SsdpMessageCopyWithImpl<SsdpMessage>(this as SsdpMessage, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is SsdpMessage &&
+            (identical(other.startLine, startLine) ||
+                other.startLine == startLine) &&
+            (identical(other.method, method) || other.method == method) &&
+            (identical(other.statusCode, statusCode) ||
+                other.statusCode == statusCode) &&
+            (identical(other.location, location) ||
+                other.location == location) &&
+            (identical(other.searchTarget, searchTarget) ||
+                other.searchTarget == searchTarget) &&
+            (identical(other.notificationType, notificationType) ||
+                other.notificationType == notificationType) &&
+            (identical(other.notificationSubType, notificationSubType) ||
+                other.notificationSubType == notificationSubType) &&
+            (identical(other.usn, usn) || other.usn == usn) &&
+            (identical(other.server, server) || other.server == server) &&
+            (identical(other.maxAge, maxAge) || other.maxAge == maxAge) &&
+            (identical(other.bootId, bootId) || other.bootId == bootId) &&
+            (identical(other.nextBootId, nextBootId) ||
+                other.nextBootId == nextBootId) &&
+            (identical(other.configId, configId) ||
+                other.configId == configId) &&
+            (identical(other.searchPort, searchPort) ||
+                other.searchPort == searchPort) &&
+            const DeepCollectionEquality().equals(other.headers, headers));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, startLine, method, statusCode,
+      location, searchTarget, notificationType, notificationSubType, usn,
+      server, maxAge, bootId, nextBootId, configId, searchPort,
+      const DeepCollectionEquality().hash(headers));
+
+  @override
+  String toString() {
+    return 'SsdpMessage(startLine: $startLine, method: $method, statusCode: $statusCode, location: $location, searchTarget: $searchTarget, notificationType: $notificationType, notificationSubType: $notificationSubType, usn: $usn, server: $server, maxAge: $maxAge, bootId: $bootId, nextBootId: $nextBootId, configId: $configId, searchPort: $searchPort, headers: $headers)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $SsdpMessageCopyWith<$Res> {
+  factory $SsdpMessageCopyWith(
+          SsdpMessage value, $Res Function(SsdpMessage) _then) =
+      _$SsdpMessageCopyWithImpl;
+  @useResult
+  $Res call(
+      {String startLine,
+      String? method,
+      int? statusCode,
+      String? location,
+      String? searchTarget,
+      String? notificationType,
+      String? notificationSubType,
+      String? usn,
+      String? server,
+      int? maxAge,
+      int? bootId,
+      int? nextBootId,
+      int? configId,
+      int? searchPort,
+      List<SsdpHeader> headers});
+}
+
+/// @nodoc
+class _$SsdpMessageCopyWithImpl<$Res> implements $SsdpMessageCopyWith<$Res> {
+  _$SsdpMessageCopyWithImpl(this._self, this._then);
+
+  final SsdpMessage _self;
+  final $Res Function(SsdpMessage) _then;
+
+  /// Create a copy of SsdpMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? startLine = null,
+    Object? method = freezed,
+    Object? statusCode = freezed,
+    Object? location = freezed,
+    Object? searchTarget = freezed,
+    Object? notificationType = freezed,
+    Object? notificationSubType = freezed,
+    Object? usn = freezed,
+    Object? server = freezed,
+    Object? maxAge = freezed,
+    Object? bootId = freezed,
+    Object? nextBootId = freezed,
+    Object? configId = freezed,
+    Object? searchPort = freezed,
+    Object? headers = null,
+  }) {
+    return _then(_self.copyWith(
+      startLine: null == startLine
+          ? _self.startLine
+          : startLine // ignore: cast_nullable_to_non_nullable
+              as String,
+      method: freezed == method
+          ? _self.method
+          : method // ignore: cast_nullable_to_non_nullable
+              as String?,
+      statusCode: freezed == statusCode
+          ? _self.statusCode
+          : statusCode // ignore: cast_nullable_to_non_nullable
+              as int?,
+      location: freezed == location
+          ? _self.location
+          : location // ignore: cast_nullable_to_non_nullable
+              as String?,
+      searchTarget: freezed == searchTarget
+          ? _self.searchTarget
+          : searchTarget // ignore: cast_nullable_to_non_nullable
+              as String?,
+      notificationType: freezed == notificationType
+          ? _self.notificationType
+          : notificationType // ignore: cast_nullable_to_non_nullable
+              as String?,
+      notificationSubType: freezed == notificationSubType
+          ? _self.notificationSubType
+          : notificationSubType // ignore: cast_nullable_to_non_nullable
+              as String?,
+      usn: freezed == usn
+          ? _self.usn
+          : usn // ignore: cast_nullable_to_non_nullable
+              as String?,
+      server: freezed == server
+          ? _self.server
+          : server // ignore: cast_nullable_to_non_nullable
+              as String?,
+      maxAge: freezed == maxAge
+          ? _self.maxAge
+          : maxAge // ignore: cast_nullable_to_non_nullable
+              as int?,
+      bootId: freezed == bootId
+          ? _self.bootId
+          : bootId // ignore: cast_nullable_to_non_nullable
+              as int?,
+      nextBootId: freezed == nextBootId
+          ? _self.nextBootId
+          : nextBootId // ignore: cast_nullable_to_non_nullable
+              as int?,
+      configId: freezed == configId
+          ? _self.configId
+          : configId // ignore: cast_nullable_to_non_nullable
+              as int?,
+      searchPort: freezed == searchPort
+          ? _self.searchPort
+          : searchPort // ignore: cast_nullable_to_non_nullable
+              as int?,
+      headers: null == headers
+          ? _self.headers
+          : headers // ignore: cast_nullable_to_non_nullable
+              as List<SsdpHeader>,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [SsdpMessage].
+extension SsdpMessagePatterns on SsdpMessage {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>(
+    TResult Function(_SsdpMessage value)? $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpMessage() when $default != null:
+        return $default(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>(
+    TResult Function(_SsdpMessage value) $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpMessage():
+        return $default(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>(
+    TResult? Function(_SsdpMessage value)? $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpMessage() when $default != null:
+        return $default(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>(
+    TResult Function(String startLine, String? method, int? statusCode,
+            String? location, String? searchTarget, String? notificationType,
+            String? notificationSubType, String? usn, String? server,
+            int? maxAge, int? bootId, int? nextBootId, int? configId,
+            int? searchPort, List<SsdpHeader> headers)?
+        $default, {
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpMessage() when $default != null:
+        return $default(_that.startLine, _that.method, _that.statusCode,
+            _that.location, _that.searchTarget, _that.notificationType,
+            _that.notificationSubType, _that.usn, _that.server, _that.maxAge,
+            _that.bootId, _that.nextBootId, _that.configId, _that.searchPort,
+            _that.headers);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>(
+    TResult Function(String startLine, String? method, int? statusCode,
+            String? location, String? searchTarget, String? notificationType,
+            String? notificationSubType, String? usn, String? server,
+            int? maxAge, int? bootId, int? nextBootId, int? configId,
+            int? searchPort, List<SsdpHeader> headers)
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpMessage():
+        return $default(_that.startLine, _that.method, _that.statusCode,
+            _that.location, _that.searchTarget, _that.notificationType,
+            _that.notificationSubType, _that.usn, _that.server, _that.maxAge,
+            _that.bootId, _that.nextBootId, _that.configId, _that.searchPort,
+            _that.headers);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>(
+    TResult? Function(String startLine, String? method, int? statusCode,
+            String? location, String? searchTarget, String? notificationType,
+            String? notificationSubType, String? usn, String? server,
+            int? maxAge, int? bootId, int? nextBootId, int? configId,
+            int? searchPort, List<SsdpHeader> headers)?
+        $default,
+  ) {
+    final _that = this;
+    switch (_that) {
+      case _SsdpMessage() when $default != null:
+        return $default(_that.startLine, _that.method, _that.statusCode,
+            _that.location, _that.searchTarget, _that.notificationType,
+            _that.notificationSubType, _that.usn, _that.server, _that.maxAge,
+            _that.bootId, _that.nextBootId, _that.configId, _that.searchPort,
+            _that.headers);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// @nodoc
+
+class _SsdpMessage extends SsdpMessage {
+  const _SsdpMessage(
+      {required this.startLine,
+      this.method,
+      this.statusCode,
+      this.location,
+      this.searchTarget,
+      this.notificationType,
+      this.notificationSubType,
+      this.usn,
+      this.server,
+      this.maxAge,
+      this.bootId,
+      this.nextBootId,
+      this.configId,
+      this.searchPort,
+      required final List<SsdpHeader> headers})
+      : _headers = headers,
+        super._();
+
+  @override
+  final String startLine;
+  @override
+  final String? method;
+  @override
+  final int? statusCode;
+  @override
+  final String? location;
+  @override
+  final String? searchTarget;
+  @override
+  final String? notificationType;
+  @override
+  final String? notificationSubType;
+  @override
+  final String? usn;
+  @override
+  final String? server;
+  @override
+  final int? maxAge;
+  @override
+  final int? bootId;
+  @override
+  final int? nextBootId;
+  @override
+  final int? configId;
+  @override
+  final int? searchPort;
+  final List<SsdpHeader> _headers;
+  @override
+  List<SsdpHeader> get headers {
+    if (_headers is EqualUnmodifiableListView) return _headers;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_headers);
+  }
+
+  /// Create a copy of SsdpMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  _$SsdpMessageCopyWith<_SsdpMessage> get copyWith =>
+      __$SsdpMessageCopyWithImpl<_SsdpMessage>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _SsdpMessage &&
+            (identical(other.startLine, startLine) ||
+                other.startLine == startLine) &&
+            (identical(other.method, method) || other.method == method) &&
+            (identical(other.statusCode, statusCode) ||
+                other.statusCode == statusCode) &&
+            (identical(other.location, location) ||
+                other.location == location) &&
+            (identical(other.searchTarget, searchTarget) ||
+                other.searchTarget == searchTarget) &&
+            (identical(other.notificationType, notificationType) ||
+                other.notificationType == notificationType) &&
+            (identical(other.notificationSubType, notificationSubType) ||
+                other.notificationSubType == notificationSubType) &&
+            (identical(other.usn, usn) || other.usn == usn) &&
+            (identical(other.server, server) || other.server == server) &&
+            (identical(other.maxAge, maxAge) || other.maxAge == maxAge) &&
+            (identical(other.bootId, bootId) || other.bootId == bootId) &&
+            (identical(other.nextBootId, nextBootId) ||
+                other.nextBootId == nextBootId) &&
+            (identical(other.configId, configId) ||
+                other.configId == configId) &&
+            (identical(other.searchPort, searchPort) ||
+                other.searchPort == searchPort) &&
+            const DeepCollectionEquality().equals(other._headers, _headers));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, startLine, method, statusCode,
+      location, searchTarget, notificationType, notificationSubType, usn,
+      server, maxAge, bootId, nextBootId, configId, searchPort,
+      const DeepCollectionEquality().hash(_headers));
+
+  @override
+  String toString() {
+    return 'SsdpMessage(startLine: $startLine, method: $method, statusCode: $statusCode, location: $location, searchTarget: $searchTarget, notificationType: $notificationType, notificationSubType: $notificationSubType, usn: $usn, server: $server, maxAge: $maxAge, bootId: $bootId, nextBootId: $nextBootId, configId: $configId, searchPort: $searchPort, headers: $headers)';
+  }
+}
+
+/// @nodoc
+abstract mixin class _$SsdpMessageCopyWith<$Res>
+    implements $SsdpMessageCopyWith<$Res> {
+  factory _$SsdpMessageCopyWith(
+          _SsdpMessage value, $Res Function(_SsdpMessage) _then) =
+      __$SsdpMessageCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {String startLine,
+      String? method,
+      int? statusCode,
+      String? location,
+      String? searchTarget,
+      String? notificationType,
+      String? notificationSubType,
+      String? usn,
+      String? server,
+      int? maxAge,
+      int? bootId,
+      int? nextBootId,
+      int? configId,
+      int? searchPort,
+      List<SsdpHeader> headers});
+}
+
+/// @nodoc
+class __$SsdpMessageCopyWithImpl<$Res> implements _$SsdpMessageCopyWith<$Res> {
+  __$SsdpMessageCopyWithImpl(this._self, this._then);
+
+  final _SsdpMessage _self;
+  final $Res Function(_SsdpMessage) _then;
+
+  /// Create a copy of SsdpMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? startLine = null,
+    Object? method = freezed,
+    Object? statusCode = freezed,
+    Object? location = freezed,
+    Object? searchTarget = freezed,
+    Object? notificationType = freezed,
+    Object? notificationSubType = freezed,
+    Object? usn = freezed,
+    Object? server = freezed,
+    Object? maxAge = freezed,
+    Object? bootId = freezed,
+    Object? nextBootId = freezed,
+    Object? configId = freezed,
+    Object? searchPort = freezed,
+    Object? headers = null,
+  }) {
+    return _then(_SsdpMessage(
+      startLine: null == startLine
+          ? _self.startLine
+          : startLine // ignore: cast_nullable_to_non_nullable
+              as String,
+      method: freezed == method
+          ? _self.method
+          : method // ignore: cast_nullable_to_non_nullable
+              as String?,
+      statusCode: freezed == statusCode
+          ? _self.statusCode
+          : statusCode // ignore: cast_nullable_to_non_nullable
+              as int?,
+      location: freezed == location
+          ? _self.location
+          : location // ignore: cast_nullable_to_non_nullable
+              as String?,
+      searchTarget: freezed == searchTarget
+          ? _self.searchTarget
+          : searchTarget // ignore: cast_nullable_to_non_nullable
+              as String?,
+      notificationType: freezed == notificationType
+          ? _self.notificationType
+          : notificationType // ignore: cast_nullable_to_non_nullable
+              as String?,
+      notificationSubType: freezed == notificationSubType
+          ? _self.notificationSubType
+          : notificationSubType // ignore: cast_nullable_to_non_nullable
+              as String?,
+      usn: freezed == usn
+          ? _self.usn
+          : usn // ignore: cast_nullable_to_non_nullable
+              as String?,
+      server: freezed == server
+          ? _self.server
+          : server // ignore: cast_nullable_to_non_nullable
+              as String?,
+      maxAge: freezed == maxAge
+          ? _self.maxAge
+          : maxAge // ignore: cast_nullable_to_non_nullable
+              as int?,
+      bootId: freezed == bootId
+          ? _self.bootId
+          : bootId // ignore: cast_nullable_to_non_nullable
+              as int?,
+      nextBootId: freezed == nextBootId
+          ? _self.nextBootId
+          : nextBootId // ignore: cast_nullable_to_non_nullable
+              as int?,
+      configId: freezed == configId
+          ? _self.configId
+          : configId // ignore: cast_nullable_to_non_nullable
+              as int?,
+      searchPort: freezed == searchPort
+          ? _self.searchPort
+          : searchPort // ignore: cast_nullable_to_non_nullable
+              as int?,
+      headers: null == headers
+          ? _self._headers
+          : headers // ignore: cast_nullable_to_non_nullable
+              as List<SsdpHeader>,
+    ));
+  }
+}
+
+// dart format on
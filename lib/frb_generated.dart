@@ -4,6 +4,12 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/cast.dart';
+import 'api/description.dart';
+import 'api/monitor.dart';
+import 'api/network.dart';
+import 'api/notify.dart';
+import 'api/scpd.dart';
+import 'api/ssdp.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -68,7 +74,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 496271499;
+  int get rustContentHash => 832639785;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,9 +85,58 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  DeviceMonitor crateApiMonitorDeviceMonitorNew(
+      {required List<ProjectorInfo> devices, required BigInt intervalSecs});
+
+  Stream<ReachabilityEvent> crateApiMonitorDeviceMonitorRun(
+      {required DeviceMonitor that});
+
+  void crateApiMonitorDeviceMonitorStop({required DeviceMonitor that});
+
+  List<ProjectorInfo> crateApiNotifyNotifyListenerDevices(
+      {required NotifyListener that});
+
+  NotifyListener crateApiNotifyNotifyListenerNew(
+      {required List<String> notifyTargets});
+
+  Stream<DeviceEvent> crateApiNotifyNotifyListenerRun(
+      {required NotifyListener that});
+
+  void crateApiNotifyNotifyListenerStop({required NotifyListener that});
+
+  void crateApiCastScanHandleCancel({required ScanHandle that});
+
+  ScanHandle crateApiCastScanHandleNew({required ScanOptions options});
+
+  Future<ScanReport> crateApiCastScanHandleRun({required ScanHandle that});
+
+  Stream<ScanEvent> crateApiCastScanHandleRunStream({required ScanHandle that});
+
+  Future<ProjectorInfo> crateApiCastAddDeviceByLocation({required String url});
+
+  Future<DeviceCapabilities> crateApiDescriptionDeviceCapabilitiesDefault();
+
+  List<ProjectorInfo> crateApiCastDevicesFromJson({required String json});
+
+  String crateApiCastDevicesToJson({required List<ProjectorInfo> devices});
+
+  Future<List<NetworkInterface>> crateApiNetworkListNetworkInterfaces();
+
+  Future<ProjectorInfo> crateApiCastProbeIp(
+      {required String ip, required BigInt timeoutSecs});
+
   Future<void> crateApiCastProjectorInfoCastVideo(
       {required ProjectorInfo that, required String videoUrl});
 
+  Future<DeviceStatus> crateApiCastProjectorInfoCheckAlive(
+      {required ProjectorInfo that, required BigInt timeoutMs});
+
+  Future<Uint8List> crateApiCastProjectorInfoFetchIcon(
+      {required ProjectorInfo that, required int preferredSize});
+
+  Future<List<ServiceDescription>> crateApiCastProjectorInfoFetchServices(
+      {required ProjectorInfo that});
+
   Future<(String, String)> crateApiCastProjectorInfoGetPositionInfo(
       {required ProjectorInfo that});
 
@@ -93,10 +148,16 @@ abstract class RustLibApi extends BaseApi {
 
   Future<int> crateApiCastProjectorInfoGetVolume({required ProjectorInfo that});
 
+  List<DeviceIcon> crateApiCastProjectorInfoIcons(
+      {required ProjectorInfo that});
+
   Future<void> crateApiCastProjectorInfoPause({required ProjectorInfo that});
 
   Future<void> crateApiCastProjectorInfoPlay({required ProjectorInfo that});
 
+  Future<ProjectorInfo> crateApiCastProjectorInfoRefresh(
+      {required ProjectorInfo that, required BigInt timeoutSecs});
+
   Future<void> crateApiCastProjectorInfoSeek(
       {required ProjectorInfo that, required String targetTime});
 
@@ -108,10 +169,74 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiCastProjectorInfoStop({required ProjectorInfo that});
 
+  Future<bool> crateApiCastProjectorInfoSupportsAction(
+      {required ProjectorInfo that,
+      required String serviceType,
+      required String action});
+
+  Future<void> crateApiCastProjectorInfoWake({required ProjectorInfo that});
+
+  Future<ProjectorInfo> crateApiCastResolveDevice(
+      {required String udn, required BigInt timeoutSecs});
+
+  Future<ScanDiagnostics> crateApiCastScanDiagnosticsDefault();
+
+  ScanOptions crateApiCastScanOptionsWithTimeout({required BigInt timeoutSecs});
+
   Future<List<ProjectorInfo>> crateApiCastScanProjectors(
       {required BigInt timeoutSecs});
 
+  Stream<ScanEvent> crateApiCastScanProjectorsStream(
+      {required ScanOptions options});
+
+  Future<List<ProjectorInfo>> crateApiCastScanProjectorsWithOptions(
+      {required ScanOptions options});
+
+  Future<ScanReport> crateApiCastScanProjectorsWithReport(
+      {required ScanOptions options});
+
+  bool crateApiScpdServiceDescriptionSupportsAction(
+      {required ServiceDescription that, required String action});
+
+  String? crateApiSsdpSsdpMessageHeader(
+      {required SsdpMessage that, required String name});
+
+  Future<ProjectorInfo> crateApiCastWakeAndWait(
+      {required String macAddress,
+      required String udnOrIp,
+      required BigInt timeoutSecs});
+
   Future<void> crateApiCastWakeOnLan({required String macAddress});
+
+  Future<void> crateApiCastWakeOnLanWithOptions(
+      {required String macAddress, required WakeOptions options});
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_DeviceMonitor;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_DeviceMonitor;
+
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_DeviceMonitorPtr;
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_NotifyListener;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_NotifyListener;
+
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_NotifyListenerPtr;
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_ScanHandle;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_ScanHandle;
+
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_ScanHandlePtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -123,632 +248,3303 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<void> crateApiCastProjectorInfoCastVideo(
-      {required ProjectorInfo that, required String videoUrl}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  DeviceMonitor crateApiMonitorDeviceMonitorNew(
+      {required List<ProjectorInfo> devices, required BigInt intervalSecs}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
-        sse_encode_String(videoUrl, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
+        sse_encode_list_projector_info(devices, serializer);
+        sse_encode_u_64(intervalSecs, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiCastProjectorInfoCastVideoConstMeta,
-      argValues: [that, videoUrl],
+      constMeta: kCrateApiMonitorDeviceMonitorNewConstMeta,
+      argValues: [devices, intervalSecs],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoCastVideoConstMeta =>
+  TaskConstMeta get kCrateApiMonitorDeviceMonitorNewConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_cast_video",
-        argNames: ["that", "videoUrl"],
+        debugName: "DeviceMonitor_new",
+        argNames: ["devices", "intervalSecs"],
       );
 
   @override
-  Future<(String, String)> crateApiCastProjectorInfoGetPositionInfo(
-      {required ProjectorInfo that}) {
-    return handler.executeNormal(NormalTask(
+  Stream<ReachabilityEvent> crateApiMonitorDeviceMonitorRun(
+      {required DeviceMonitor that}) {
+    final sink = RustStreamSink<ReachabilityEvent>();
+    unawaited(handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+            that, serializer);
+        sse_encode_StreamSink_reachability_event_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 2, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_record_string_string,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCastProjectorInfoGetPositionInfoConstMeta,
+      constMeta: kCrateApiMonitorDeviceMonitorRunConstMeta,
+      argValues: [that, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMonitorDeviceMonitorRunConstMeta =>
+      const TaskConstMeta(
+        debugName: "DeviceMonitor_run",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  void crateApiMonitorDeviceMonitorStop({required DeviceMonitor that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMonitorDeviceMonitorStopConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoGetPositionInfoConstMeta =>
+  TaskConstMeta get kCrateApiMonitorDeviceMonitorStopConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_get_position_info",
+        debugName: "DeviceMonitor_stop",
         argNames: ["that"],
       );
 
   @override
-  Future<(BigInt, BigInt)> crateApiCastProjectorInfoGetPositionInfoSec(
-      {required ProjectorInfo that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  List<ProjectorInfo> crateApiNotifyNotifyListenerDevices(
+      {required NotifyListener that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_record_u_64_u_64,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_list_projector_info,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiCastProjectorInfoGetPositionInfoSecConstMeta,
+      constMeta: kCrateApiNotifyNotifyListenerDevicesConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoGetPositionInfoSecConstMeta =>
+  TaskConstMeta get kCrateApiNotifyNotifyListenerDevicesConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_get_position_info_sec",
+        debugName: "NotifyListener_devices",
         argNames: ["that"],
       );
 
   @override
-  Future<TransportState> crateApiCastProjectorInfoGetTransportInfo(
-      {required ProjectorInfo that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  NotifyListener crateApiNotifyNotifyListenerNew(
+      {required List<String> notifyTargets}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
+        sse_encode_list_String(notifyTargets, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_transport_state,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiCastProjectorInfoGetTransportInfoConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiNotifyNotifyListenerNewConstMeta,
+      argValues: [notifyTargets],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoGetTransportInfoConstMeta =>
+  TaskConstMeta get kCrateApiNotifyNotifyListenerNewConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_get_transport_info",
-        argNames: ["that"],
+        debugName: "NotifyListener_new",
+        argNames: ["notifyTargets"],
       );
 
   @override
-  Future<int> crateApiCastProjectorInfoGetVolume(
-      {required ProjectorInfo that}) {
-    return handler.executeNormal(NormalTask(
+  Stream<DeviceEvent> crateApiNotifyNotifyListenerRun(
+      {required NotifyListener that}) {
+    final sink = RustStreamSink<DeviceEvent>();
+    unawaited(handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+            that, serializer);
+        sse_encode_StreamSink_device_event_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
+            funcId: 6, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_8,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCastProjectorInfoGetVolumeConstMeta,
+      constMeta: kCrateApiNotifyNotifyListenerRunConstMeta,
+      argValues: [that, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiNotifyNotifyListenerRunConstMeta =>
+      const TaskConstMeta(
+        debugName: "NotifyListener_run",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  void crateApiNotifyNotifyListenerStop({required NotifyListener that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiNotifyNotifyListenerStopConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoGetVolumeConstMeta =>
+  TaskConstMeta get kCrateApiNotifyNotifyListenerStopConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_get_volume",
+        debugName: "NotifyListener_stop",
         argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiCastProjectorInfoPause({required ProjectorInfo that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiCastScanHandleCancel({required ScanHandle that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiCastProjectorInfoPauseConstMeta,
+      constMeta: kCrateApiCastScanHandleCancelConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoPauseConstMeta =>
+  TaskConstMeta get kCrateApiCastScanHandleCancelConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_pause",
+        debugName: "ScanHandle_cancel",
         argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiCastProjectorInfoPlay({required ProjectorInfo that}) {
+  ScanHandle crateApiCastScanHandleNew({required ScanOptions options}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_scan_options(options, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiCastScanHandleNewConstMeta,
+      argValues: [options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastScanHandleNewConstMeta => const TaskConstMeta(
+        debugName: "ScanHandle_new",
+        argNames: ["options"],
+      );
+
+  @override
+  Future<ScanReport> crateApiCastScanHandleRun({required ScanHandle that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
+            funcId: 10, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_scan_report,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCastProjectorInfoPlayConstMeta,
+      constMeta: kCrateApiCastScanHandleRunConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoPlayConstMeta =>
-      const TaskConstMeta(
-        debugName: "projector_info_play",
+  TaskConstMeta get kCrateApiCastScanHandleRunConstMeta => const TaskConstMeta(
+        debugName: "ScanHandle_run",
         argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiCastProjectorInfoSeek(
-      {required ProjectorInfo that, required String targetTime}) {
-    return handler.executeNormal(NormalTask(
+  Stream<ScanEvent> crateApiCastScanHandleRunStream(
+      {required ScanHandle that}) {
+    final sink = RustStreamSink<ScanEvent>();
+    unawaited(handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
-        sse_encode_String(targetTime, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+            that, serializer);
+        sse_encode_StreamSink_scan_event_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
+            funcId: 11, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCastProjectorInfoSeekConstMeta,
-      argValues: [that, targetTime],
+      constMeta: kCrateApiCastScanHandleRunStreamConstMeta,
+      argValues: [that, sink],
       apiImpl: this,
-    ));
+    )));
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoSeekConstMeta =>
+  TaskConstMeta get kCrateApiCastScanHandleRunStreamConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_seek",
-        argNames: ["that", "targetTime"],
+        debugName: "ScanHandle_run_stream",
+        argNames: ["that", "sink"],
       );
 
   @override
-  Future<void> crateApiCastProjectorInfoSetMute(
-      {required ProjectorInfo that, required bool mute}) {
+  Future<ProjectorInfo> crateApiCastAddDeviceByLocation({required String url}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
-        sse_encode_bool(mute, serializer);
+        sse_encode_String(url, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
+            funcId: 12, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_projector_info,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCastProjectorInfoSetMuteConstMeta,
-      argValues: [that, mute],
+      constMeta: kCrateApiCastAddDeviceByLocationConstMeta,
+      argValues: [url],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoSetMuteConstMeta =>
+  TaskConstMeta get kCrateApiCastAddDeviceByLocationConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_set_mute",
-        argNames: ["that", "mute"],
+        debugName: "add_device_by_location",
+        argNames: ["url"],
       );
 
   @override
-  Future<void> crateApiCastProjectorInfoSetVolume(
-      {required ProjectorInfo that, required int volume}) {
+  Future<DeviceCapabilities> crateApiDescriptionDeviceCapabilitiesDefault() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
-        sse_encode_u_8(volume, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
+            funcId: 13, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_device_capabilities,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDescriptionDeviceCapabilitiesDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDescriptionDeviceCapabilitiesDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "device_capabilities_default",
+        argNames: [],
+      );
+
+  @override
+  List<ProjectorInfo> crateApiCastDevicesFromJson({required String json}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(json, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_projector_info,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCastProjectorInfoSetVolumeConstMeta,
-      argValues: [that, volume],
+      constMeta: kCrateApiCastDevicesFromJsonConstMeta,
+      argValues: [json],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoSetVolumeConstMeta =>
+  TaskConstMeta get kCrateApiCastDevicesFromJsonConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_set_volume",
-        argNames: ["that", "volume"],
+        debugName: "devices_from_json",
+        argNames: ["json"],
       );
 
   @override
-  Future<void> crateApiCastProjectorInfoStop({required ProjectorInfo that}) {
+  String crateApiCastDevicesToJson({required List<ProjectorInfo> devices}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_projector_info(devices, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastDevicesToJsonConstMeta,
+      argValues: [devices],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastDevicesToJsonConstMeta => const TaskConstMeta(
+        debugName: "devices_to_json",
+        argNames: ["devices"],
+      );
+
+  @override
+  Future<List<NetworkInterface>> crateApiNetworkListNetworkInterfaces() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_projector_info(that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 11, port: port_);
+            funcId: 16, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_list_network_interface,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCastProjectorInfoStopConstMeta,
-      argValues: [that],
+      constMeta: kCrateApiNetworkListNetworkInterfacesConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastProjectorInfoStopConstMeta =>
+  TaskConstMeta get kCrateApiNetworkListNetworkInterfacesConstMeta =>
       const TaskConstMeta(
-        debugName: "projector_info_stop",
-        argNames: ["that"],
+        debugName: "list_network_interfaces",
+        argNames: [],
       );
 
   @override
-  Future<List<ProjectorInfo>> crateApiCastScanProjectors(
-      {required BigInt timeoutSecs}) {
+  Future<ProjectorInfo> crateApiCastProbeIp(
+      {required String ip, required BigInt timeoutSecs}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(ip, serializer);
         sse_encode_u_64(timeoutSecs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
+            funcId: 17, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_projector_info,
+        decodeSuccessData: sse_decode_projector_info,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCastScanProjectorsConstMeta,
-      argValues: [timeoutSecs],
+      constMeta: kCrateApiCastProbeIpConstMeta,
+      argValues: [ip, timeoutSecs],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastScanProjectorsConstMeta => const TaskConstMeta(
-        debugName: "scan_projectors",
-        argNames: ["timeoutSecs"],
+  TaskConstMeta get kCrateApiCastProbeIpConstMeta => const TaskConstMeta(
+        debugName: "probe_ip",
+        argNames: ["ip", "timeoutSecs"],
       );
 
   @override
-  Future<void> crateApiCastWakeOnLan({required String macAddress}) {
+  Future<void> crateApiCastProjectorInfoCastVideo(
+      {required ProjectorInfo that, required String videoUrl}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(macAddress, serializer);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_String(videoUrl, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
+            funcId: 18, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_AnyhowException,
       ),
-      constMeta: kCrateApiCastWakeOnLanConstMeta,
-      argValues: [macAddress],
+      constMeta: kCrateApiCastProjectorInfoCastVideoConstMeta,
+      argValues: [that, videoUrl],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiCastWakeOnLanConstMeta => const TaskConstMeta(
-        debugName: "wake_on_lan",
-        argNames: ["macAddress"],
+  TaskConstMeta get kCrateApiCastProjectorInfoCastVideoConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_cast_video",
+        argNames: ["that", "videoUrl"],
       );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  Future<DeviceStatus> crateApiCastProjectorInfoCheckAlive(
+      {required ProjectorInfo that, required BigInt timeoutMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_u_64(timeoutMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 19, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_device_status,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiCastProjectorInfoCheckAliveConstMeta,
+      argValues: [that, timeoutMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoCheckAliveConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_check_alive",
+        argNames: ["that", "timeoutMs"],
+      );
+
+  @override
+  Future<Uint8List> crateApiCastProjectorInfoFetchIcon(
+      {required ProjectorInfo that, required int preferredSize}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_u_32(preferredSize, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 20, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoFetchIconConstMeta,
+      argValues: [that, preferredSize],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoFetchIconConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_fetch_icon",
+        argNames: ["that", "preferredSize"],
+      );
+
+  @override
+  Future<List<ServiceDescription>> crateApiCastProjectorInfoFetchServices(
+      {required ProjectorInfo that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 21, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_service_description,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoFetchServicesConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoFetchServicesConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_fetch_services",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<(String, String)> crateApiCastProjectorInfoGetPositionInfo(
+      {required ProjectorInfo that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 22, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_record_string_string,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoGetPositionInfoConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoGetPositionInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_get_position_info",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<(BigInt, BigInt)> crateApiCastProjectorInfoGetPositionInfoSec(
+      {required ProjectorInfo that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 23, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_record_u_64_u_64,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoGetPositionInfoSecConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoGetPositionInfoSecConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_get_position_info_sec",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<TransportState> crateApiCastProjectorInfoGetTransportInfo(
+      {required ProjectorInfo that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 24, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_transport_state,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoGetTransportInfoConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoGetTransportInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_get_transport_info",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<int> crateApiCastProjectorInfoGetVolume(
+      {required ProjectorInfo that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 25, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_8,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoGetVolumeConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoGetVolumeConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_get_volume",
+        argNames: ["that"],
+      );
+
+  @override
+  List<DeviceIcon> crateApiCastProjectorInfoIcons(
+      {required ProjectorInfo that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_device_icon,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiCastProjectorInfoIconsConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoIconsConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_icons",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiCastProjectorInfoPause({required ProjectorInfo that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 27, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoPauseConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoPauseConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_pause",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiCastProjectorInfoPlay({required ProjectorInfo that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 28, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoPlayConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoPlayConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_play",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<ProjectorInfo> crateApiCastProjectorInfoRefresh(
+      {required ProjectorInfo that, required BigInt timeoutSecs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_u_64(timeoutSecs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 29, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_projector_info,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoRefreshConstMeta,
+      argValues: [that, timeoutSecs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoRefreshConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_refresh",
+        argNames: ["that", "timeoutSecs"],
+      );
+
+  @override
+  Future<void> crateApiCastProjectorInfoSeek(
+      {required ProjectorInfo that, required String targetTime}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_String(targetTime, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 30, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoSeekConstMeta,
+      argValues: [that, targetTime],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoSeekConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_seek",
+        argNames: ["that", "targetTime"],
+      );
+
+  @override
+  Future<void> crateApiCastProjectorInfoSetMute(
+      {required ProjectorInfo that, required bool mute}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_bool(mute, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 31, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoSetMuteConstMeta,
+      argValues: [that, mute],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoSetMuteConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_set_mute",
+        argNames: ["that", "mute"],
+      );
+
+  @override
+  Future<void> crateApiCastProjectorInfoSetVolume(
+      {required ProjectorInfo that, required int volume}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_u_8(volume, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 32, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoSetVolumeConstMeta,
+      argValues: [that, volume],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoSetVolumeConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_set_volume",
+        argNames: ["that", "volume"],
+      );
+
+  @override
+  Future<void> crateApiCastProjectorInfoStop({required ProjectorInfo that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 33, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoStopConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoStopConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_stop",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<bool> crateApiCastProjectorInfoSupportsAction(
+      {required ProjectorInfo that,
+      required String serviceType,
+      required String action}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        sse_encode_String(serviceType, serializer);
+        sse_encode_String(action, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 34, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoSupportsActionConstMeta,
+      argValues: [that, serviceType, action],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoSupportsActionConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_supports_action",
+        argNames: ["that", "serviceType", "action"],
+      );
+
+  @override
+  Future<void> crateApiCastProjectorInfoWake({required ProjectorInfo that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_projector_info(that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastProjectorInfoWakeConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastProjectorInfoWakeConstMeta =>
+      const TaskConstMeta(
+        debugName: "projector_info_wake",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<ProjectorInfo> crateApiCastResolveDevice(
+      {required String udn, required BigInt timeoutSecs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(udn, serializer);
+        sse_encode_u_64(timeoutSecs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_projector_info,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastResolveDeviceConstMeta,
+      argValues: [udn, timeoutSecs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastResolveDeviceConstMeta => const TaskConstMeta(
+        debugName: "resolve_device",
+        argNames: ["udn", "timeoutSecs"],
+      );
+
+  @override
+  Future<ScanDiagnostics> crateApiCastScanDiagnosticsDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_scan_diagnostics,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiCastScanDiagnosticsDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastScanDiagnosticsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_diagnostics_default",
+        argNames: [],
+      );
+
+  @override
+  ScanOptions crateApiCastScanOptionsWithTimeout(
+      {required BigInt timeoutSecs}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(timeoutSecs, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_scan_options,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiCastScanOptionsWithTimeoutConstMeta,
+      argValues: [timeoutSecs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastScanOptionsWithTimeoutConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_options_with_timeout",
+        argNames: ["timeoutSecs"],
+      );
+
+  @override
+  Future<List<ProjectorInfo>> crateApiCastScanProjectors(
+      {required BigInt timeoutSecs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(timeoutSecs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 39, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_projector_info,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastScanProjectorsConstMeta,
+      argValues: [timeoutSecs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastScanProjectorsConstMeta => const TaskConstMeta(
+        debugName: "scan_projectors",
+        argNames: ["timeoutSecs"],
+      );
+
+  @override
+  Stream<ScanEvent> crateApiCastScanProjectorsStream(
+      {required ScanOptions options}) {
+    final sink = RustStreamSink<ScanEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_scan_options(options, serializer);
+        sse_encode_StreamSink_scan_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastScanProjectorsStreamConstMeta,
+      argValues: [options, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCastScanProjectorsStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_projectors_stream",
+        argNames: ["options", "sink"],
+      );
+
+  @override
+  Future<List<ProjectorInfo>> crateApiCastScanProjectorsWithOptions(
+      {required ScanOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_scan_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_projector_info,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastScanProjectorsWithOptionsConstMeta,
+      argValues: [options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastScanProjectorsWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_projectors_with_options",
+        argNames: ["options"],
+      );
+
+  @override
+  Future<ScanReport> crateApiCastScanProjectorsWithReport(
+      {required ScanOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_scan_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_scan_report,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastScanProjectorsWithReportConstMeta,
+      argValues: [options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastScanProjectorsWithReportConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_projectors_with_report",
+        argNames: ["options"],
+      );
+
+  @override
+  bool crateApiScpdServiceDescriptionSupportsAction(
+      {required ServiceDescription that, required String action}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_service_description(that, serializer);
+        sse_encode_String(action, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiScpdServiceDescriptionSupportsActionConstMeta,
+      argValues: [that, action],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiScpdServiceDescriptionSupportsActionConstMeta =>
+      const TaskConstMeta(
+        debugName: "service_description_supports_action",
+        argNames: ["that", "action"],
+      );
+
+  @override
+  String? crateApiSsdpSsdpMessageHeader(
+      {required SsdpMessage that, required String name}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_ssdp_message(that, serializer);
+        sse_encode_String(name, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSsdpSsdpMessageHeaderConstMeta,
+      argValues: [that, name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSsdpSsdpMessageHeaderConstMeta =>
+      const TaskConstMeta(
+        debugName: "ssdp_message_header",
+        argNames: ["that", "name"],
+      );
+
+  @override
+  Future<ProjectorInfo> crateApiCastWakeAndWait(
+      {required String macAddress,
+      required String udnOrIp,
+      required BigInt timeoutSecs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(macAddress, serializer);
+        sse_encode_String(udnOrIp, serializer);
+        sse_encode_u_64(timeoutSecs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_projector_info,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastWakeAndWaitConstMeta,
+      argValues: [macAddress, udnOrIp, timeoutSecs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastWakeAndWaitConstMeta => const TaskConstMeta(
+        debugName: "wake_and_wait",
+        argNames: ["macAddress", "udnOrIp", "timeoutSecs"],
+      );
+
+  @override
+  Future<void> crateApiCastWakeOnLan({required String macAddress}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(macAddress, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastWakeOnLanConstMeta,
+      argValues: [macAddress],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastWakeOnLanConstMeta => const TaskConstMeta(
+        debugName: "wake_on_lan",
+        argNames: ["macAddress"],
+      );
+
+  @override
+  Future<void> crateApiCastWakeOnLanWithOptions(
+      {required String macAddress, required WakeOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(macAddress, serializer);
+        sse_encode_box_autoadd_wake_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiCastWakeOnLanWithOptionsConstMeta,
+      argValues: [macAddress, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiCastWakeOnLanWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "wake_on_lan_with_options",
+        argNames: ["macAddress", "options"],
+      );
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_DeviceMonitor => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_DeviceMonitor => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor;
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_NotifyListener => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_NotifyListener => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener;
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_ScanHandle => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_ScanHandle => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  DeviceMonitor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeviceMonitorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  NotifyListener dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NotifyListenerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ScanHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScanHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DeviceMonitor dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeviceMonitorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  NotifyListener dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NotifyListenerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ScanHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScanHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DeviceMonitor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeviceMonitorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  NotifyListener dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NotifyListenerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ScanHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScanHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<DeviceEvent> dco_decode_StreamSink_device_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ReachabilityEvent> dco_decode_StreamSink_reachability_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ScanEvent> dco_decode_StreamSink_scan_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  ActionArgument dco_decode_action_argument(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ActionArgument(
+      name: dco_decode_String(arr[0]),
+      direction: dco_decode_argument_direction(arr[1]),
+      relatedStateVariable: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ActionInfo dco_decode_action_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ActionInfo(
+      name: dco_decode_String(arr[0]),
+      arguments: dco_decode_list_action_argument(arr[1]),
+    );
+  }
+
+  @protected
+  AllowedValueRange dco_decode_allowed_value_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AllowedValueRange(
+      minimum: dco_decode_String(arr[0]),
+      maximum: dco_decode_String(arr[1]),
+      step: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  ArgumentDirection dco_decode_argument_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ArgumentDirection.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AllowedValueRange dco_decode_box_autoadd_allowed_value_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_allowed_value_range(raw);
+  }
+
+  @protected
+  ProjectorInfo dco_decode_box_autoadd_projector_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_projector_info(raw);
+  }
+
+  @protected
+  ScanOptions dco_decode_box_autoadd_scan_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scan_options(raw);
+  }
+
+  @protected
+  ServiceDescription dco_decode_box_autoadd_service_description(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_service_description(raw);
+  }
+
+  @protected
+  SsdpMessage dco_decode_box_autoadd_ssdp_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ssdp_message(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  WakeOptions dco_decode_box_autoadd_wake_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wake_options(raw);
+  }
+
+  @protected
+  DeviceCapabilities dco_decode_device_capabilities(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DeviceCapabilities(
+      canPlay: dco_decode_bool(arr[0]),
+      canControlVolume: dco_decode_bool(arr[1]),
+      canSubscribe: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  DeviceDescription dco_decode_device_description(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return DeviceDescription(
+      deviceType: dco_decode_String(arr[0]),
+      friendlyName: dco_decode_String(arr[1]),
+      manufacturer: dco_decode_String(arr[2]),
+      manufacturerUrl: dco_decode_opt_String(arr[3]),
+      modelDescription: dco_decode_opt_String(arr[4]),
+      modelName: dco_decode_String(arr[5]),
+      modelNumber: dco_decode_opt_String(arr[6]),
+      modelUrl: dco_decode_opt_String(arr[7]),
+      serialNumber: dco_decode_opt_String(arr[8]),
+      udn: dco_decode_String(arr[9]),
+      presentationUrl: dco_decode_opt_String(arr[10]),
+      macAddress: dco_decode_opt_String(arr[11]),
+      icons: dco_decode_list_device_icon(arr[12]),
+      services: dco_decode_list_service_info(arr[13]),
+      devices: dco_decode_list_device_description(arr[14]),
+    );
+  }
+
+  @protected
+  DeviceEvent dco_decode_device_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DeviceEvent_Added(
+          dco_decode_projector_info(raw[1]),
+        );
+      case 1:
+        return DeviceEvent_Updated(
+          dco_decode_projector_info(raw[1]),
+        );
+      case 2:
+        return DeviceEvent_Removed(
+          dco_decode_projector_info(raw[1]),
+        );
+      case 3:
+        return DeviceEvent_Rebooted(
+          dco_decode_projector_info(raw[1]),
+        );
+      case 4:
+        return DeviceEvent_DescriptionChanged(
+          dco_decode_projector_info(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DeviceIcon dco_decode_device_icon(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DeviceIcon(
+      mimeType: dco_decode_String(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      depth: dco_decode_u_32(arr[3]),
+      url: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  DeviceKind dco_decode_device_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeviceKind.values[raw as int];
+  }
+
+  @protected
+  DeviceStatus dco_decode_device_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DeviceStatus(
+      online: dco_decode_bool(arr[0]),
+      httpLatencyMs: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      ssdpLatencyMs: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      error: dco_decode_opt_String(arr[3]),
+      ssdp: dco_decode_opt_box_autoadd_ssdp_message(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<ActionArgument> dco_decode_list_action_argument(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_action_argument).toList();
+  }
+
+  @protected
+  List<ActionInfo> dco_decode_list_action_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_action_info).toList();
+  }
+
+  @protected
+  List<DeviceDescription> dco_decode_list_device_description(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_device_description).toList();
+  }
+
+  @protected
+  List<DeviceIcon> dco_decode_list_device_icon(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_device_icon).toList();
+  }
+
+  @protected
+  List<NetworkInterface> dco_decode_list_network_interface(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_network_interface).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ProjectorInfo> dco_decode_list_projector_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_projector_info).toList();
+  }
+
+  @protected
+  List<ServiceDescription> dco_decode_list_service_description(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_service_description).toList();
+  }
+
+  @protected
+  List<ServiceInfo> dco_decode_list_service_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_service_info).toList();
+  }
+
+  @protected
+  List<SsdpHeader> dco_decode_list_ssdp_header(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_ssdp_header).toList();
+  }
+
+  @protected
+  List<StateVariable> dco_decode_list_state_variable(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_state_variable).toList();
+  }
+
+  @protected
+  NetworkInterface dco_decode_network_interface(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return NetworkInterface(
+      name: dco_decode_String(arr[0]),
+      index: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      ip: dco_decode_String(arr[2]),
+      prefixLen: dco_decode_u_8(arr[3]),
+      isIpv6: dco_decode_bool(arr[4]),
+      isUp: dco_decode_bool(arr[5]),
+      isLoopback: dco_decode_bool(arr[6]),
+      eligible: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AllowedValueRange? dco_decode_opt_box_autoadd_allowed_value_range(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_allowed_value_range(raw);
+  }
+
+  @protected
+  SsdpMessage? dco_decode_opt_box_autoadd_ssdp_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_ssdp_message(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  ProjectorInfo dco_decode_projector_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return ProjectorInfo(
+      friendlyName: dco_decode_String(arr[0]),
+      ip: dco_decode_String(arr[1]),
+      locationXmlUrl: dco_decode_String(arr[2]),
+      udn: dco_decode_String(arr[3]),
+      avTransportUrl: dco_decode_opt_String(arr[4]),
+      renderingControlUrl: dco_decode_opt_String(arr[5]),
+      description: dco_decode_device_description(arr[6]),
+      kind: dco_decode_device_kind(arr[7]),
+      capabilities: dco_decode_device_capabilities(arr[8]),
+      ssdp: dco_decode_opt_box_autoadd_ssdp_message(arr[9]),
+      macAddress: dco_decode_opt_String(arr[10]),
+    );
+  }
+
+  @protected
+  ReachabilityEvent dco_decode_reachability_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ReachabilityEvent_Online(
+          device: dco_decode_projector_info(raw[1]),
+          status: dco_decode_device_status(raw[2]),
+        );
+      case 1:
+        return ReachabilityEvent_Offline(
+          device: dco_decode_projector_info(raw[1]),
+          status: dco_decode_device_status(raw[2]),
+        );
+      case 2:
+        return ReachabilityEvent_Rebooted(
+          device: dco_decode_projector_info(raw[1]),
+          status: dco_decode_device_status(raw[2]),
+        );
+      case 3:
+        return ReachabilityEvent_DescriptionChanged(
+          device: dco_decode_projector_info(raw[1]),
+          status: dco_decode_device_status(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  (BigInt, BigInt) dco_decode_record_u_64_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_u_64(arr[0]),
+      dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  ScanDiagnostics dco_decode_scan_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ScanDiagnostics(
+      multicastInterfaces: dco_decode_list_String(arr[0]),
+      usedBroadcast: dco_decode_bool(arr[1]),
+      broadcastTargets: dco_decode_list_String(arr[2]),
+      errors: dco_decode_list_String(arr[3]),
+      cancelled: dco_decode_bool(arr[4]),
+      usedSweep: dco_decode_bool(arr[5]),
+      sweptHosts: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  ScanEvent dco_decode_scan_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ScanEvent_DeviceFound(
+          dco_decode_projector_info(raw[1]),
+        );
+      case 1:
+        return ScanEvent_Completed(
+          total: dco_decode_u_32(raw[1]),
+          diagnostics: dco_decode_scan_diagnostics(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ScanOptions dco_decode_scan_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return ScanOptions(
+      timeoutSecs: dco_decode_u_64(arr[0]),
+      searchTargets: dco_decode_list_String(arr[1]),
+      interfaces: dco_decode_list_String(arr[2]),
+      enableIpv6: dco_decode_bool(arr[3]),
+      renderersOnly: dco_decode_bool(arr[4]),
+      sendCount: dco_decode_u_32(arr[5]),
+      sendIntervalMs: dco_decode_u_64(arr[6]),
+      mxSecs: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      multicastTtl: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      subnetSweep: dco_decode_bool(arr[9]),
+      sweepConcurrency: dco_decode_opt_box_autoadd_u_32(arr[10]),
+      sweepRatePerSec: dco_decode_opt_box_autoadd_u_32(arr[11]),
+    );
+  }
+
+  @protected
+  ScanReport dco_decode_scan_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ScanReport(
+      devices: dco_decode_list_projector_info(arr[0]),
+      diagnostics: dco_decode_scan_diagnostics(arr[1]),
+    );
+  }
+
+  @protected
+  ServiceDescription dco_decode_service_description(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ServiceDescription(
+      serviceType: dco_decode_String(arr[0]),
+      scpdUrl: dco_decode_String(arr[1]),
+      actions: dco_decode_list_action_info(arr[2]),
+      stateVariables: dco_decode_list_state_variable(arr[3]),
+    );
+  }
+
+  @protected
+  ServiceInfo dco_decode_service_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ServiceInfo(
+      serviceType: dco_decode_String(arr[0]),
+      serviceId: dco_decode_String(arr[1]),
+      scpdUrl: dco_decode_String(arr[2]),
+      controlUrl: dco_decode_String(arr[3]),
+      eventSubUrl: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  SsdpHeader dco_decode_ssdp_header(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SsdpHeader(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  SsdpMessage dco_decode_ssdp_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return SsdpMessage(
+      startLine: dco_decode_String(arr[0]),
+      method: dco_decode_opt_String(arr[1]),
+      statusCode: dco_decode_opt_box_autoadd_u_16(arr[2]),
+      location: dco_decode_opt_String(arr[3]),
+      searchTarget: dco_decode_opt_String(arr[4]),
+      notificationType: dco_decode_opt_String(arr[5]),
+      notificationSubType: dco_decode_opt_String(arr[6]),
+      usn: dco_decode_opt_String(arr[7]),
+      server: dco_decode_opt_String(arr[8]),
+      maxAge: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      bootId: dco_decode_opt_box_autoadd_u_32(arr[10]),
+      nextBootId: dco_decode_opt_box_autoadd_u_32(arr[11]),
+      configId: dco_decode_opt_box_autoadd_u_32(arr[12]),
+      searchPort: dco_decode_opt_box_autoadd_u_16(arr[13]),
+      headers: dco_decode_list_ssdp_header(arr[14]),
+    );
+  }
+
+  @protected
+  StateVariable dco_decode_state_variable(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return StateVariable(
+      name: dco_decode_String(arr[0]),
+      dataType: dco_decode_String(arr[1]),
+      sendEvents: dco_decode_bool(arr[2]),
+      defaultValue: dco_decode_opt_String(arr[3]),
+      allowedValues: dco_decode_list_String(arr[4]),
+      allowedValueRange: dco_decode_opt_box_autoadd_allowed_value_range(arr[5]),
+    );
+  }
+
+  @protected
+  TransportState dco_decode_transport_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TransportState.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WakeOptions dco_decode_wake_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WakeOptions(
+      targetAddress: dco_decode_opt_String(arr[0]),
+      port: dco_decode_u_16(arr[1]),
+      directedBroadcast: dco_decode_bool(arr[2]),
+      repeatCount: dco_decode_u_32(arr[3]),
+      repeatIntervalMs: dco_decode_u_64(arr[4]),
+      secureOnPassword: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  DeviceMonitor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DeviceMonitorImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  NotifyListener sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return NotifyListenerImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ScanHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ScanHandleImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  DeviceMonitor sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DeviceMonitorImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  NotifyListener sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return NotifyListenerImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ScanHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ScanHandleImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  DeviceMonitor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DeviceMonitorImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  NotifyListener sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return NotifyListenerImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ScanHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ScanHandleImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RustStreamSink<DeviceEvent> sse_decode_StreamSink_device_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ReachabilityEvent> sse_decode_StreamSink_reachability_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ScanEvent> sse_decode_StreamSink_scan_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  ActionArgument sse_decode_action_argument(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_direction = sse_decode_argument_direction(deserializer);
+    var var_relatedStateVariable = sse_decode_String(deserializer);
+    return ActionArgument(
+        name: var_name,
+        direction: var_direction,
+        relatedStateVariable: var_relatedStateVariable);
+  }
+
+  @protected
+  ActionInfo sse_decode_action_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_arguments = sse_decode_list_action_argument(deserializer);
+    return ActionInfo(name: var_name, arguments: var_arguments);
+  }
+
+  @protected
+  AllowedValueRange sse_decode_allowed_value_range(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_minimum = sse_decode_String(deserializer);
+    var var_maximum = sse_decode_String(deserializer);
+    var var_step = sse_decode_opt_String(deserializer);
+    return AllowedValueRange(
+        minimum: var_minimum, maximum: var_maximum, step: var_step);
+  }
+
+  @protected
+  ArgumentDirection sse_decode_argument_direction(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ArgumentDirection.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AllowedValueRange sse_decode_box_autoadd_allowed_value_range(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_allowed_value_range(deserializer));
+  }
+
+  @protected
+  ProjectorInfo sse_decode_box_autoadd_projector_info(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_projector_info(deserializer));
+  }
+
+  @protected
+  ScanOptions sse_decode_box_autoadd_scan_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scan_options(deserializer));
+  }
+
+  @protected
+  ServiceDescription sse_decode_box_autoadd_service_description(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_service_description(deserializer));
+  }
+
+  @protected
+  SsdpMessage sse_decode_box_autoadd_ssdp_message(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ssdp_message(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  WakeOptions sse_decode_box_autoadd_wake_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wake_options(deserializer));
+  }
+
+  @protected
+  DeviceCapabilities sse_decode_device_capabilities(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_canPlay = sse_decode_bool(deserializer);
+    var var_canControlVolume = sse_decode_bool(deserializer);
+    var var_canSubscribe = sse_decode_bool(deserializer);
+    return DeviceCapabilities(
+        canPlay: var_canPlay,
+        canControlVolume: var_canControlVolume,
+        canSubscribe: var_canSubscribe);
+  }
+
+  @protected
+  DeviceDescription sse_decode_device_description(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deviceType = sse_decode_String(deserializer);
+    var var_friendlyName = sse_decode_String(deserializer);
+    var var_manufacturer = sse_decode_String(deserializer);
+    var var_manufacturerUrl = sse_decode_opt_String(deserializer);
+    var var_modelDescription = sse_decode_opt_String(deserializer);
+    var var_modelName = sse_decode_String(deserializer);
+    var var_modelNumber = sse_decode_opt_String(deserializer);
+    var var_modelUrl = sse_decode_opt_String(deserializer);
+    var var_serialNumber = sse_decode_opt_String(deserializer);
+    var var_udn = sse_decode_String(deserializer);
+    var var_presentationUrl = sse_decode_opt_String(deserializer);
+    var var_macAddress = sse_decode_opt_String(deserializer);
+    var var_icons = sse_decode_list_device_icon(deserializer);
+    var var_services = sse_decode_list_service_info(deserializer);
+    var var_devices = sse_decode_list_device_description(deserializer);
+    return DeviceDescription(
+        deviceType: var_deviceType,
+        friendlyName: var_friendlyName,
+        manufacturer: var_manufacturer,
+        manufacturerUrl: var_manufacturerUrl,
+        modelDescription: var_modelDescription,
+        modelName: var_modelName,
+        modelNumber: var_modelNumber,
+        modelUrl: var_modelUrl,
+        serialNumber: var_serialNumber,
+        udn: var_udn,
+        presentationUrl: var_presentationUrl,
+        macAddress: var_macAddress,
+        icons: var_icons,
+        services: var_services,
+        devices: var_devices);
+  }
+
+  @protected
+  DeviceEvent sse_decode_device_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_projector_info(deserializer);
+        return DeviceEvent_Added(var_field0);
+      case 1:
+        var var_field0 = sse_decode_projector_info(deserializer);
+        return DeviceEvent_Updated(var_field0);
+      case 2:
+        var var_field0 = sse_decode_projector_info(deserializer);
+        return DeviceEvent_Removed(var_field0);
+      case 3:
+        var var_field0 = sse_decode_projector_info(deserializer);
+        return DeviceEvent_Rebooted(var_field0);
+      case 4:
+        var var_field0 = sse_decode_projector_info(deserializer);
+        return DeviceEvent_DescriptionChanged(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DeviceIcon sse_decode_device_icon(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_depth = sse_decode_u_32(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    return DeviceIcon(
+        mimeType: var_mimeType,
+        width: var_width,
+        height: var_height,
+        depth: var_depth,
+        url: var_url);
+  }
+
+  @protected
+  DeviceKind sse_decode_device_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DeviceKind.values[inner];
+  }
+
+  @protected
+  DeviceStatus sse_decode_device_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_online = sse_decode_bool(deserializer);
+    var var_httpLatencyMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_ssdpLatencyMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    var var_ssdp = sse_decode_opt_box_autoadd_ssdp_message(deserializer);
+    return DeviceStatus(
+        online: var_online,
+        httpLatencyMs: var_httpLatencyMs,
+        ssdpLatencyMs: var_ssdpLatencyMs,
+        error: var_error,
+        ssdp: var_ssdp);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ActionArgument> sse_decode_list_action_argument(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ActionArgument>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_action_argument(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ActionInfo> sse_decode_list_action_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ActionInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_action_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DeviceDescription> sse_decode_list_device_description(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DeviceDescription>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_device_description(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DeviceIcon> sse_decode_list_device_icon(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DeviceIcon>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_device_icon(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NetworkInterface> sse_decode_list_network_interface(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NetworkInterface>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_network_interface(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ProjectorInfo> sse_decode_list_projector_info(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ProjectorInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_projector_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ServiceDescription> sse_decode_list_service_description(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ServiceDescription>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_service_description(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ServiceInfo> sse_decode_list_service_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ServiceInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_service_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SsdpHeader> sse_decode_list_ssdp_header(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SsdpHeader>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_ssdp_header(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<StateVariable> sse_decode_list_state_variable(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StateVariable>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_state_variable(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  NetworkInterface sse_decode_network_interface(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_index = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_ip = sse_decode_String(deserializer);
+    var var_prefixLen = sse_decode_u_8(deserializer);
+    var var_isIpv6 = sse_decode_bool(deserializer);
+    var var_isUp = sse_decode_bool(deserializer);
+    var var_isLoopback = sse_decode_bool(deserializer);
+    var var_eligible = sse_decode_bool(deserializer);
+    return NetworkInterface(
+        name: var_name,
+        index: var_index,
+        ip: var_ip,
+        prefixLen: var_prefixLen,
+        isIpv6: var_isIpv6,
+        isUp: var_isUp,
+        isLoopback: var_isLoopback,
+        eligible: var_eligible);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AllowedValueRange? sse_decode_opt_box_autoadd_allowed_value_range(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_allowed_value_range(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SsdpMessage? sse_decode_opt_box_autoadd_ssdp_message(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_ssdp_message(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ProjectorInfo dco_decode_box_autoadd_projector_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_projector_info(raw);
+  ProjectorInfo sse_decode_projector_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_friendlyName = sse_decode_String(deserializer);
+    var var_ip = sse_decode_String(deserializer);
+    var var_locationXmlUrl = sse_decode_String(deserializer);
+    var var_udn = sse_decode_String(deserializer);
+    var var_avTransportUrl = sse_decode_opt_String(deserializer);
+    var var_renderingControlUrl = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_device_description(deserializer);
+    var var_kind = sse_decode_device_kind(deserializer);
+    var var_capabilities = sse_decode_device_capabilities(deserializer);
+    var var_ssdp = sse_decode_opt_box_autoadd_ssdp_message(deserializer);
+    var var_macAddress = sse_decode_opt_String(deserializer);
+    return ProjectorInfo(
+        friendlyName: var_friendlyName,
+        ip: var_ip,
+        locationXmlUrl: var_locationXmlUrl,
+        udn: var_udn,
+        avTransportUrl: var_avTransportUrl,
+        renderingControlUrl: var_renderingControlUrl,
+        description: var_description,
+        kind: var_kind,
+        capabilities: var_capabilities,
+        ssdp: var_ssdp,
+        macAddress: var_macAddress);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  ReachabilityEvent sse_decode_reachability_event(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_device = sse_decode_projector_info(deserializer);
+        var var_status = sse_decode_device_status(deserializer);
+        return ReachabilityEvent_Online(device: var_device, status: var_status);
+      case 1:
+        var var_device = sse_decode_projector_info(deserializer);
+        var var_status = sse_decode_device_status(deserializer);
+        return ReachabilityEvent_Offline(
+            device: var_device, status: var_status);
+      case 2:
+        var var_device = sse_decode_projector_info(deserializer);
+        var var_status = sse_decode_device_status(deserializer);
+        return ReachabilityEvent_Rebooted(
+            device: var_device, status: var_status);
+      case 3:
+        var var_device = sse_decode_projector_info(deserializer);
+        var var_status = sse_decode_device_status(deserializer);
+        return ReachabilityEvent_DescriptionChanged(
+            device: var_device, status: var_status);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  (String, String) sse_decode_record_string_string(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  List<ProjectorInfo> dco_decode_list_projector_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_projector_info).toList();
+  (BigInt, BigInt) sse_decode_record_u_64_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_u_64(deserializer);
+    var var_field1 = sse_decode_u_64(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  ScanDiagnostics sse_decode_scan_diagnostics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_multicastInterfaces = sse_decode_list_String(deserializer);
+    var var_usedBroadcast = sse_decode_bool(deserializer);
+    var var_broadcastTargets = sse_decode_list_String(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    var var_cancelled = sse_decode_bool(deserializer);
+    var var_usedSweep = sse_decode_bool(deserializer);
+    var var_sweptHosts = sse_decode_u_32(deserializer);
+    return ScanDiagnostics(
+        multicastInterfaces: var_multicastInterfaces,
+        usedBroadcast: var_usedBroadcast,
+        broadcastTargets: var_broadcastTargets,
+        errors: var_errors,
+        cancelled: var_cancelled,
+        usedSweep: var_usedSweep,
+        sweptHosts: var_sweptHosts);
   }
 
   @protected
-  ProjectorInfo dco_decode_projector_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return ProjectorInfo(
-      friendlyName: dco_decode_String(arr[0]),
-      ip: dco_decode_String(arr[1]),
-      locationXmlUrl: dco_decode_String(arr[2]),
-      avTransportUrl: dco_decode_opt_String(arr[3]),
-      renderingControlUrl: dco_decode_opt_String(arr[4]),
-    );
+  ScanEvent sse_decode_scan_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_projector_info(deserializer);
+        return ScanEvent_DeviceFound(var_field0);
+      case 1:
+        var var_total = sse_decode_u_32(deserializer);
+        var var_diagnostics = sse_decode_scan_diagnostics(deserializer);
+        return ScanEvent_Completed(
+            total: var_total, diagnostics: var_diagnostics);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_String(arr[0]),
-      dco_decode_String(arr[1]),
-    );
+  ScanOptions sse_decode_scan_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeoutSecs = sse_decode_u_64(deserializer);
+    var var_searchTargets = sse_decode_list_String(deserializer);
+    var var_interfaces = sse_decode_list_String(deserializer);
+    var var_enableIpv6 = sse_decode_bool(deserializer);
+    var var_renderersOnly = sse_decode_bool(deserializer);
+    var var_sendCount = sse_decode_u_32(deserializer);
+    var var_sendIntervalMs = sse_decode_u_64(deserializer);
+    var var_mxSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_multicastTtl = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_subnetSweep = sse_decode_bool(deserializer);
+    var var_sweepConcurrency = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_sweepRatePerSec = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return ScanOptions(
+        timeoutSecs: var_timeoutSecs,
+        searchTargets: var_searchTargets,
+        interfaces: var_interfaces,
+        enableIpv6: var_enableIpv6,
+        renderersOnly: var_renderersOnly,
+        sendCount: var_sendCount,
+        sendIntervalMs: var_sendIntervalMs,
+        mxSecs: var_mxSecs,
+        multicastTtl: var_multicastTtl,
+        subnetSweep: var_subnetSweep,
+        sweepConcurrency: var_sweepConcurrency,
+        sweepRatePerSec: var_sweepRatePerSec);
+  }
+
+  @protected
+  ScanReport sse_decode_scan_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_devices = sse_decode_list_projector_info(deserializer);
+    var var_diagnostics = sse_decode_scan_diagnostics(deserializer);
+    return ScanReport(devices: var_devices, diagnostics: var_diagnostics);
+  }
+
+  @protected
+  ServiceDescription sse_decode_service_description(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_serviceType = sse_decode_String(deserializer);
+    var var_scpdUrl = sse_decode_String(deserializer);
+    var var_actions = sse_decode_list_action_info(deserializer);
+    var var_stateVariables = sse_decode_list_state_variable(deserializer);
+    return ServiceDescription(
+        serviceType: var_serviceType,
+        scpdUrl: var_scpdUrl,
+        actions: var_actions,
+        stateVariables: var_stateVariables);
+  }
+
+  @protected
+  ServiceInfo sse_decode_service_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_serviceType = sse_decode_String(deserializer);
+    var var_serviceId = sse_decode_String(deserializer);
+    var var_scpdUrl = sse_decode_String(deserializer);
+    var var_controlUrl = sse_decode_String(deserializer);
+    var var_eventSubUrl = sse_decode_String(deserializer);
+    return ServiceInfo(
+        serviceType: var_serviceType,
+        serviceId: var_serviceId,
+        scpdUrl: var_scpdUrl,
+        controlUrl: var_controlUrl,
+        eventSubUrl: var_eventSubUrl);
+  }
+
+  @protected
+  SsdpHeader sse_decode_ssdp_header(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return SsdpHeader(name: var_name, value: var_value);
+  }
+
+  @protected
+  SsdpMessage sse_decode_ssdp_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startLine = sse_decode_String(deserializer);
+    var var_method = sse_decode_opt_String(deserializer);
+    var var_statusCode = sse_decode_opt_box_autoadd_u_16(deserializer);
+    var var_location = sse_decode_opt_String(deserializer);
+    var var_searchTarget = sse_decode_opt_String(deserializer);
+    var var_notificationType = sse_decode_opt_String(deserializer);
+    var var_notificationSubType = sse_decode_opt_String(deserializer);
+    var var_usn = sse_decode_opt_String(deserializer);
+    var var_server = sse_decode_opt_String(deserializer);
+    var var_maxAge = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bootId = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_nextBootId = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_configId = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_searchPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+    var var_headers = sse_decode_list_ssdp_header(deserializer);
+    return SsdpMessage(
+        startLine: var_startLine,
+        method: var_method,
+        statusCode: var_statusCode,
+        location: var_location,
+        searchTarget: var_searchTarget,
+        notificationType: var_notificationType,
+        notificationSubType: var_notificationSubType,
+        usn: var_usn,
+        server: var_server,
+        maxAge: var_maxAge,
+        bootId: var_bootId,
+        nextBootId: var_nextBootId,
+        configId: var_configId,
+        searchPort: var_searchPort,
+        headers: var_headers);
+  }
+
+  @protected
+  StateVariable sse_decode_state_variable(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_dataType = sse_decode_String(deserializer);
+    var var_sendEvents = sse_decode_bool(deserializer);
+    var var_defaultValue = sse_decode_opt_String(deserializer);
+    var var_allowedValues = sse_decode_list_String(deserializer);
+    var var_allowedValueRange = sse_decode_opt_box_autoadd_allowed_value_range(deserializer);
+    return StateVariable(
+        name: var_name,
+        dataType: var_dataType,
+        sendEvents: var_sendEvents,
+        defaultValue: var_defaultValue,
+        allowedValues: var_allowedValues,
+        allowedValueRange: var_allowedValueRange);
+  }
+
+  @protected
+  TransportState sse_decode_transport_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TransportState.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  WakeOptions sse_decode_wake_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_targetAddress = sse_decode_opt_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    var var_directedBroadcast = sse_decode_bool(deserializer);
+    var var_repeatCount = sse_decode_u_32(deserializer);
+    var var_repeatIntervalMs = sse_decode_u_64(deserializer);
+    var var_secureOnPassword = sse_decode_opt_String(deserializer);
+    return WakeOptions(
+        targetAddress: var_targetAddress,
+        port: var_port,
+        directedBroadcast: var_directedBroadcast,
+        repeatCount: var_repeatCount,
+        repeatIntervalMs: var_repeatIntervalMs,
+        secureOnPassword: var_secureOnPassword);
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      DeviceMonitor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as DeviceMonitorImpl).frbInternalSseEncode(move: true),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      NotifyListener self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as NotifyListenerImpl).frbInternalSseEncode(move: true),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      ScanHandle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ScanHandleImpl).frbInternalSseEncode(move: true), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      DeviceMonitor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as DeviceMonitorImpl).frbInternalSseEncode(move: false),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      NotifyListener self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as NotifyListenerImpl).frbInternalSseEncode(move: false),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      ScanHandle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ScanHandleImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      DeviceMonitor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as DeviceMonitorImpl).frbInternalSseEncode(move: null),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      NotifyListener self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as NotifyListenerImpl).frbInternalSseEncode(move: null),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      ScanHandle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ScanHandleImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_device_event_Sse(
+      RustStreamSink<DeviceEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_device_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_reachability_event_Sse(
+      RustStreamSink<ReachabilityEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_reachability_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_scan_event_Sse(
+      RustStreamSink<ScanEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_scan_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_action_argument(
+      ActionArgument self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_argument_direction(self.direction, serializer);
+    sse_encode_String(self.relatedStateVariable, serializer);
+  }
+
+  @protected
+  void sse_encode_action_info(ActionInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_action_argument(self.arguments, serializer);
+  }
+
+  @protected
+  void sse_encode_allowed_value_range(
+      AllowedValueRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.minimum, serializer);
+    sse_encode_String(self.maximum, serializer);
+    sse_encode_opt_String(self.step, serializer);
+  }
+
+  @protected
+  void sse_encode_argument_direction(
+      ArgumentDirection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_allowed_value_range(
+      AllowedValueRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_allowed_value_range(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_projector_info(
+      ProjectorInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_projector_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_scan_options(
+      ScanOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scan_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_service_description(
+      ServiceDescription self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_service_description(self, serializer);
   }
 
   @protected
-  (BigInt, BigInt) dco_decode_record_u_64_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_u_64(arr[0]),
-      dco_decode_u_64(arr[1]),
-    );
+  void sse_encode_box_autoadd_ssdp_message(
+      SsdpMessage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ssdp_message(self, serializer);
   }
 
   @protected
-  TransportState dco_decode_transport_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return TransportState.values[raw as int];
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_wake_options(
+      WakeOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wake_options(self, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_device_capabilities(
+      DeviceCapabilities self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.canPlay, serializer);
+    sse_encode_bool(self.canControlVolume, serializer);
+    sse_encode_bool(self.canSubscribe, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_device_description(
+      DeviceDescription self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_String(self.deviceType, serializer);
+    sse_encode_String(self.friendlyName, serializer);
+    sse_encode_String(self.manufacturer, serializer);
+    sse_encode_opt_String(self.manufacturerUrl, serializer);
+    sse_encode_opt_String(self.modelDescription, serializer);
+    sse_encode_String(self.modelName, serializer);
+    sse_encode_opt_String(self.modelNumber, serializer);
+    sse_encode_opt_String(self.modelUrl, serializer);
+    sse_encode_opt_String(self.serialNumber, serializer);
+    sse_encode_String(self.udn, serializer);
+    sse_encode_opt_String(self.presentationUrl, serializer);
+    sse_encode_opt_String(self.macAddress, serializer);
+    sse_encode_list_device_icon(self.icons, serializer);
+    sse_encode_list_service_info(self.services, serializer);
+    sse_encode_list_device_description(self.devices, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_device_event(DeviceEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    switch (self) {
+      case DeviceEvent_Added(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_projector_info(field0, serializer);
+      case DeviceEvent_Updated(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_projector_info(field0, serializer);
+      case DeviceEvent_Removed(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_projector_info(field0, serializer);
+      case DeviceEvent_Rebooted(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_projector_info(field0, serializer);
+      case DeviceEvent_DescriptionChanged(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_projector_info(field0, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_device_icon(DeviceIcon self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_32(self.depth, serializer);
+    sse_encode_String(self.url, serializer);
   }
 
   @protected
-  ProjectorInfo sse_decode_box_autoadd_projector_info(
-      SseDeserializer deserializer) {
+  void sse_encode_device_kind(DeviceKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_projector_info(deserializer));
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_device_status(DeviceStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_bool(self.online, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.httpLatencyMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.ssdpLatencyMs, serializer);
+    sse_encode_opt_String(self.error, serializer);
+    sse_encode_opt_box_autoadd_ssdp_message(self.ssdp, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  List<ProjectorInfo> sse_decode_list_projector_info(
-      SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ProjectorInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_projector_info(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_list_action_argument(
+      List<ActionArgument> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_action_argument(item, serializer);
+    }
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_action_info(
+      List<ActionInfo> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_action_info(item, serializer);
     }
   }
 
   @protected
-  ProjectorInfo sse_decode_projector_info(SseDeserializer deserializer) {
+  void sse_encode_list_device_description(
+      List<DeviceDescription> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_friendlyName = sse_decode_String(deserializer);
-    var var_ip = sse_decode_String(deserializer);
-    var var_locationXmlUrl = sse_decode_String(deserializer);
-    var var_avTransportUrl = sse_decode_opt_String(deserializer);
-    var var_renderingControlUrl = sse_decode_opt_String(deserializer);
-    return ProjectorInfo(
-        friendlyName: var_friendlyName,
-        ip: var_ip,
-        locationXmlUrl: var_locationXmlUrl,
-        avTransportUrl: var_avTransportUrl,
-        renderingControlUrl: var_renderingControlUrl);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_device_description(item, serializer);
+    }
   }
 
   @protected
-  (String, String) sse_decode_record_string_string(
-      SseDeserializer deserializer) {
+  void sse_encode_list_device_icon(
+      List<DeviceIcon> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_device_icon(item, serializer);
+    }
   }
 
   @protected
-  (BigInt, BigInt) sse_decode_record_u_64_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_network_interface(
+      List<NetworkInterface> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_64(deserializer);
-    var var_field1 = sse_decode_u_64(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_network_interface(item, serializer);
+    }
   }
 
   @protected
-  TransportState sse_decode_transport_state(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return TransportState.values[inner];
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_projector_info(
+      List<ProjectorInfo> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_projector_info(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_service_description(
+      List<ServiceDescription> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_service_description(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_service_info(
+      List<ServiceInfo> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_service_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
+  void sse_encode_list_ssdp_header(
+      List<SsdpHeader> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_ssdp_header(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_state_variable(
+      List<StateVariable> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_state_variable(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_network_interface(
+      NetworkInterface self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.index, serializer);
+    sse_encode_String(self.ip, serializer);
+    sse_encode_u_8(self.prefixLen, serializer);
+    sse_encode_bool(self.isIpv6, serializer);
+    sse_encode_bool(self.isUp, serializer);
+    sse_encode_bool(self.isLoopback, serializer);
+    sse_encode_bool(self.eligible, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_projector_info(
-      ProjectorInfo self, SseSerializer serializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_projector_info(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_allowed_value_range(
+      AllowedValueRange? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_allowed_value_range(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_ssdp_message(
+      SsdpMessage? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_ssdp_message(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_projector_info(
-      List<ProjectorInfo> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_projector_info(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
   }
 
@@ -758,8 +3554,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.friendlyName, serializer);
     sse_encode_String(self.ip, serializer);
     sse_encode_String(self.locationXmlUrl, serializer);
+    sse_encode_String(self.udn, serializer);
     sse_encode_opt_String(self.avTransportUrl, serializer);
     sse_encode_opt_String(self.renderingControlUrl, serializer);
+    sse_encode_device_description(self.description, serializer);
+    sse_encode_device_kind(self.kind, serializer);
+    sse_encode_device_capabilities(self.capabilities, serializer);
+    sse_encode_opt_box_autoadd_ssdp_message(self.ssdp, serializer);
+    sse_encode_opt_String(self.macAddress, serializer);
+  }
+
+  @protected
+  void sse_encode_reachability_event(
+      ReachabilityEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ReachabilityEvent_Online(device: final device, status: final status):
+        sse_encode_i_32(0, serializer);
+        sse_encode_projector_info(device, serializer);
+        sse_encode_device_status(status, serializer);
+      case ReachabilityEvent_Offline(
+          device: final device, status: final status):
+        sse_encode_i_32(1, serializer);
+        sse_encode_projector_info(device, serializer);
+        sse_encode_device_status(status, serializer);
+      case ReachabilityEvent_Rebooted(
+          device: final device, status: final status):
+        sse_encode_i_32(2, serializer);
+        sse_encode_projector_info(device, serializer);
+        sse_encode_device_status(status, serializer);
+      case ReachabilityEvent_DescriptionChanged(
+          device: final device, status: final status):
+        sse_encode_i_32(3, serializer);
+        sse_encode_projector_info(device, serializer);
+        sse_encode_device_status(status, serializer);
+    }
   }
 
   @protected
@@ -778,6 +3607,116 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_scan_diagnostics(
+      ScanDiagnostics self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.multicastInterfaces, serializer);
+    sse_encode_bool(self.usedBroadcast, serializer);
+    sse_encode_list_String(self.broadcastTargets, serializer);
+    sse_encode_list_String(self.errors, serializer);
+    sse_encode_bool(self.cancelled, serializer);
+    sse_encode_bool(self.usedSweep, serializer);
+    sse_encode_u_32(self.sweptHosts, serializer);
+  }
+
+  @protected
+  void sse_encode_scan_event(ScanEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ScanEvent_DeviceFound(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_projector_info(field0, serializer);
+      case ScanEvent_Completed(
+          total: final total, diagnostics: final diagnostics):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(total, serializer);
+        sse_encode_scan_diagnostics(diagnostics, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_scan_options(ScanOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.timeoutSecs, serializer);
+    sse_encode_list_String(self.searchTargets, serializer);
+    sse_encode_list_String(self.interfaces, serializer);
+    sse_encode_bool(self.enableIpv6, serializer);
+    sse_encode_bool(self.renderersOnly, serializer);
+    sse_encode_u_32(self.sendCount, serializer);
+    sse_encode_u_64(self.sendIntervalMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.mxSecs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.multicastTtl, serializer);
+    sse_encode_bool(self.subnetSweep, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.sweepConcurrency, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.sweepRatePerSec, serializer);
+  }
+
+  @protected
+  void sse_encode_scan_report(ScanReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_projector_info(self.devices, serializer);
+    sse_encode_scan_diagnostics(self.diagnostics, serializer);
+  }
+
+  @protected
+  void sse_encode_service_description(
+      ServiceDescription self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.serviceType, serializer);
+    sse_encode_String(self.scpdUrl, serializer);
+    sse_encode_list_action_info(self.actions, serializer);
+    sse_encode_list_state_variable(self.stateVariables, serializer);
+  }
+
+  @protected
+  void sse_encode_service_info(ServiceInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.serviceType, serializer);
+    sse_encode_String(self.serviceId, serializer);
+    sse_encode_String(self.scpdUrl, serializer);
+    sse_encode_String(self.controlUrl, serializer);
+    sse_encode_String(self.eventSubUrl, serializer);
+  }
+
+  @protected
+  void sse_encode_ssdp_header(SsdpHeader self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_ssdp_message(SsdpMessage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.startLine, serializer);
+    sse_encode_opt_String(self.method, serializer);
+    sse_encode_opt_box_autoadd_u_16(self.statusCode, serializer);
+    sse_encode_opt_String(self.location, serializer);
+    sse_encode_opt_String(self.searchTarget, serializer);
+    sse_encode_opt_String(self.notificationType, serializer);
+    sse_encode_opt_String(self.notificationSubType, serializer);
+    sse_encode_opt_String(self.usn, serializer);
+    sse_encode_opt_String(self.server, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxAge, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bootId, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.nextBootId, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.configId, serializer);
+    sse_encode_opt_box_autoadd_u_16(self.searchPort, serializer);
+    sse_encode_list_ssdp_header(self.headers, serializer);
+  }
+
+  @protected
+  void sse_encode_state_variable(StateVariable self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.dataType, serializer);
+    sse_encode_bool(self.sendEvents, serializer);
+    sse_encode_opt_String(self.defaultValue, serializer);
+    sse_encode_list_String(self.allowedValues, serializer);
+    sse_encode_opt_box_autoadd_allowed_value_range(self.allowedValueRange, serializer);
+  }
+
   @protected
   void sse_encode_transport_state(
       TransportState self, SseSerializer serializer) {
@@ -785,6 +3724,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -801,4 +3752,126 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_wake_options(WakeOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.targetAddress, serializer);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_bool(self.directedBroadcast, serializer);
+    sse_encode_u_32(self.repeatCount, serializer);
+    sse_encode_u_64(self.repeatIntervalMs, serializer);
+    sse_encode_opt_String(self.secureOnPassword, serializer);
+  }
+}
+
+@sealed
+class DeviceMonitorImpl extends RustOpaque implements DeviceMonitor {
+  // Not to be used by end users
+  DeviceMonitorImpl.frbInternalDcoDecode(List<dynamic> wire)
+      : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  DeviceMonitorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_DeviceMonitor,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_DeviceMonitor,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_DeviceMonitorPtr,
+  );
+
+  /// 开始监控，直到调用 stop() 为止；首次检查会推送每个设备的初始状态，
+  /// 设备重启或描述变化时推送刷新后的设备信息
+  Stream<ReachabilityEvent> run() =>
+      RustLib.instance.api.crateApiMonitorDeviceMonitorRun(
+        that: this,
+      );
+
+  /// 停止监控；在 run() 开始前调用时 run() 会立即返回
+  void stop() => RustLib.instance.api.crateApiMonitorDeviceMonitorStop(
+        that: this,
+      );
+}
+
+@sealed
+class NotifyListenerImpl extends RustOpaque implements NotifyListener {
+  // Not to be used by end users
+  NotifyListenerImpl.frbInternalDcoDecode(List<dynamic> wire)
+      : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  NotifyListenerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_NotifyListener,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_NotifyListener,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_NotifyListenerPtr,
+  );
+
+  /// 当前在线设备快照
+  List<ProjectorInfo> devices() =>
+      RustLib.instance.api.crateApiNotifyNotifyListenerDevices(
+        that: this,
+      );
+
+  /// 开始监听，直到调用 stop() 为止；设备变化通过 sink 推送
+  Stream<DeviceEvent> run() =>
+      RustLib.instance.api.crateApiNotifyNotifyListenerRun(
+        that: this,
+      );
+
+  /// 停止监听；在 run() 开始前调用时 run() 会立即返回
+  void stop() => RustLib.instance.api.crateApiNotifyNotifyListenerStop(
+        that: this,
+      );
+}
+
+@sealed
+class ScanHandleImpl extends RustOpaque implements ScanHandle {
+  // Not to be used by end users
+  ScanHandleImpl.frbInternalDcoDecode(List<dynamic> wire)
+      : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ScanHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_ScanHandle,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ScanHandle,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ScanHandlePtr,
+  );
+
+  /// 取消扫描：停止接收响应并中止正在进行的描述文件请求
+  void cancel() => RustLib.instance.api.crateApiCastScanHandleCancel(
+        that: this,
+      );
+
+  /// 执行扫描；被取消时立即返回已发现的设备 (diagnostics.cancelled 为 true)
+  Future<ScanReport> run() => RustLib.instance.api.crateApiCastScanHandleRun(
+        that: this,
+      );
+
+  /// 流式执行扫描，事件与 scan_projectors_stream 相同；被取消时同样以 Completed 结束
+  Stream<ScanEvent> runStream() =>
+      RustLib.instance.api.crateApiCastScanHandleRunStream(
+        that: this,
+      );
 }
@@ -7,6 +7,12 @@
 // ignore_for_file: argument_type_not_assignable
 
 import 'api/cast.dart';
+import 'api/description.dart';
+import 'api/monitor.dart';
+import 'api/network.dart';
+import 'api/notify.dart';
+import 'api/scpd.dart';
+import 'api/ssdp.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -20,42 +26,234 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_DeviceMonitorPtr => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor;
+
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_NotifyListenerPtr => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener;
+
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_ScanHandlePtr => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle;
+
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  DeviceMonitor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      dynamic raw);
+
+  @protected
+  NotifyListener dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      dynamic raw);
+
+  @protected
+  ScanHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      dynamic raw);
+
+  @protected
+  DeviceMonitor dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      dynamic raw);
+
+  @protected
+  NotifyListener dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      dynamic raw);
+
+  @protected
+  ScanHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      dynamic raw);
+
+  @protected
+  DeviceMonitor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      dynamic raw);
+
+  @protected
+  NotifyListener dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      dynamic raw);
+
+  @protected
+  ScanHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<DeviceEvent> dco_decode_StreamSink_device_event_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<ReachabilityEvent> dco_decode_StreamSink_reachability_event_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<ScanEvent> dco_decode_StreamSink_scan_event_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  ActionArgument dco_decode_action_argument(dynamic raw);
+
+  @protected
+  ActionInfo dco_decode_action_info(dynamic raw);
+
+  @protected
+  AllowedValueRange dco_decode_allowed_value_range(dynamic raw);
+
+  @protected
+  ArgumentDirection dco_decode_argument_direction(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  AllowedValueRange dco_decode_box_autoadd_allowed_value_range(dynamic raw);
+
   @protected
   ProjectorInfo dco_decode_box_autoadd_projector_info(dynamic raw);
 
+  @protected
+  ScanOptions dco_decode_box_autoadd_scan_options(dynamic raw);
+
+  @protected
+  ServiceDescription dco_decode_box_autoadd_service_description(dynamic raw);
+
+  @protected
+  SsdpMessage dco_decode_box_autoadd_ssdp_message(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  WakeOptions dco_decode_box_autoadd_wake_options(dynamic raw);
+
+  @protected
+  DeviceCapabilities dco_decode_device_capabilities(dynamic raw);
+
+  @protected
+  DeviceDescription dco_decode_device_description(dynamic raw);
+
+  @protected
+  DeviceEvent dco_decode_device_event(dynamic raw);
+
+  @protected
+  DeviceIcon dco_decode_device_icon(dynamic raw);
+
+  @protected
+  DeviceKind dco_decode_device_kind(dynamic raw);
+
+  @protected
+  DeviceStatus dco_decode_device_status(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<ActionArgument> dco_decode_list_action_argument(dynamic raw);
+
+  @protected
+  List<ActionInfo> dco_decode_list_action_info(dynamic raw);
+
+  @protected
+  List<DeviceDescription> dco_decode_list_device_description(dynamic raw);
+
+  @protected
+  List<DeviceIcon> dco_decode_list_device_icon(dynamic raw);
+
+  @protected
+  List<NetworkInterface> dco_decode_list_network_interface(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
   List<ProjectorInfo> dco_decode_list_projector_info(dynamic raw);
 
+  @protected
+  List<ServiceDescription> dco_decode_list_service_description(dynamic raw);
+
+  @protected
+  List<ServiceInfo> dco_decode_list_service_info(dynamic raw);
+
+  @protected
+  List<SsdpHeader> dco_decode_list_ssdp_header(dynamic raw);
+
+  @protected
+  List<StateVariable> dco_decode_list_state_variable(dynamic raw);
+
+  @protected
+  NetworkInterface dco_decode_network_interface(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  AllowedValueRange? dco_decode_opt_box_autoadd_allowed_value_range(
+      dynamic raw);
+
+  @protected
+  SsdpMessage? dco_decode_opt_box_autoadd_ssdp_message(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
   @protected
   ProjectorInfo dco_decode_projector_info(dynamic raw);
 
+  @protected
+  ReachabilityEvent dco_decode_reachability_event(dynamic raw);
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
   @protected
   (BigInt, BigInt) dco_decode_record_u_64_u_64(dynamic raw);
 
+  @protected
+  ScanDiagnostics dco_decode_scan_diagnostics(dynamic raw);
+
+  @protected
+  ScanEvent dco_decode_scan_event(dynamic raw);
+
+  @protected
+  ScanOptions dco_decode_scan_options(dynamic raw);
+
+  @protected
+  ScanReport dco_decode_scan_report(dynamic raw);
+
+  @protected
+  ServiceDescription dco_decode_service_description(dynamic raw);
+
+  @protected
+  ServiceInfo dco_decode_service_info(dynamic raw);
+
+  @protected
+  SsdpHeader dco_decode_ssdp_header(dynamic raw);
+
+  @protected
+  SsdpMessage dco_decode_ssdp_message(dynamic raw);
+
+  @protected
+  StateVariable dco_decode_state_variable(dynamic raw);
+
   @protected
   TransportState dco_decode_transport_state(dynamic raw);
 
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_u_64(dynamic raw);
 
@@ -65,22 +263,152 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  WakeOptions dco_decode_wake_options(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  DeviceMonitor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      SseDeserializer deserializer);
+
+  @protected
+  NotifyListener sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      SseDeserializer deserializer);
+
+  @protected
+  ScanHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      SseDeserializer deserializer);
+
+  @protected
+  DeviceMonitor sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      SseDeserializer deserializer);
+
+  @protected
+  NotifyListener sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      SseDeserializer deserializer);
+
+  @protected
+  ScanHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      SseDeserializer deserializer);
+
+  @protected
+  DeviceMonitor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      SseDeserializer deserializer);
+
+  @protected
+  NotifyListener sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      SseDeserializer deserializer);
+
+  @protected
+  ScanHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<DeviceEvent> sse_decode_StreamSink_device_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ReachabilityEvent> sse_decode_StreamSink_reachability_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ScanEvent> sse_decode_StreamSink_scan_event_Sse(
+      SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  ActionArgument sse_decode_action_argument(SseDeserializer deserializer);
+
+  @protected
+  ActionInfo sse_decode_action_info(SseDeserializer deserializer);
+
+  @protected
+  AllowedValueRange sse_decode_allowed_value_range(
+      SseDeserializer deserializer);
+
+  @protected
+  ArgumentDirection sse_decode_argument_direction(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  AllowedValueRange sse_decode_box_autoadd_allowed_value_range(
+      SseDeserializer deserializer);
+
   @protected
   ProjectorInfo sse_decode_box_autoadd_projector_info(
       SseDeserializer deserializer);
 
+  @protected
+  ScanOptions sse_decode_box_autoadd_scan_options(SseDeserializer deserializer);
+
+  @protected
+  ServiceDescription sse_decode_box_autoadd_service_description(
+      SseDeserializer deserializer);
+
+  @protected
+  SsdpMessage sse_decode_box_autoadd_ssdp_message(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  WakeOptions sse_decode_box_autoadd_wake_options(SseDeserializer deserializer);
+
+  @protected
+  DeviceCapabilities sse_decode_device_capabilities(
+      SseDeserializer deserializer);
+
+  @protected
+  DeviceDescription sse_decode_device_description(SseDeserializer deserializer);
+
+  @protected
+  DeviceEvent sse_decode_device_event(SseDeserializer deserializer);
+
+  @protected
+  DeviceIcon sse_decode_device_icon(SseDeserializer deserializer);
+
+  @protected
+  DeviceKind sse_decode_device_kind(SseDeserializer deserializer);
+
+  @protected
+  DeviceStatus sse_decode_device_status(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<ActionArgument> sse_decode_list_action_argument(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ActionInfo> sse_decode_list_action_info(SseDeserializer deserializer);
+
+  @protected
+  List<DeviceDescription> sse_decode_list_device_description(
+      SseDeserializer deserializer);
+
+  @protected
+  List<DeviceIcon> sse_decode_list_device_icon(SseDeserializer deserializer);
+
+  @protected
+  List<NetworkInterface> sse_decode_list_network_interface(
+      SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -88,12 +416,46 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<ProjectorInfo> sse_decode_list_projector_info(
       SseDeserializer deserializer);
 
+  @protected
+  List<ServiceDescription> sse_decode_list_service_description(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ServiceInfo> sse_decode_list_service_info(SseDeserializer deserializer);
+
+  @protected
+  List<SsdpHeader> sse_decode_list_ssdp_header(SseDeserializer deserializer);
+
+  @protected
+  List<StateVariable> sse_decode_list_state_variable(
+      SseDeserializer deserializer);
+
+  @protected
+  NetworkInterface sse_decode_network_interface(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  AllowedValueRange? sse_decode_opt_box_autoadd_allowed_value_range(
+      SseDeserializer deserializer);
+
+  @protected
+  SsdpMessage? sse_decode_opt_box_autoadd_ssdp_message(
+      SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   ProjectorInfo sse_decode_projector_info(SseDeserializer deserializer);
 
+  @protected
+  ReachabilityEvent sse_decode_reachability_event(SseDeserializer deserializer);
+
   @protected
   (String, String) sse_decode_record_string_string(
       SseDeserializer deserializer);
@@ -101,9 +463,43 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (BigInt, BigInt) sse_decode_record_u_64_u_64(SseDeserializer deserializer);
 
+  @protected
+  ScanDiagnostics sse_decode_scan_diagnostics(SseDeserializer deserializer);
+
+  @protected
+  ScanEvent sse_decode_scan_event(SseDeserializer deserializer);
+
+  @protected
+  ScanOptions sse_decode_scan_options(SseDeserializer deserializer);
+
+  @protected
+  ScanReport sse_decode_scan_report(SseDeserializer deserializer);
+
+  @protected
+  ServiceDescription sse_decode_service_description(
+      SseDeserializer deserializer);
+
+  @protected
+  ServiceInfo sse_decode_service_info(SseDeserializer deserializer);
+
+  @protected
+  SsdpHeader sse_decode_ssdp_header(SseDeserializer deserializer);
+
+  @protected
+  SsdpMessage sse_decode_ssdp_message(SseDeserializer deserializer);
+
+  @protected
+  StateVariable sse_decode_state_variable(SseDeserializer deserializer);
+
   @protected
   TransportState sse_decode_transport_state(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_u_64(SseDeserializer deserializer);
 
@@ -113,23 +509,161 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  WakeOptions sse_decode_wake_options(SseDeserializer deserializer);
+
   @protected
   void sse_encode_AnyhowException(
       AnyhowException self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      DeviceMonitor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      NotifyListener self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      ScanHandle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      DeviceMonitor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      NotifyListener self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      ScanHandle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+      DeviceMonitor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+      NotifyListener self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+      ScanHandle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_device_event_Sse(
+      RustStreamSink<DeviceEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_reachability_event_Sse(
+      RustStreamSink<ReachabilityEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_scan_event_Sse(
+      RustStreamSink<ScanEvent> self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_action_argument(
+      ActionArgument self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_action_info(ActionInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_allowed_value_range(
+      AllowedValueRange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_argument_direction(
+      ArgumentDirection self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_allowed_value_range(
+      AllowedValueRange self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_projector_info(
       ProjectorInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_scan_options(
+      ScanOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_service_description(
+      ServiceDescription self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_ssdp_message(
+      SsdpMessage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_wake_options(
+      WakeOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_device_capabilities(
+      DeviceCapabilities self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_device_description(
+      DeviceDescription self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_device_event(DeviceEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_device_icon(DeviceIcon self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_device_kind(DeviceKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_device_status(DeviceStatus self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_action_argument(
+      List<ActionArgument> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_action_info(
+      List<ActionInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_device_description(
+      List<DeviceDescription> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_device_icon(
+      List<DeviceIcon> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_network_interface(
+      List<NetworkInterface> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
@@ -138,12 +672,50 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_projector_info(
       List<ProjectorInfo> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_service_description(
+      List<ServiceDescription> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_service_info(
+      List<ServiceInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_ssdp_header(
+      List<SsdpHeader> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_state_variable(
+      List<StateVariable> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_network_interface(
+      NetworkInterface self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_allowed_value_range(
+      AllowedValueRange? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_ssdp_message(
+      SsdpMessage? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_projector_info(ProjectorInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_reachability_event(
+      ReachabilityEvent self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_string_string(
       (String, String) self, SseSerializer serializer);
@@ -152,10 +724,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_record_u_64_u_64(
       (BigInt, BigInt) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_scan_diagnostics(
+      ScanDiagnostics self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_event(ScanEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_options(ScanOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_report(ScanReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_service_description(
+      ServiceDescription self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_service_info(ServiceInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ssdp_header(SsdpHeader self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ssdp_message(SsdpMessage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_state_variable(StateVariable self, SseSerializer serializer);
+
   @protected
   void sse_encode_transport_state(
       TransportState self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
@@ -164,12 +771,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_wake_options(WakeOptions self, SseSerializer serializer);
 }
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+          int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+              ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+          int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+              ptr);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+          int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+              ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+          int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+              ptr);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+          int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+              ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+          int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+              ptr);
 }
 
 @JS('wasm_bindgen')
@@ -177,4 +826,28 @@ external RustLibWasmModule get wasmModule;
 
 @JS()
 @anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceMonitor(
+          int ptr);
+
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNotifyListener(
+          int ptr);
+
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerScanHandle(
+          int ptr);
+}
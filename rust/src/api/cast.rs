@@ -1,24 +1,198 @@
+use crate::api::description::{
+    parse_description, DeviceCapabilities, DeviceDescription, DeviceIcon, DeviceKind,
+};
+use crate::api::icon::{best_icon, device_icons, fetch_icon_cached};
+use crate::api::monitor::{check_device, DeviceStatus};
+use crate::api::scpd::{ensure_action_supported, fetch_service_description, ServiceDescription};
+use crate::api::ssdp::{parse_ssdp_message, SsdpMessage};
+use crate::api::sweep::sweep_subnets;
+use crate::api::network::{
+    directed_broadcast, is_link_local_v6, is_vpn_ip, link_local_scope, lookup_neighbor_mac,
+    parse_mac, parse_zone, remember_link_local_scope, select_ipv6_interfaces,
+    select_scan_interfaces, set_link_local_scope, url_ipv6,
+};
+use crate::frb_generated::StreamSink;
 use anyhow::{Context, Result};
 use flutter_rust_bridge::frb;
-use reqwest::Client;
-use socket2::{Domain, Protocol, Socket, Type};
-use std::mem::MaybeUninit;
-use std::net::{Ipv4Addr, SocketAddrV4};
-use std::time::Duration;
+use futures::stream::FuturesUnordered;
+use futures::{Stream, StreamExt};
+use reqwest::{Client, Method};
+use serde::{Deserialize, Serialize};
+use socket2::{Domain, Protocol, SockRef, Socket, Type};
+use std::collections::{HashSet, VecDeque};
+use std::convert::Infallible;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
 
 const AV_SERVICE: &str = "urn:schemas-upnp-org:service:AVTransport:1";
 const RC_SERVICE: &str = "urn:schemas-upnp-org:service:RenderingControl:1";
 
+// SSDP 多播地址和端口
+pub(crate) const SSDP_ADDR: Ipv4Addr = Ipv4Addr::new(239, 255, 255, 250);
+pub(crate) const SSDP_PORT: u16 = 1900;
+// IPv6 SSDP 多播地址：FF02::C 链路本地，FF05::C 站点本地
+pub(crate) const SSDP_ADDRS_V6: [Ipv6Addr; 2] = [
+    Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0xc),
+    Ipv6Addr::new(0xff05, 0, 0, 0, 0, 0, 0, 0xc),
+];
+
+// 未指定搜索目标时使用的 ST
+const DEFAULT_SEARCH_TARGET: &str = "upnp:rootdevice";
+// M-SEARCH 默认发送次数、间隔、MX 上限及组播 TTL
+const DEFAULT_SEND_COUNT: u32 = 3;
+const DEFAULT_SEND_INTERVAL_MS: u64 = 500;
+const DEFAULT_MX_SECS: u32 = 3;
+const DEFAULT_MULTICAST_TTL: u32 = 2;
+// 扫描时同时下载的描述文件数量上限
+pub(crate) const MAX_CONCURRENT_FETCHES: usize = 8;
+// 扫描截止后等待进行中的描述文件请求的最长时间 (与单个请求的超时相同)
+const FETCH_GRACE_PERIOD: Duration = Duration::from_secs(2);
+// 链路本地 IPv6 请求使用的占位主机名 (实际地址通过 resolve 指定)
+const LINK_LOCAL_HOST: &str = "link-local.invalid";
+// Wake-on-LAN 默认端口 (discard)
+const WOL_DEFAULT_PORT: u16 = 9;
+// wake_and_wait 每轮搜索的超时、AVTransport 请求超时及失败后的重试间隔
+const WAKE_SEARCH_TIMEOUT_SECS: u64 = 2;
+const WAKE_CONTROL_TIMEOUT: Duration = Duration::from_secs(3);
+const WAKE_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+// 超时上限 (一年)：过大的超时 (如 u64::MAX) 与 Instant 相加会溢出
+const MAX_TIMEOUT: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
 // --- 数据模型 ---
 
 #[frb(dart_metadata=("freezed"))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectorInfo {
     pub friendly_name: String, // 设备名 (如 "客厅的投影仪")
-    pub ip: String,
+    pub ip: String,               // IPv4 或 IPv6 地址 (IPv6 不带方括号)
     pub location_xml_url: String, // 设备描述文件地址
+    pub udn: String,              // 设备唯一标识 (如 uuid:4d696e69-444c-164e-9d41-b827eb96c6c2)，用于去重
     pub av_transport_url: Option<String>,
     pub rendering_control_url: Option<String>,
+    pub description: DeviceDescription, // 完整的设备描述 (型号、厂商、图标、服务列表、嵌入设备)
+    // 以下字段晚于 JSON 持久化格式加入，缺失时使用默认值，以便读取旧版本保存的设备
+    #[serde(default)]
+    pub kind: DeviceKind,
+    #[serde(default)]
+    pub capabilities: DeviceCapabilities,
+    #[serde(default)]
+    pub ssdp: Option<SsdpMessage>, // 发现该设备的 SSDP 响应 (SERVER、BOOTID 等)，手动按 LOCATION 添加时为 None
+    /// 网卡 MAC (aa:bb:cc:dd:ee:ff)，用于远程开机；优先取系统邻居表，其次取描述文件
+    /// 邻居表仅对同一子网的设备有效；iOS 上不可用，Android 10+ 的应用通常无权读取，此时多为 null
+    #[serde(default)]
+    pub mac_address: Option<String>,
+}
+
+// 扫描参数
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    pub timeout_secs: u64,
+    // 搜索目标 (ST)，如 urn:schemas-upnp-org:device:MediaRenderer:1、ssdp:all；为空时使用 upnp:rootdevice
+    pub search_targets: Vec<String>,
+    // 参与扫描的接口名或 IP (见 list_network_interfaces)，为空时使用所有可用接口
+    pub interfaces: Vec<String>,
+    // 同时通过 IPv6 (FF02::C / FF05::C) 搜索
+    pub enable_ipv6: bool,
+    // 只返回媒体渲染器 (DeviceKind::MediaRenderer)，过滤掉路由器、NAS 等
+    pub renderers_only: bool,
+    // M-SEARCH 发送次数 (UDP 可能丢包，UPnP 规范建议多次发送)，0 视为 1
+    pub send_count: u32,
+    // 两次发送之间的间隔 (毫秒)
+    pub send_interval_ms: u64,
+    // MX：设备随机延迟响应的最大秒数 (1-5)，为空时取 timeout_secs 且不超过 3
+    pub mx_secs: Option<u32>,
+    // 组播 TTL / IPv6 跳数 (1-255)，为空时为 2
+    pub multicast_ttl: Option<u32>,
+    // 组播、广播都没有发现设备时，逐个探测本机子网内的主机 (单播 M-SEARCH + 常见描述文件端口)
+    pub subnet_sweep: bool,
+    // 子网扫描同时探测的主机数，为空时为 32
+    pub sweep_concurrency: Option<u32>,
+    // 子网扫描每秒新开始探测的主机数 (1-1000)，为空时为 50
+    pub sweep_rate_per_sec: Option<u32>,
+}
+
+impl ScanOptions {
+    /// 默认参数：搜索 rootdevice，发送 3 次，间隔 500ms；其余字段可在 Dart 侧用 copyWith 修改
+    #[frb(sync)]
+    pub fn with_timeout(timeout_secs: u64) -> Self {
+        Self {
+            timeout_secs,
+            search_targets: vec![DEFAULT_SEARCH_TARGET.to_string()],
+            interfaces: Vec::new(),
+            enable_ipv6: true,
+            renderers_only: false,
+            send_count: DEFAULT_SEND_COUNT,
+            send_interval_ms: DEFAULT_SEND_INTERVAL_MS,
+            mx_secs: None,
+            multicast_ttl: None,
+            subnet_sweep: false,
+            sweep_concurrency: None,
+            sweep_rate_per_sec: None,
+        }
+    }
+
+    // UDA 1.1 规定 MX 取值 1-5
+    fn mx(&self) -> u32 {
+        self.mx_secs
+            .unwrap_or(self.timeout_secs.min(DEFAULT_MX_SECS as u64) as u32)
+            .clamp(1, 5)
+    }
+
+    // TTL 为 0 时组播发不出本机，超过 255 时 setsockopt 会报错
+    fn ttl(&self) -> u32 {
+        self.multicast_ttl
+            .unwrap_or(DEFAULT_MULTICAST_TTL)
+            .clamp(1, 255)
+    }
+
+    // 是否保留该设备 (renderers_only 时只保留媒体渲染器)
+    pub(crate) fn accepts(&self, info: &ProjectorInfo) -> bool {
+        !self.renderers_only || info.kind == DeviceKind::MediaRenderer
+    }
+
+    // 实际发送的搜索目标
+    pub(crate) fn targets(&self) -> Vec<&str> {
+        if self.search_targets.is_empty() {
+            vec![DEFAULT_SEARCH_TARGET]
+        } else {
+            self.search_targets.iter().map(String::as_str).collect()
+        }
+    }
+}
+
+// 扫描诊断信息，用于排查"找不到设备"一类问题
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Default)]
+pub struct ScanDiagnostics {
+    pub multicast_interfaces: Vec<String>, // 发送 IPv4 组播的接口地址
+    pub used_broadcast: bool,              // 组播失败后是否走了广播回退
+    pub broadcast_targets: Vec<String>,    // 广播回退时按接口掩码计算出的目标地址
+    pub errors: Vec<String>,               // 扫描过程中被忽略的错误
+    pub cancelled: bool,                   // 扫描是否被 ScanHandle::cancel 提前结束
+    pub used_sweep: bool,                  // 是否走了单播子网扫描
+    pub swept_hosts: u32,                  // 子网扫描探测的主机数
+}
+
+// 扫描结果 (设备列表 + 诊断信息)
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ScanReport {
+    pub devices: Vec<ProjectorInfo>,
+    pub diagnostics: ScanDiagnostics,
+}
+
+// 流式扫描事件
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub enum ScanEvent {
+    DeviceFound(ProjectorInfo), // 解析完一个设备描述后立即推送
+    // 扫描结束，total 为去重后的设备数
+    Completed {
+        total: u32,
+        diagnostics: ScanDiagnostics,
+    },
 }
 
 // --- ProjectorInfo 方法实现 ---
@@ -37,6 +211,106 @@ impl ProjectorInfo {
             .ok_or_else(|| anyhow::anyhow!("RenderingControl URL not available for this device"))
     }
 
+    // 调用前按 SCPD 检查动作是否受支持，避免等到设备返回 500 才发现
+    async fn send_action(
+        &self,
+        control_url: &str,
+        service_type: &str,
+        action: &str,
+        args: &str,
+    ) -> Result<String> {
+        ensure_action_supported(&self.description, service_type, action).await?;
+        send_soap_action(control_url, service_type, action, args).await
+    }
+
+    /// 获取设备所有服务的 SCPD (动作、参数、状态变量)，包括嵌入设备的服务
+    /// 单个服务的 SCPD 获取失败时跳过该服务，只有全部失败时才返回错误
+    pub async fn fetch_services(&self) -> Result<Vec<ServiceDescription>> {
+        let all_services = self.description.all_services();
+        let results = futures::future::join_all(all_services.iter().map(|service| {
+            fetch_service_description(&service.service_type, &service.scpd_url)
+        }))
+        .await;
+
+        let mut services = Vec::new();
+        let mut first_error = None;
+        for result in results {
+            match result {
+                Ok(service) => services.push(service),
+                Err(e) => {
+                    println!("SCPD: {:#}, skipping service", e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        match first_error {
+            Some(e) if services.is_empty() => Err(e),
+            _ => Ok(services),
+        }
+    }
+
+    /// 设备是否支持指定服务的指定动作 (如 AVTransport:1 的 Seek)
+    pub async fn supports_action(&self, service_type: String, action: String) -> Result<bool> {
+        let service = self
+            .description
+            .find_service(&service_type)
+            .ok_or_else(|| anyhow::anyhow!("Service {} not available for this device", service_type))?;
+        let scpd = fetch_service_description(&service.service_type, &service.scpd_url).await?;
+        Ok(scpd.find_action(&action).is_some())
+    }
+
+    /// 刷新设备地址和控制 URL：先尝试保存的 LOCATION，不可达或 UDN 不符时按 UDN 重新搜索
+    pub async fn refresh(&self, timeout_secs: u64) -> Result<ProjectorInfo> {
+        let saved = parse_device_xml(&self.location_xml_url)
+            .await
+            .ok()
+            .map(|mut info| {
+                fill_missing_udn(&mut info, None);
+                info
+            })
+            .filter(|info| info.udn == self.udn);
+        let mut info = match saved {
+            Some(mut info) => {
+                info.ssdp = self.ssdp.clone();
+                info
+            }
+            None => resolve_device(self.udn.clone(), timeout_secs).await?,
+        };
+        // 查不到 MAC 时 (如设备已跨网段) 保留之前记录的地址
+        if info.mac_address.is_none() {
+            info.mac_address = self.mac_address.clone();
+        }
+        Ok(info)
+    }
+
+    /// 向设备发送 Wake-on-LAN 魔术包，需要已知 mac_address
+    pub fn wake(&self) -> Result<()> {
+        let mac_address = self
+            .mac_address
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("MAC address of {} is unknown", self.friendly_name))?;
+        wake_on_lan(mac_address)
+    }
+
+    /// 检查设备是否在线：同时请求描述文件 (HEAD，失败时 GET) 并发送单播 M-SEARCH，返回各自的延迟
+    pub async fn check_alive(&self, timeout_ms: u64) -> DeviceStatus {
+        check_device(self, Duration::from_millis(timeout_ms)).await
+    }
+
+    /// 设备描述中声明的图标 (mimetype、宽高、色深、URL)
+    #[frb(sync)]
+    pub fn icons(&self) -> Vec<DeviceIcon> {
+        device_icons(&self.description)
+    }
+
+    /// 下载最接近 preferred_size 像素的图标，返回图片数据 (按设备 UDN 缓存)
+    pub async fn fetch_icon(&self, preferred_size: u32) -> Result<Vec<u8>> {
+        let icons = self.icons();
+        let icon = best_icon(&icons, preferred_size)
+            .ok_or_else(|| anyhow::anyhow!("Device does not provide any icon"))?;
+        fetch_icon_cached(&self.udn, &icon.url).await
+    }
+
     /// 投送视频 (SetAVTransportURI + Play)
     pub async fn cast_video(&self, video_url: String) -> Result<()> {
         let control_url = self.get_av_url()?;
@@ -44,7 +318,7 @@ impl ProjectorInfo {
             "<InstanceID>0</InstanceID><CurrentURI>{}</CurrentURI><CurrentURIMetaData></CurrentURIMetaData>",
             video_url
         );
-        send_soap_action(control_url, AV_SERVICE, "SetAVTransportURI", &args).await?;
+        self.send_action(control_url, AV_SERVICE, "SetAVTransportURI", &args).await?;
         self.play().await?;
         Ok(())
     }
@@ -52,7 +326,7 @@ impl ProjectorInfo {
     /// 播放
     pub async fn play(&self) -> Result<()> {
         let control_url = self.get_av_url()?;
-        send_soap_action(
+        self.send_action(
             control_url,
             AV_SERVICE,
             "Play",
@@ -65,7 +339,7 @@ impl ProjectorInfo {
     /// 暂停
     pub async fn pause(&self) -> Result<()> {
         let control_url = self.get_av_url()?;
-        send_soap_action(
+        self.send_action(
             control_url,
             AV_SERVICE,
             "Pause",
@@ -78,7 +352,7 @@ impl ProjectorInfo {
     /// 停止
     pub async fn stop(&self) -> Result<()> {
         let control_url = self.get_av_url()?;
-        send_soap_action(
+        self.send_action(
             control_url,
             AV_SERVICE,
             "Stop",
@@ -95,7 +369,7 @@ impl ProjectorInfo {
             "<InstanceID>0</InstanceID><Unit>REL_TIME</Unit><Target>{}</Target>",
             target_time
         );
-        send_soap_action(control_url, AV_SERVICE, "Seek", &args).await?;
+        self.send_action(control_url, AV_SERVICE, "Seek", &args).await?;
         Ok(())
     }
 
@@ -106,7 +380,7 @@ impl ProjectorInfo {
             "<InstanceID>0</InstanceID><Channel>Master</Channel><DesiredVolume>{}</DesiredVolume>",
             volume
         );
-        send_soap_action(control_url, RC_SERVICE, "SetVolume", &args).await?;
+        self.send_action(control_url, RC_SERVICE, "SetVolume", &args).await?;
         Ok(())
     }
 
@@ -114,7 +388,7 @@ impl ProjectorInfo {
     pub async fn get_volume(&self) -> Result<u8> {
         let control_url = self.get_rc_url()?;
         let args = "<InstanceID>0</InstanceID><Channel>Master</Channel>";
-        let xml = send_soap_action(control_url, RC_SERVICE, "GetVolume", args).await?;
+        let xml = self.send_action(control_url, RC_SERVICE, "GetVolume", args).await?;
 
         let regex = regex::Regex::new(
             r"(?i)<(?:.*:)?CurrentVolume[^>]*>(\s*\d+\s*)</(?:.*:)?CurrentVolume>",
@@ -138,7 +412,7 @@ impl ProjectorInfo {
             "<InstanceID>0</InstanceID><Channel>Master</Channel><DesiredMute>{}</DesiredMute>",
             val
         );
-        send_soap_action(control_url, RC_SERVICE, "SetMute", &args).await?;
+        self.send_action(control_url, RC_SERVICE, "SetMute", &args).await?;
         Ok(())
     }
 
@@ -146,7 +420,7 @@ impl ProjectorInfo {
     pub async fn get_position_info(&self) -> Result<(String, String)> {
         let control_url = self.get_av_url()?;
         let args = "<InstanceID>0</InstanceID>";
-        let xml = send_soap_action(control_url, AV_SERVICE, "GetPositionInfo", args).await?;
+        let xml = self.send_action(control_url, AV_SERVICE, "GetPositionInfo", args).await?;
 
         let cur_regex = regex::Regex::new(r"<RelTime>(.*?)</RelTime>").unwrap();
         let dur_regex = regex::Regex::new(r"<TrackDuration>(.*?)</TrackDuration>").unwrap();
@@ -169,7 +443,7 @@ impl ProjectorInfo {
     pub async fn get_position_info_sec(&self) -> Result<(u64, u64)> {
         let control_url = self.get_av_url()?;
         let args = "<InstanceID>0</InstanceID>";
-        let xml = send_soap_action(control_url, AV_SERVICE, "GetPositionInfo", args).await?;
+        let xml = self.send_action(control_url, AV_SERVICE, "GetPositionInfo", args).await?;
 
         let cur_regex = regex::Regex::new(r"(?i)<RelTime[^>]*>(.*?)</RelTime>").unwrap();
         let dur_regex =
@@ -202,7 +476,7 @@ impl ProjectorInfo {
     pub async fn get_transport_info(&self) -> Result<TransportState> {
         let control_url = self.get_av_url()?;
         let args = "<InstanceID>0</InstanceID>";
-        let xml = send_soap_action(control_url, AV_SERVICE, "GetTransportInfo", args).await?;
+        let xml = self.send_action(control_url, AV_SERVICE, "GetTransportInfo", args).await?;
 
         let regex = regex::Regex::new(
             r"(?i)<(?:.*:)?CurrentTransportState[^>]*>(.*?)</(?:.*:)?CurrentTransportState>",
@@ -230,122 +504,419 @@ impl ProjectorInfo {
 
 // --- 1. 扫描功能 (服务发现) ---
 
+// 发现新设备时的回调
+pub(crate) type OnDeviceFound<'a> = dyn Fn(&ProjectorInfo) + Send + Sync + 'a;
+
 pub async fn scan_projectors(timeout_secs: u64) -> Result<Vec<ProjectorInfo>> {
-    // SSDP 多播地址和端口
-    const SSDP_ADDR: Ipv4Addr = Ipv4Addr::new(239, 255, 255, 250);
-    const SSDP_PORT: u16 = 1900;
+    let options = ScanOptions::with_timeout(timeout_secs);
+    Ok(discover_projectors(&options, &|_| {}, &never_cancelled()).await?.devices)
+}
 
-    // 构造 M-SEARCH 请求
-    let search_request = format!(
-        "M-SEARCH * HTTP/1.1\r\n\
-         HOST: {}:{}\r\n\
-         MAN: \"ssdp:discover\"\r\n\
-         MX: {}\r\n\
-         ST: upnp:rootdevice\r\n\
-         \r\n",
-        SSDP_ADDR, SSDP_PORT, timeout_secs
-    );
+/// 按指定搜索目标扫描，只返回 ST 匹配的设备
+pub async fn scan_projectors_with_options(options: ScanOptions) -> Result<Vec<ProjectorInfo>> {
+    Ok(discover_projectors(&options, &|_| {}, &never_cancelled()).await?.devices)
+}
+
+/// 扫描并返回诊断信息 (使用的接口、广播目标等)
+pub async fn scan_projectors_with_report(options: ScanOptions) -> Result<ScanReport> {
+    discover_projectors(&options, &|_| {}, &never_cancelled()).await
+}
+
+/// 流式扫描：每解析完一个设备立即推送 DeviceFound，结束时推送 Completed
+pub async fn scan_projectors_stream(options: ScanOptions, sink: StreamSink<ScanEvent>) -> Result<()> {
+    stream_scan(&options, &sink, &never_cancelled()).await
+}
+
+/// 可取消的扫描。一个 ScanHandle 只对应一次扫描，取消后需重新创建
+#[frb(opaque)]
+pub struct ScanHandle {
+    options: ScanOptions,
+    cancel_tx: watch::Sender<bool>,
+}
+
+impl ScanHandle {
+    #[frb(sync)]
+    pub fn new(options: ScanOptions) -> Self {
+        let (cancel_tx, _) = watch::channel(false);
+        Self { options, cancel_tx }
+    }
+
+    /// 执行扫描；被取消时立即返回已发现的设备 (diagnostics.cancelled 为 true)
+    pub async fn run(&self) -> Result<ScanReport> {
+        discover_projectors(&self.options, &|_| {}, &self.cancel_tx.subscribe()).await
+    }
+
+    /// 流式执行扫描，事件与 scan_projectors_stream 相同；被取消时同样以 Completed 结束
+    pub async fn run_stream(&self, sink: StreamSink<ScanEvent>) -> Result<()> {
+        stream_scan(&self.options, &sink, &self.cancel_tx.subscribe()).await
+    }
+
+    /// 取消扫描：停止接收响应并中止正在进行的描述文件请求
+    #[frb(sync)]
+    pub fn cancel(&self) {
+        let _ = self.cancel_tx.send_replace(true);
+    }
+}
+
+async fn stream_scan(
+    options: &ScanOptions,
+    sink: &StreamSink<ScanEvent>,
+    cancel: &watch::Receiver<bool>,
+) -> Result<()> {
+    let on_found = |info: &ProjectorInfo| {
+        let _ = sink.add(ScanEvent::DeviceFound(info.clone()));
+    };
+    let report = discover_projectors(options, &on_found, cancel).await?;
+    let _ = sink.add(ScanEvent::Completed {
+        total: report.devices.len() as u32,
+        diagnostics: report.diagnostics,
+    });
+    Ok(())
+}
+
+// 不可取消的扫描：发送端立即被丢弃，wait_cancelled 永远不会返回
+fn never_cancelled() -> watch::Receiver<bool> {
+    watch::channel(false).1
+}
+
+// 等待取消信号 (已取消时立即返回)
+pub(crate) async fn wait_cancelled(cancel: &mut watch::Receiver<bool>) {
+    if cancel.wait_for(|cancelled| *cancelled).await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+async fn discover_projectors(
+    options: &ScanOptions,
+    on_found: &OnDeviceFound<'_>,
+    cancel: &watch::Receiver<bool>,
+) -> Result<ScanReport> {
+    // 每个 ST 单独构造一个 M-SEARCH 请求 (一个请求只能携带一个 ST)
+    let host = format!("{}:{}", SSDP_ADDR, SSDP_PORT);
+    let search_requests: Vec<String> = options
+        .targets()
+        .into_iter()
+        .map(|st| build_search_request(&host, st, options.mx()))
+        .collect();
 
     let mut devices = Vec::new();
+    let mut diagnostics = ScanDiagnostics::default();
     
     // 尝试方法1: 标准组播 SSDP
     println!("SSDP: Trying multicast method...");
-    match try_multicast_ssdp(&search_request, timeout_secs).await {
-        Ok(found_devices) => {
-            println!("SSDP: Multicast succeeded, found {} devices", found_devices.len());
-            for dev in found_devices {
-                if !devices.iter().any(|d: &ProjectorInfo| d.ip == dev.ip) {
-                    devices.push(dev);
-                }
-            }
+    let multicast_result = try_multicast_ssdp(
+        &search_requests,
+        options,
+        &mut devices,
+        &mut diagnostics,
+        on_found,
+        cancel,
+    )
+    .await;
+    match multicast_result {
+        Ok(()) => {
+            println!("SSDP: Multicast succeeded, found {} devices", devices.len());
+        }
+        // 已取消时不再走广播回退
+        Err(e) if *cancel.borrow() => {
+            diagnostics.errors.push(format!("Multicast failed: {:#}", e));
         }
         Err(e) => {
             println!("SSDP: Multicast failed: {}, trying broadcast method...", e);
+            diagnostics.errors.push(format!("Multicast failed: {:#}", e));
+            diagnostics.used_broadcast = true;
             
-            // 方法2: 使用广播发送到子网
-            match try_broadcast_ssdp(&search_request, timeout_secs).await {
-                Ok(found_devices) => {
-                    println!("SSDP: Broadcast succeeded, found {} devices", found_devices.len());
-                    for dev in found_devices {
-                        if !devices.iter().any(|d: &ProjectorInfo| d.ip == dev.ip) {
-                            devices.push(dev);
-                        }
-                    }
+            // 方法2: 使用广播发送到子网 (组播阶段已发现的设备保留，不会重复推送)
+            let broadcast_result = try_broadcast_ssdp(
+                &search_requests,
+                options,
+                &mut devices,
+                &mut diagnostics,
+                on_found,
+                cancel,
+            )
+            .await;
+            match broadcast_result {
+                Ok(()) => {
+                    println!("SSDP: Broadcast succeeded, found {} devices", devices.len());
                 }
                 Err(e2) => {
                     println!("SSDP: Broadcast also failed: {}", e2);
-                    // 如果两种方法都失败，返回原始错误
-                    return Err(e.context("Both multicast and broadcast SSDP methods failed"));
+                    diagnostics.errors.push(format!("Broadcast failed: {:#}", e2));
+                    // 如果两种方法都失败且未启用子网扫描，返回原始错误
+                    if !options.subnet_sweep || *cancel.borrow() {
+                        return Err(e.context("Both multicast and broadcast SSDP methods failed"));
+                    }
                 }
             }
         }
     }
 
-    Ok(devices)
+    // 方法3 (可选): 组播、广播被过滤时逐个主机单播探测
+    if options.subnet_sweep && devices.is_empty() && !*cancel.borrow() {
+        println!("SSDP: No devices found, sweeping local subnets...");
+        diagnostics.used_sweep = true;
+        sweep_subnets(options, &mut devices, &mut diagnostics, on_found, cancel)
+            .await
+            .context("Subnet sweep failed")?;
+        println!("SSDP: Sweep finished, found {} devices", devices.len());
+    }
+
+    diagnostics.cancelled = *cancel.borrow();
+    Ok(ScanReport {
+        devices,
+        diagnostics,
+    })
 }
 
-// 尝试使用组播发送 SSDP
-async fn try_multicast_ssdp(search_request: &str, timeout_secs: u64) -> Result<Vec<ProjectorInfo>> {
-    const SSDP_ADDR: Ipv4Addr = Ipv4Addr::new(239, 255, 255, 250);
-    const SSDP_PORT: u16 = 1900;
-    
-    let socket = create_ssdp_socket()?;
-    
-    // 发送搜索请求到组播地址
+// 构造 M-SEARCH 请求，host 形如 239.255.255.250:1900 或 [ff02::c]:1900
+fn build_search_request(host: &str, search_target: &str, mx: u32) -> String {
+    format!(
+        "M-SEARCH * HTTP/1.1\r\n\
+         HOST: {}\r\n\
+         MAN: \"ssdp:discover\"\r\n\
+         MX: {}\r\n\
+         ST: {}\r\n\
+         \r\n",
+        host, mx, search_target
+    )
+}
+
+// 判断响应的 ST/NT 是否属于请求的搜索目标 (ssdp:all 匹配一切)
+pub(crate) fn matches_search_target(targets: &[&str], target: &str) -> bool {
+    targets
+        .iter()
+        .any(|t| *t == "ssdp:all" || t.eq_ignore_ascii_case(target.trim()))
+}
+
+// 尝试使用组播发送 SSDP (IPv4，启用时同时走 IPv6)
+async fn try_multicast_ssdp(
+    search_requests: &[String],
+    options: &ScanOptions,
+    devices: &mut Vec<ProjectorInfo>,
+    diagnostics: &mut ScanDiagnostics,
+    on_found: &OnDeviceFound<'_>,
+    cancel: &watch::Receiver<bool>,
+) -> Result<()> {
+    let v4 = open_multicast_v4(options, diagnostics).and_then(|(socket, interfaces)| {
+        send_multicast_v4(&socket, &interfaces, search_requests)?;
+        Ok((into_tokio_socket(socket)?, interfaces))
+    });
+
+    let v6 = if options.enable_ipv6 {
+        match open_multicast_v6(options).and_then(|(socket, interfaces)| {
+            send_multicast_v6(&socket, &interfaces, options)?;
+            Ok((into_tokio_socket(socket)?, interfaces))
+        }) {
+            Ok(v6) => Some(v6),
+            Err(e) => {
+                println!("SSDP: IPv6 multicast unavailable: {}", e);
+                diagnostics.errors.push(format!("IPv6 multicast unavailable: {:#}", e));
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // 纯 IPv6 网络上 IPv4 组播会失败，只要 IPv6 可用就继续
+    let v4 = match v4 {
+        Ok(v4) => Some(v4),
+        Err(e) if v6.is_none() => return Err(e),
+        Err(e) => {
+            println!("SSDP: IPv4 multicast failed: {}, continuing with IPv6 only", e);
+            diagnostics.errors.push(format!("IPv4 multicast failed: {:#}", e));
+            None
+        }
+    };
+
+    let sockets: Vec<&tokio::net::UdpSocket> =
+        v4.iter().map(|(socket, _)| socket).chain(v6.iter().map(|(socket, _)| socket)).collect();
+    let resend = || {
+        if let Some((socket, interfaces)) = &v4 {
+            let _ = send_multicast_v4(&SockRef::from(socket), interfaces, search_requests);
+        }
+        if let Some((socket, interfaces)) = &v6 {
+            let _ = send_multicast_v6(&SockRef::from(socket), interfaces, options);
+        }
+    };
+
+    tokio::select! {
+        result = collect_ssdp_responses(&sockets, options, devices, on_found, cancel) => result,
+        never = retransmit(options, resend) => match never {},
+    }
+}
+
+// 按 send_count / send_interval_ms 重发 M-SEARCH (第一次已发送)，之后一直挂起直到收集结束
+// 返回 Infallible：永远不会完成，由类型保证 select! 中只有收集分支会结束
+async fn retransmit(options: &ScanOptions, resend: impl Fn()) -> Infallible {
+    for _ in 1..options.send_count.max(1) {
+        tokio::time::sleep(Duration::from_millis(options.send_interval_ms)).await;
+        println!("SSDP: Retransmitting M-SEARCH");
+        resend();
+    }
+    std::future::pending().await
+}
+
+// 创建 IPv4 组播 socket，返回 socket 和发送用的接口地址
+fn open_multicast_v4(
+    options: &ScanOptions,
+    diagnostics: &mut ScanDiagnostics,
+) -> Result<(Socket, Vec<Ipv4Addr>)> {
+    let interfaces = multicast_interface_addrs(&options.interfaces)?;
+    diagnostics.multicast_interfaces = interfaces.iter().map(|ip| ip.to_string()).collect();
+    let socket = create_ssdp_socket(0, &interfaces)?;
+    socket.set_multicast_ttl_v4(options.ttl())?;
+    Ok((socket, interfaces))
+}
+
+// 通过 IPv4 组播发送 M-SEARCH，每个接口各发一次；响应统一回到同一个 socket
+fn send_multicast_v4(
+    socket: &Socket,
+    interfaces: &[Ipv4Addr],
+    search_requests: &[String],
+) -> Result<()> {
     let target_addr = SocketAddrV4::new(SSDP_ADDR, SSDP_PORT);
-    socket
-        .send_to(search_request.as_bytes(), &target_addr.into())
-        .context("Failed to send SSDP multicast request")?;
+    let send_interfaces = if interfaces.is_empty() {
+        &[Ipv4Addr::UNSPECIFIED][..]
+    } else {
+        interfaces
+    };
+
+    let mut sent = false;
+    let mut last_err = None;
+    for interface in send_interfaces {
+        if !interface.is_unspecified() {
+            if let Err(err) = socket.set_multicast_if_v4(interface) {
+                println!("SSDP: Failed to switch multicast interface to {}: {}", interface, err);
+                last_err = Some(err);
+                continue;
+            }
+        }
+        for search_request in search_requests {
+            match socket.send_to(search_request.as_bytes(), &target_addr.into()) {
+                Ok(_) => sent = true,
+                Err(err) => {
+                    println!("SSDP: Failed to send multicast request via {}: {}", interface, err);
+                    last_err = Some(err);
+                }
+            }
+        }
+    }
+
+    if !sent {
+        return Err(last_err
+            .unwrap_or_else(|| std::io::Error::other("No multicast interface available")))
+        .context("Failed to send SSDP multicast request");
+    }
     
     println!("SSDP: Multicast request sent successfully");
-    
-    collect_ssdp_responses(socket, timeout_secs).await
+
+    Ok(())
+}
+
+// 创建 IPv6 组播 socket，返回 socket 和发送用的接口索引
+fn open_multicast_v6(options: &ScanOptions) -> Result<(Socket, Vec<u32>)> {
+    let interfaces = select_ipv6_interfaces(&options.interfaces)?;
+    let socket = create_ssdp_socket_v6(0, &interfaces)?;
+    socket.set_multicast_hops_v6(options.ttl())?;
+    Ok((socket, interfaces))
+}
+
+// 通过 IPv6 组播 (FF02::C、FF05::C) 发送 M-SEARCH
+fn send_multicast_v6(socket: &Socket, interfaces: &[u32], options: &ScanOptions) -> Result<()> {
+    let mut sent = false;
+    let mut last_err = None;
+    for index in interfaces {
+        if let Err(err) = socket.set_multicast_if_v6(*index) {
+            println!("SSDP: Failed to switch IPv6 multicast interface to #{}: {}", index, err);
+            last_err = Some(err);
+            continue;
+        }
+        for group in SSDP_ADDRS_V6 {
+            // 链路本地组播必须带上接口索引作为 scope id
+            let target_addr = SocketAddrV6::new(group, SSDP_PORT, 0, *index);
+            let host = format!("[{}]:{}", group, SSDP_PORT);
+            for st in options.targets() {
+                let search_request = build_search_request(&host, st, options.mx());
+                match socket.send_to(search_request.as_bytes(), &target_addr.into()) {
+                    Ok(_) => sent = true,
+                    Err(err) => {
+                        println!(
+                            "SSDP: Failed to send IPv6 request to {} via #{}: {}",
+                            group, index, err
+                        );
+                        last_err = Some(err);
+                    }
+                }
+            }
+        }
+    }
+
+    if !sent {
+        return Err(last_err
+            .unwrap_or_else(|| std::io::Error::other("No IPv6 multicast interface available")))
+        .context("Failed to send SSDP IPv6 multicast request");
+    }
+
+    println!("SSDP: IPv6 multicast request sent successfully");
+
+    Ok(())
 }
 
 // 尝试使用广播发送 SSDP（iOS 不需要特殊权限）
-async fn try_broadcast_ssdp(search_request: &str, timeout_secs: u64) -> Result<Vec<ProjectorInfo>> {
-    use std::net::UdpSocket;
-    
-    const SSDP_PORT: u16 = 1900;
-    
+async fn try_broadcast_ssdp(
+    search_requests: &[String],
+    options: &ScanOptions,
+    devices: &mut Vec<ProjectorInfo>,
+    diagnostics: &mut ScanDiagnostics,
+    on_found: &OnDeviceFound<'_>,
+    cancel: &watch::Receiver<bool>,
+) -> Result<()> {
     // 创建 UDP socket
-    let socket = UdpSocket::bind("0.0.0.0:0")?;
+    let socket = tokio::net::UdpSocket::bind("0.0.0.0:0")
+        .await
+        .context("Failed to bind SSDP broadcast socket")?;
     socket.set_broadcast(true)?;
-    socket.set_read_timeout(Some(Duration::from_secs(timeout_secs)))?;
-    
-    let mut broadcast_targets = Vec::new();
-    if let Some(local_ip) = get_local_ip() {
-        // 计算广播地址 (假设 /24 子网)
-        let octets = local_ip.octets();
-        let subnet_broadcast = Ipv4Addr::new(octets[0], octets[1], octets[2], 255);
-        println!("SSDP: Using subnet broadcast address: {}", subnet_broadcast);
-        broadcast_targets.push(subnet_broadcast);
-    } else {
-        println!(
-            "SSDP: No explicit LAN IP detected, falling back to limited broadcast only"
-        );
-    }
 
-    let limited_broadcast = Ipv4Addr::new(255, 255, 255, 255);
-    if !broadcast_targets.contains(&limited_broadcast) {
-        broadcast_targets.push(limited_broadcast);
+    let broadcast_targets = broadcast_targets(&options.interfaces);
+    diagnostics.broadcast_targets = broadcast_targets.iter().map(|ip| ip.to_string()).collect();
+    send_broadcast(&socket, &broadcast_targets, search_requests)?;
+
+    // 收集响应，期间按设定重发
+    let sockets = [&socket];
+    let resend = || {
+        let _ = send_broadcast(&socket, &broadcast_targets, search_requests);
+    };
+    tokio::select! {
+        result = collect_ssdp_responses(&sockets, options, devices, on_found, cancel) => result,
+        never = retransmit(options, resend) => match never {},
     }
+}
 
+// 向每个广播地址发送 M-SEARCH (UDP 发送不会长时间阻塞，直接使用非阻塞发送)
+fn send_broadcast(
+    socket: &tokio::net::UdpSocket,
+    broadcast_targets: &[Ipv4Addr],
+    search_requests: &[String],
+) -> Result<()> {
     let mut sent = false;
     let mut last_err = None;
     for broadcast_ip in broadcast_targets {
-        let target_addr = SocketAddrV4::new(broadcast_ip, SSDP_PORT);
-        match socket.send_to(search_request.as_bytes(), target_addr) {
-            Ok(_) => {
-                println!("SSDP: Broadcast request sent to {}", broadcast_ip);
-                sent = true;
-            }
-            Err(err) => {
-                println!(
-                    "SSDP: Failed to send broadcast request to {}: {}",
-                    broadcast_ip, err
-                );
-                last_err = Some(err);
+        let target_addr = SocketAddrV4::new(*broadcast_ip, SSDP_PORT);
+        for search_request in search_requests {
+            match socket.try_send_to(search_request.as_bytes(), target_addr.into()) {
+                Ok(_) => {
+                    println!("SSDP: Broadcast request sent to {}", broadcast_ip);
+                    sent = true;
+                }
+                Err(err) => {
+                    println!(
+                        "SSDP: Failed to send broadcast request to {}: {}",
+                        broadcast_ip, err
+                    );
+                    last_err = Some(err);
+                }
             }
         }
     }
@@ -355,124 +926,254 @@ async fn try_broadcast_ssdp(search_request: &str, timeout_secs: u64) -> Result<V
             .unwrap_or_else(|| std::io::Error::other("No SSDP broadcast target available")))
         .context("Failed to send SSDP broadcast request");
     }
-    
-    // 收集响应
-    let mut devices = Vec::new();
-    let mut buffer = [0u8; 2048];
-    let start_time = std::time::Instant::now();
-    
-    socket.set_nonblocking(true)?;
-    
-    while start_time.elapsed() < Duration::from_secs(timeout_secs) {
-        match socket.recv_from(&mut buffer) {
-            Ok((size, _addr)) => {
-                if let Ok(response) = String::from_utf8(buffer[..size].to_vec()) {
-                    if let Some(location) = extract_location(&response) {
-                        if let Ok(info) = parse_device_xml(&location).await {
-                            if !devices.iter().any(|d: &ProjectorInfo| d.ip == info.ip) {
-                                devices.push(info);
-                            }
-                        }
+    Ok(())
+}
+
+// 计算广播目标：每个接口按实际掩码得到定向广播地址，最后加上受限广播 255.255.255.255
+fn broadcast_targets(selected: &[String]) -> Vec<Ipv4Addr> {
+    let mut targets = Vec::new();
+    match select_scan_interfaces(selected) {
+        Ok(interfaces) => {
+            for iface in interfaces {
+                if let Some(subnet_broadcast) = directed_broadcast(iface.ip, iface.prefix_len) {
+                    println!(
+                        "SSDP: Using subnet broadcast address {} for {} ({}/{})",
+                        subnet_broadcast, iface.name, iface.ip, iface.prefix_len
+                    );
+                    if !targets.contains(&subnet_broadcast) {
+                        targets.push(subnet_broadcast);
                     }
                 }
             }
-            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
-                std::thread::sleep(Duration::from_millis(100));
-            }
-            Err(_) => break,
+        }
+        Err(e) => {
+            println!(
+                "SSDP: Interface enumeration failed: {}, falling back to limited broadcast only",
+                e
+            );
         }
     }
-    
-    Ok(devices)
+
+    let limited_broadcast = Ipv4Addr::BROADCAST;
+    if !targets.contains(&limited_broadcast) {
+        targets.push(limited_broadcast);
+    }
+    targets
 }
 
-// 收集 SSDP 响应
-async fn collect_ssdp_responses(socket: Socket, timeout_secs: u64) -> Result<Vec<ProjectorInfo>> {
-    socket.set_read_timeout(Some(Duration::from_secs(timeout_secs)))?;
-    
-    let mut devices = Vec::new();
-    let mut buffer: Vec<MaybeUninit<u8>> = vec![MaybeUninit::uninit(); 2048];
-    let start_time = std::time::Instant::now();
-
-    while start_time.elapsed() < Duration::from_secs(timeout_secs) {
-        match socket.recv_from(&mut buffer) {
-            Ok((size, _addr)) => {
-                let data: Vec<u8> = buffer[..size]
-                    .iter()
-                    .map(|b| unsafe { b.assume_init() })
-                    .collect();
-
-                if let Ok(response) = String::from_utf8(data) {
-                    if let Some(location) = extract_location(&response) {
-                        if let Ok(info) = parse_device_xml(&location).await {
-                            if !devices.iter().any(|d: &ProjectorInfo| d.ip == info.ip) {
-                                devices.push(info);
-                            }
-                        }
+// 将超时秒数转换为可安全加到 Instant 上的时长
+fn timeout_duration(timeout_secs: u64) -> Duration {
+    Duration::from_secs(timeout_secs).min(MAX_TIMEOUT)
+}
+
+// 收集 SSDP 响应 (同时轮询 IPv4 与 IPv6 socket，直到超时)
+async fn collect_ssdp_responses(
+    sockets: &[&tokio::net::UdpSocket],
+    options: &ScanOptions,
+    devices: &mut Vec<ProjectorInfo>,
+    on_found: &OnDeviceFound<'_>,
+    cancel: &watch::Receiver<bool>,
+) -> Result<()> {
+    let targets = options.targets();
+    let mut cancel = cancel.clone();
+    let deadline = tokio::time::Instant::now() + timeout_duration(options.timeout_secs);
+    let mut datagrams = receive_datagrams(sockets);
+
+    // 同一设备会对每个 ST 各回复一次，已请求过的 LOCATION (含之前阶段已发现的设备) 不再重复下载
+    let mut requested: HashSet<String> =
+        devices.iter().map(|d| d.location_xml_url.clone()).collect();
+    let mut queued: VecDeque<(String, SsdpMessage)> = VecDeque::new();
+    let mut fetches = FuturesUnordered::new();
+    let mut receiving = true;
+
+    loop {
+        // 在并发上限内派发排队中的描述文件请求
+        while fetches.len() < MAX_CONCURRENT_FETCHES {
+            let Some((location, message)) = queued.pop_front() else {
+                break;
+            };
+            fetches.push(async move {
+                let result = parse_device_xml(&location).await.map(|mut info| {
+                    fill_missing_udn(&mut info, message.usn.as_deref());
+                    info.ssdp = Some(message);
+                    info
+                });
+                (location, result)
+            });
+        }
+        // 截止时间后不再接收新响应，进行中的请求最多再等待一个宽限期
+        if !receiving && fetches.is_empty() {
+            break;
+        }
+
+        // 取消时直接返回，未完成的描述文件请求随 fetches 一起被丢弃 (中止)
+        tokio::select! {
+            _ = wait_cancelled(&mut cancel) => {
+                println!("SSDP: Scan cancelled, {} in-flight requests aborted", fetches.len());
+                break;
+            }
+            received = tokio::time::timeout_at(deadline, datagrams.next()), if receiving => {
+                let Ok(Some(received)) = received else {
+                    receiving = false;
+                    if !queued.is_empty() {
+                        println!("SSDP: Deadline reached, {} queued requests dropped", queued.len());
+                        queued.clear();
+                    }
+                    continue;
+                };
+                let (data, source) = received.context("SSDP receive error")?;
+                let Some(message) = std::str::from_utf8(&data).ok().and_then(parse_ssdp_message) else {
+                    continue;
+                };
+                remember_link_local_scope(source, message.location.as_deref());
+                // 只处理 ST 属于请求的搜索目标的响应 (缺少 ST 的响应不做过滤)
+                if message
+                    .search_target
+                    .as_deref()
+                    .is_some_and(|st| !matches_search_target(&targets, st))
+                {
+                    continue;
+                }
+                if let Some(location) = message.location.clone() {
+                    if requested.insert(location.clone()) {
+                        queued.push_back((location, message));
                     }
                 }
             }
-            Err(e)
-                if e.kind() == std::io::ErrorKind::WouldBlock
-                    || e.kind() == std::io::ErrorKind::TimedOut =>
-            {
+            _ = tokio::time::sleep_until(deadline + FETCH_GRACE_PERIOD), if !receiving => {
+                println!("SSDP: Grace period over, {} in-flight requests aborted", fetches.len());
                 break;
             }
-            Err(e) => {
-                return Err(anyhow::anyhow!("SSDP receive error: {}", e));
+            Some((location, result)) = fetches.next(), if !fetches.is_empty() => {
+                match result {
+                    Ok(info) if !options.accepts(&info) => {
+                        println!("SSDP: Skipping {} ({:?})", info.friendly_name, info.kind);
+                    }
+                    Ok(info) => record_device(devices, info, on_found),
+                    Err(e) => println!("SSDP: Failed to fetch {}: {}", location, e),
+                }
             }
         }
     }
 
-    Ok(devices)
+    Ok(())
+}
+
+// 将多个 socket 的接收合并为一个数据报流 (保留源地址，链路本地 IPv6 需要其中的 scope id)
+fn receive_datagrams<'a>(
+    sockets: &[&'a tokio::net::UdpSocket],
+) -> impl Stream<Item = std::io::Result<(Vec<u8>, SocketAddr)>> + Unpin + 'a {
+    futures::stream::select_all(sockets.iter().map(|&socket| {
+        Box::pin(futures::stream::unfold(socket, |socket| async move {
+            let mut buffer = vec![0u8; 2048];
+            let received = socket.recv_from(&mut buffer).await.map(|(size, source)| {
+                buffer.truncate(size);
+                (buffer, source)
+            });
+            Some((received, socket))
+        }))
+    }))
+}
+
+// 将 socket2 创建的 socket 注册到 tokio (socket 已是非阻塞模式)
+pub(crate) fn into_tokio_socket(socket: Socket) -> Result<tokio::net::UdpSocket> {
+    tokio::net::UdpSocket::from_std(socket.into())
+        .context("Failed to register SSDP socket with tokio")
+}
+
+// 记录新设备 (按 UDN 去重，同一 IP 上的多个设备分别保留)，只有首次出现的设备才会触发回调
+pub(crate) fn record_device(devices: &mut Vec<ProjectorInfo>, info: ProjectorInfo, on_found: &OnDeviceFound<'_>) {
+    if !devices.iter().any(|d| d.udn == info.udn) {
+        on_found(&info);
+        devices.push(info);
+    }
+}
+
+// 确定加入多播组的接口地址；接口枚举失败时回退到 get_local_ip 推测的单个地址
+pub(crate) fn multicast_interface_addrs(selected: &[String]) -> Result<Vec<Ipv4Addr>> {
+    match select_scan_interfaces(selected) {
+        Ok(interfaces) => {
+            for iface in &interfaces {
+                println!("SSDP: Using interface {} ({})", iface.name, iface.ip);
+            }
+            Ok(interfaces.into_iter().map(|iface| iface.ip).collect())
+        }
+        Err(e) if selected.is_empty() => {
+            println!(
+                "SSDP: Interface enumeration failed: {}, falling back to detected local IP",
+                e
+            );
+            let detected_local_ip = get_local_ip();
+            println!("SSDP: Detected local IP: {:?}", detected_local_ip);
+            Ok(detected_local_ip.into_iter().collect())
+        }
+        Err(e) => Err(e),
+    }
 }
 
-// 创建 SSDP socket
-fn create_ssdp_socket() -> Result<Socket> {
+// 创建 SSDP socket，bind_port 为 0 时绑定随机端口 (M-SEARCH)，为 1900 时用于监听 NOTIFY
+// interfaces 为要加入多播组的接口地址，为空时使用系统默认接口
+pub(crate) fn create_ssdp_socket(bind_port: u16, interfaces: &[Ipv4Addr]) -> Result<Socket> {
     let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))
         .context("Failed to create socket")?;
 
     // 设置 socket 选项
     socket.set_reuse_address(true)?;
+    // 1900 端口通常已被系统或其他 DLNA 应用占用，需要端口复用
+    #[cfg(unix)]
+    if bind_port != 0 {
+        socket.set_reuse_port(true)?;
+    }
 
-    // 绑定到任意端口
-    let bind_addr = SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0);
-    socket.bind(&bind_addr.into())?;
+    let bind_addr = SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, bind_port);
+    socket
+        .bind(&bind_addr.into())
+        .with_context(|| format!("Failed to bind SSDP socket to port {}", bind_port))?;
 
     // iOS 特定：设置多播选项
-    const SSDP_ADDR: Ipv4Addr = Ipv4Addr::new(239, 255, 255, 250);
 
-    // 获取本地 IP 地址用于加入多播组
-    let detected_local_ip = get_local_ip();
-    println!("SSDP: Detected local IP: {:?}", detected_local_ip);
-
-    let multicast_if = detected_local_ip.unwrap_or(Ipv4Addr::UNSPECIFIED);
-    if detected_local_ip.is_none() {
-        println!("SSDP: Falling back to default multicast interface");
+    // 加入多播组（iOS 需要，即使只是发送），每个接口单独加入
+    let mut joined = Vec::new();
+    let mut last_err = None;
+    for interface in interfaces {
+        println!(
+            "SSDP: Joining multicast group {} on interface {}",
+            SSDP_ADDR, interface
+        );
+        match socket.join_multicast_v4(&SSDP_ADDR, interface) {
+            Ok(()) => joined.push(*interface),
+            Err(err) => {
+                println!(
+                    "SSDP: Warning - Failed to join multicast group on {}: {}",
+                    interface, err
+                );
+                last_err = Some(err);
+            }
+        }
     }
 
-    // 加入多播组（iOS 需要，即使只是发送）
-    println!(
-        "SSDP: Joining multicast group {} on interface {}",
-        SSDP_ADDR, multicast_if
-    );
-    if let Err(err) = socket.join_multicast_v4(&SSDP_ADDR, &multicast_if) {
-        if detected_local_ip.is_some() {
+    if interfaces.is_empty() {
+        println!("SSDP: Falling back to default multicast interface");
+        if let Err(err) = socket.join_multicast_v4(&SSDP_ADDR, &Ipv4Addr::UNSPECIFIED) {
+            println!(
+                "SSDP: Warning - Failed to join multicast group on default interface: {}",
+                err
+            );
+        }
+    } else if joined.is_empty() {
+        if let Some(err) = last_err {
             return Err(err).context("Failed to join SSDP multicast group");
         }
-        println!(
-            "SSDP: Warning - Failed to join multicast group on default interface: {}",
-            err
-        );
     }
 
     // 设置多播 TTL
     socket.set_multicast_ttl_v4(2)?;
 
-    // 设置多播接口（iOS 需要明确指定）
+    // 设置多播接口（iOS 需要明确指定），多接口发送时由调用方逐个切换
+    let multicast_if = joined.first().copied().unwrap_or(Ipv4Addr::UNSPECIFIED);
     println!("SSDP: Setting multicast interface to {}", multicast_if);
     if let Err(err) = socket.set_multicast_if_v4(&multicast_if) {
-        if detected_local_ip.is_some() {
+        if !joined.is_empty() {
             return Err(err).context("Failed to set SSDP multicast interface");
         }
         println!(
@@ -490,28 +1191,58 @@ fn create_ssdp_socket() -> Result<Socket> {
     Ok(socket)
 }
 
-// 获取本地 IP 地址（过滤 VPN/代理接口）
-fn get_local_ip() -> Option<Ipv4Addr> {
-    use std::net::UdpSocket;
+// 创建 IPv6 SSDP socket，在每个接口上加入 FF02::C 和 FF05::C
+pub(crate) fn create_ssdp_socket_v6(bind_port: u16, interfaces: &[u32]) -> Result<Socket> {
+    let socket = Socket::new(Domain::IPV6, Type::DGRAM, Some(Protocol::UDP))
+        .context("Failed to create IPv6 socket")?;
 
-    // 判断是否是 VPN/代理软件使用的虚拟 IP 地址
-    fn is_vpn_ip(ip: &Ipv4Addr) -> bool {
-        let octets = ip.octets();
-        // 198.18.0.0/15 - 通常被 Surge、Shadowrocket 等代理软件使用
-        if octets[0] == 198 && (octets[1] == 18 || octets[1] == 19) {
-            return true;
-        }
-        // 100.64.0.0/10 - CGNAT，有时也被 VPN 使用
-        if octets[0] == 100 && octets[1] >= 64 && octets[1] <= 127 {
-            return true;
+    socket.set_only_v6(true)?;
+    socket.set_reuse_address(true)?;
+    #[cfg(unix)]
+    if bind_port != 0 {
+        socket.set_reuse_port(true)?;
+    }
+
+    let bind_addr = SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, bind_port, 0, 0);
+    socket
+        .bind(&bind_addr.into())
+        .with_context(|| format!("Failed to bind IPv6 SSDP socket to port {}", bind_port))?;
+
+    // 接口索引 0 表示系统默认接口
+    let indexes = if interfaces.is_empty() { &[0][..] } else { interfaces };
+    let mut joined = false;
+    let mut last_err = None;
+    for index in indexes {
+        for group in SSDP_ADDRS_V6 {
+            match socket.join_multicast_v6(&group, *index) {
+                Ok(()) => joined = true,
+                Err(err) => {
+                    println!(
+                        "SSDP: Warning - Failed to join {} on interface #{}: {}",
+                        group, index, err
+                    );
+                    last_err = Some(err);
+                }
+            }
         }
-        // 10.8.0.0/24, 10.9.0.0/24 - 常见 OpenVPN 地址段
-        if octets[0] == 10 && (octets[1] == 8 || octets[1] == 9) && octets[2] == 0 {
-            return true;
+    }
+    if !joined {
+        if let Some(err) = last_err {
+            return Err(err).context("Failed to join IPv6 SSDP multicast group");
         }
-        false
     }
 
+    socket.set_multicast_hops_v6(2)?;
+    socket.set_multicast_loop_v6(true)?;
+    socket.set_nonblocking(true)?;
+
+    Ok(socket)
+}
+
+// 获取本地 IP 地址（过滤 VPN/代理接口）
+fn get_local_ip() -> Option<Ipv4Addr> {
+    use std::net::UdpSocket;
+
     // 判断是否是有效的局域网 IP
     fn is_valid_lan_ip(ip: &Ipv4Addr) -> bool {
         if ip.is_loopback() || ip.is_unspecified() || ip.is_link_local() {
@@ -545,13 +1276,11 @@ fn get_local_ip() -> Option<Ipv4Addr> {
     for gateway in &gateway_addrs {
         if let Ok(socket) = UdpSocket::bind("0.0.0.0:0") {
             if socket.connect(*gateway).is_ok() {
-                if let Ok(addr) = socket.local_addr() {
-                    if let std::net::SocketAddr::V4(addr_v4) = addr {
-                        let ip = *addr_v4.ip();
-                        if is_valid_lan_ip(&ip) {
-                            println!("SSDP: Found valid LAN IP via gateway {}: {}", gateway, ip);
-                            return Some(ip);
-                        }
+                if let Ok(std::net::SocketAddr::V4(addr_v4)) = socket.local_addr() {
+                    let ip = *addr_v4.ip();
+                    if is_valid_lan_ip(&ip) {
+                        println!("SSDP: Found valid LAN IP via gateway {}: {}", gateway, ip);
+                        return Some(ip);
                     }
                 }
             }
@@ -561,15 +1290,13 @@ fn get_local_ip() -> Option<Ipv4Addr> {
     // 方法2: 回退到原始方法，但验证结果
     if let Ok(socket) = UdpSocket::bind("0.0.0.0:0") {
         if socket.connect("8.8.8.8:80").is_ok() {
-            if let Ok(addr) = socket.local_addr() {
-                if let std::net::SocketAddr::V4(addr_v4) = addr {
-                    let ip = *addr_v4.ip();
-                    if is_valid_lan_ip(&ip) {
-                        println!("SSDP: Found valid LAN IP via external connect: {}", ip);
-                        return Some(ip);
-                    } else {
-                        println!("SSDP: Detected IP {} appears to be VPN/proxy interface, skipping", ip);
-                    }
+            if let Ok(std::net::SocketAddr::V4(addr_v4)) = socket.local_addr() {
+                let ip = *addr_v4.ip();
+                if is_valid_lan_ip(&ip) {
+                    println!("SSDP: Found valid LAN IP via external connect: {}", ip);
+                    return Some(ip);
+                } else {
+                    println!("SSDP: Detected IP {} appears to be VPN/proxy interface, skipping", ip);
                 }
             }
         }
@@ -583,13 +1310,11 @@ fn get_local_ip() -> Option<Ipv4Addr> {
             let broadcast_addrs = ["192.168.1.255:1900", "192.168.0.255:1900", "10.0.0.255:1900"];
             for addr in &broadcast_addrs {
                 if socket.connect(*addr).is_ok() {
-                    if let Ok(local) = socket.local_addr() {
-                        if let std::net::SocketAddr::V4(addr_v4) = local {
-                            let ip = *addr_v4.ip();
-                            if is_valid_lan_ip(&ip) {
-                                println!("SSDP: Found valid LAN IP via broadcast probe: {}", ip);
-                                return Some(ip);
-                            }
+                    if let Ok(std::net::SocketAddr::V4(addr_v4)) = socket.local_addr() {
+                        let ip = *addr_v4.ip();
+                        if is_valid_lan_ip(&ip) {
+                            println!("SSDP: Found valid LAN IP via broadcast probe: {}", ip);
+                            return Some(ip);
                         }
                     }
                 }
@@ -601,79 +1326,268 @@ fn get_local_ip() -> Option<Ipv4Addr> {
     None
 }
 
-// 从 HTTP 响应中提取 LOCATION 头
-fn extract_location(response: &str) -> Option<String> {
-    for line in response.lines() {
-        if line.to_lowercase().starts_with("location:") {
-            return line
-                .split(':')
-                .skip(1)
-                .collect::<Vec<_>>()
-                .join(":")
-                .trim()
-                .to_string()
-                .into();
-        }
-    }
-    None
-}
-
 // 辅助：获取并解析设备描述 XML
-async fn parse_device_xml(url: &str) -> Result<ProjectorInfo> {
-    let client = Client::new();
-    let xml_content = client
-        .get(url)
+pub(crate) async fn parse_device_xml(url: &str) -> Result<ProjectorInfo> {
+    let xml_content = http_request(Method::GET, url)?
         .timeout(Duration::from_secs(2))
         .send()
         .await?
         .text()
         .await?;
 
-    // 这里使用简单的正则提取，生产环境建议用 xml-rs 做严谨解析
-    let name_regex = regex::Regex::new(r"<friendlyName>(.*?)</friendlyName>").unwrap();
-    let av_regex = regex::Regex::new(r"<serviceType>urn:schemas-upnp-org:service:AVTransport:1</serviceType>[\s\S]*?<controlURL>(.*?)</controlURL>").unwrap();
-    let rc_regex = regex::Regex::new(r"<serviceType>urn:schemas-upnp-org:service:RenderingControl:1</serviceType>[\s\S]*?<controlURL>(.*?)</controlURL>").unwrap();
+    let mut info = projector_info_from_description(parse_description(&xml_content, url)?, url);
+    // 刚请求过描述文件，同网段设备的地址此时一定在邻居表中
+    if let Some(mac_address) = lookup_neighbor_mac(&info.ip).await {
+        info.mac_address = Some(mac_address);
+    }
+    Ok(info)
+}
 
-    let friendly_name = name_regex
-        .captures(&xml_content)
-        .map(|c| c.get(1).unwrap().as_str().to_string())
-        .unwrap_or("未知设备".to_string());
+// 由解析后的描述文件构造设备信息 (不含 SSDP 消息和邻居表中的 MAC)
+pub(crate) fn projector_info_from_description(
+    description: DeviceDescription,
+    url: &str,
+) -> ProjectorInfo {
+    let friendly_name = if description.friendly_name.is_empty() {
+        "未知设备".to_string()
+    } else {
+        description.friendly_name.clone()
+    };
 
-    // 解析控制 URL (通常是相对路径，需要拼接到 Base URL)
-    let base_url = url.rsplit_once('/').map(|(base, _)| base).unwrap_or(url);
+    // AVTransport、RenderingControl 可能位于嵌入的 MediaRenderer 设备中
+    let av_transport_url = description
+        .find_service(AV_SERVICE)
+        .map(|service| service.control_url.clone());
+    let rendering_control_url = description
+        .find_service(RC_SERVICE)
+        .map(|service| service.control_url.clone());
 
-    let resolve_url = |path: &str| -> String {
-        if path.starts_with("http") {
-            path.to_string()
-        } else {
-            format!("{}/{}", base_url, path.trim_start_matches('/'))
-        }
-    };
+    let kind = description.kind();
+    let capabilities =
+        description.capabilities(av_transport_url.as_deref(), rendering_control_url.as_deref());
 
-    let av_transport_url = av_regex
-        .captures(&xml_content)
-        .map(|c| resolve_url(c.get(1).unwrap().as_str()));
-    let rendering_control_url = rc_regex
-        .captures(&xml_content)
-        .map(|c| resolve_url(c.get(1).unwrap().as_str()));
-
-    // 简单从 URL 提取 IP
-    let ip = url
-        .split("://")
-        .nth(1)
-        .unwrap_or("")
-        .split(':')
-        .next()
-        .unwrap_or("")
-        .to_string();
-
-    Ok(ProjectorInfo {
+    ProjectorInfo {
         friendly_name,
-        ip,
+        ip: host_from_url(url),
         location_xml_url: url.to_string(),
+        udn: description.udn.clone(),
         av_transport_url,
         rendering_control_url,
-    })
+        mac_address: description.mac_address.clone(),
+        description,
+        kind,
+        capabilities,
+        ssdp: None,
+    }
+}
+
+// 构造 HTTP 请求。链路本地 IPv6 (fe80::/10) 必须指定接口才能连接，而 Url 不支持 %zone：
+// 主机名换成占位名并通过 resolve 指向带 scope id 的地址，Host 头保持原样
+pub(crate) fn http_request(method: Method, url: &str) -> Result<reqwest::RequestBuilder> {
+    let Some(ip) = url_ipv6(url).filter(is_link_local_v6) else {
+        return Ok(Client::new().request(method, url));
+    };
+    let scope_id = link_local_scope(&ip).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Cannot reach link-local address {} in {}: interface unknown (discover the device via SSDP or specify it as {}%<interface>)",
+            ip,
+            url,
+            ip
+        )
+    })?;
+
+    let mut scoped_url =
+        reqwest::Url::parse(url).with_context(|| format!("Invalid URL: {}", url))?;
+    let host = match scoped_url.port() {
+        Some(port) => format!("[{}]:{}", ip, port),
+        None => format!("[{}]", ip),
+    };
+    scoped_url
+        .set_host(Some(LINK_LOCAL_HOST))
+        .with_context(|| format!("Invalid URL: {}", url))?;
+    // resolve 忽略端口 (使用 URL 中的端口)，只取地址和 scope id
+    let client = Client::builder()
+        .resolve(LINK_LOCAL_HOST, SocketAddrV6::new(ip, 0, 0, scope_id).into())
+        .build()?;
+    Ok(client
+        .request(method, scoped_url)
+        .header(reqwest::header::HOST, host))
+}
+
+// USN 形如 uuid:xxx::urn:schemas-upnp-org:service:AVTransport:1，"::" 之前的部分即设备 UDN
+pub(crate) fn udn_from_usn(usn: &str) -> &str {
+    usn.split("::").next().unwrap_or(usn)
+}
+
+// 描述文件缺少 UDN 时，依次用 USN 中的 UDN、LOCATION 作为设备标识
+pub(crate) fn fill_missing_udn(info: &mut ProjectorInfo, usn: Option<&str>) {
+    if info.udn.is_empty() {
+        info.udn = usn
+            .map(udn_from_usn)
+            .filter(|udn| !udn.is_empty())
+            .unwrap_or(&info.location_xml_url)
+            .to_string();
+    }
+}
+
+// 从 URL 提取主机地址，IPv6 形如 http://[fe80::1]:49152/ 时去掉方括号
+pub(crate) fn host_from_url(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|u| {
+            u.host_str()
+                .map(|host| host.trim_start_matches('[').trim_end_matches(']').to_string())
+        })
+        .unwrap_or_default()
+}
+
+// --- 手动添加设备 (组播被屏蔽时的回退) ---
+
+/// 通过设备描述文件地址直接添加设备
+pub async fn add_device_by_location(url: String) -> Result<ProjectorInfo> {
+    let url = url.trim();
+    reqwest::Url::parse(url).with_context(|| format!("Invalid description URL: {}", url))?;
+    let mut info = parse_device_xml(url).await?;
+    fill_missing_udn(&mut info, None);
+    Ok(info)
+}
+
+/// 向 ip:1900 发送单播 M-SEARCH，并根据返回的 LOCATION 解析设备
+pub async fn probe_ip(ip: String, timeout_secs: u64) -> Result<ProjectorInfo> {
+    let target_ip = parse_ip(&ip)?;
+    let (location, message) = unicast_search(
+        target_ip,
+        DEFAULT_SEARCH_TARGET,
+        Duration::from_secs(timeout_secs),
+    )
+    .await?;
+
+    let mut info = parse_device_xml(&location).await?;
+    fill_missing_udn(&mut info, message.usn.as_deref());
+    info.ssdp = Some(message);
+    Ok(info)
+}
+
+// 解析 IP 地址，允许 IPv6 带方括号；链路本地 IPv6 可带接口 (fe80::1%en0 或 fe80::1%3)，
+// 接口会被记录下来供之后的 SSDP 与 HTTP 请求使用
+pub(crate) fn parse_ip(ip: &str) -> Result<std::net::IpAddr> {
+    let address = ip.trim().trim_start_matches('[').trim_end_matches(']');
+    if let Some((address, zone)) = address.split_once('%') {
+        let address: Ipv6Addr = address
+            .parse()
+            .with_context(|| format!("Invalid IP address: {}", ip))?;
+        set_link_local_scope(address, parse_zone(zone)?);
+        return Ok(address.into());
+    }
+    address
+        .parse()
+        .with_context(|| format!("Invalid IP address: {}", ip))
+}
+
+// 向 target_ip:1900 发送单播 M-SEARCH，返回该设备响应中的 LOCATION 和完整消息
+pub(crate) async fn unicast_search(
+    target_ip: std::net::IpAddr,
+    search_target: &str,
+    timeout: Duration,
+) -> Result<(String, SsdpMessage)> {
+    let target_addr = match target_ip {
+        std::net::IpAddr::V6(ip) if is_link_local_v6(&ip) => {
+            let scope_id = link_local_scope(&ip).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Link-local address {} needs an interface, e.g. {}%en0",
+                    ip,
+                    ip
+                )
+            })?;
+            SocketAddrV6::new(ip, SSDP_PORT, 0, scope_id).into()
+        }
+        _ => std::net::SocketAddr::new(target_ip, SSDP_PORT),
+    };
+
+    let bind_addr = if target_ip.is_ipv6() { "[::]:0" } else { "0.0.0.0:0" };
+    let socket = tokio::net::UdpSocket::bind(bind_addr)
+        .await
+        .context("Failed to create unicast SSDP socket")?;
+
+    // 单播 M-SEARCH 的 HOST 为目标地址本身 (不带 %zone)
+    let host = std::net::SocketAddr::new(target_ip, SSDP_PORT).to_string();
+    let search_request = build_search_request(&host, search_target, 1);
+    socket
+        .send_to(search_request.as_bytes(), target_addr)
+        .await
+        .context("Failed to send unicast SSDP request")?;
+    println!("SSDP: Unicast request sent to {}", target_addr);
+
+    let wait_for_location = async {
+        let mut buffer = [0u8; 2048];
+        loop {
+            let (size, addr) = socket.recv_from(&mut buffer).await?;
+            // 只接受目标设备自己的响应
+            if addr.ip() != target_ip {
+                continue;
+            }
+            let Some(message) = std::str::from_utf8(&buffer[..size])
+                .ok()
+                .and_then(parse_ssdp_message)
+            else {
+                continue;
+            };
+            remember_link_local_scope(addr, message.location.as_deref());
+            if let Some(location) = message.location.clone() {
+                return Ok::<_, std::io::Error>((location, message));
+            }
+        }
+    };
+
+    tokio::time::timeout(timeout, wait_for_location)
+        .await
+        .map_err(|_| anyhow::anyhow!("No SSDP response from {} within {:?}", target_ip, timeout))?
+        .context("SSDP receive error")
+}
+
+// --- 按 UDN 重新定位设备 (DHCP 分配新 IP 后恢复) ---
+
+/// 发送定向 M-SEARCH (ST: uuid:...) 查找指定设备，返回最新的地址和控制 URL
+pub async fn resolve_device(udn: String, timeout_secs: u64) -> Result<ProjectorInfo> {
+    let udn = udn.trim().to_string();
+    if !udn.to_ascii_lowercase().starts_with("uuid:") {
+        return Err(anyhow::anyhow!("Invalid UDN (expected uuid:...): {}", udn));
+    }
+
+    let options = ScanOptions {
+        search_targets: vec![udn.clone()],
+        ..ScanOptions::with_timeout(timeout_secs)
+    };
+    let report = discover_projectors(&options, &|_| {}, &never_cancelled()).await?;
+    report
+        .devices
+        .into_iter()
+        .find(|device| device.udn.eq_ignore_ascii_case(&udn))
+        .ok_or_else(|| anyhow::anyhow!("Device {} not found within {}s", udn, timeout_secs))
+}
+
+// --- 设备持久化 (JSON) ---
+
+/// 将设备列表序列化为 JSON，便于保存用户选择过的设备
+#[frb(sync)]
+pub fn devices_to_json(devices: Vec<ProjectorInfo>) -> Result<String> {
+    serde_json::to_string(&devices).context("Failed to serialize devices")
+}
+
+/// 从 devices_to_json 生成的 JSON 恢复设备列表 (兼容旧版本保存的 JSON)
+#[frb(sync)]
+pub fn devices_from_json(json: String) -> Result<Vec<ProjectorInfo>> {
+    let mut devices: Vec<ProjectorInfo> =
+        serde_json::from_str(&json).context("Failed to parse saved devices")?;
+    // 类别和能力完全由描述文件决定，重新推断 (旧版本 JSON 中没有这两个字段)
+    for device in &mut devices {
+        device.kind = device.description.kind();
+        device.capabilities = device.description.capabilities(
+            device.av_transport_url.as_deref(),
+            device.rendering_control_url.as_deref(),
+        );
+    }
+    Ok(devices)
 }
 
 // --- 播放状态枚举 ---
@@ -697,7 +1611,6 @@ async fn send_soap_action(
     action: &str,
     args: &str,
 ) -> Result<String> {
-    let client = Client::new();
     let body = format!(
         r#"<?xml version="1.0" encoding="utf-8"?>
         <s:Envelope s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/" xmlns:s="http://schemas.xmlsoap.org/soap/envelope/">
@@ -711,8 +1624,7 @@ async fn send_soap_action(
 
     let soap_action_header = format!("\"{}#{}\"", service_type, action);
 
-    let res = client
-        .post(control_url)
+    let res = http_request(Method::POST, control_url)?
         .header("Content-Type", "text/xml; charset=\"utf-8\"")
         .header("SOAPACTION", soap_action_header)
         .body(body)
@@ -728,25 +1640,360 @@ async fn send_soap_action(
 
 // --- 远程开机 (Wake on LAN) ---
 
+/// 远程开机参数
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct WakeOptions {
+    // 目标地址：广播地址 (如 192.168.1.255) 或设备的单播 IP，为空时发送到 255.255.255.255
+    pub target_address: Option<String>,
+    // 目标端口，通常为 9 (discard) 或 7 (echo)
+    pub port: u16,
+    // 同时发送到每个接口的定向广播地址 (多网卡主机、会丢弃 255.255.255.255 的路由器)
+    pub directed_broadcast: bool,
+    // 发送轮数 (UDP 可能丢包)，0 视为 1
+    pub repeat_count: u32,
+    // 两轮之间的间隔 (毫秒)
+    pub repeat_interval_ms: u64,
+    // SecureOn 密码：4 字节 (如 192.168.1.1 或 c0a80101) 或 6 字节 (如 01:02:03:04:05:06)
+    pub secure_on_password: Option<String>,
+}
+
+impl WakeOptions {
+    // 默认参数：向 255.255.255.255:9 发送一次
+    pub(crate) fn broadcast() -> Self {
+        Self {
+            target_address: None,
+            port: WOL_DEFAULT_PORT,
+            directed_broadcast: false,
+            repeat_count: 1,
+            repeat_interval_ms: 0,
+            secure_on_password: None,
+        }
+    }
+}
+
+/// 向 255.255.255.255:9 发送一次魔术包
 pub fn wake_on_lan(mac_address: String) -> Result<()> {
-    // 1. 处理 MAC 格式 (允许 AA:BB.. 或 AA-BB..)
-    let mac_clean = mac_address.replace([':', '-'], "");
-    let mac_bytes = hex::decode(&mac_clean).context("Invalid MAC address format")?;
+    let options = WakeOptions::broadcast();
+    let packet = build_magic_packet(&mac_address, None)?;
+    send_magic_packets(&packet, &wake_targets(&options)?)
+}
 
-    if mac_bytes.len() != 6 {
-        return Err(anyhow::anyhow!("MAC address must be 6 bytes"));
+/// 按 options 发送魔术包：可指定目标地址和端口、定向广播、重复次数及 SecureOn 密码
+pub async fn wake_on_lan_with_options(mac_address: String, options: WakeOptions) -> Result<()> {
+    let packet = build_magic_packet(&mac_address, options.secure_on_password.as_deref())?;
+    let targets = wake_targets(&options)?;
+
+    let rounds = options.repeat_count.max(1);
+    for round in 0..rounds {
+        if round > 0 {
+            tokio::time::sleep(Duration::from_millis(options.repeat_interval_ms)).await;
+        }
+        send_magic_packets(&packet, &targets)?;
     }
+    println!(
+        "WOL: Sent {} round(s) to {} target(s) for {}",
+        rounds,
+        targets.len(),
+        mac_address
+    );
+    Ok(())
+}
+
+// 魔术包：6 个 FF + 16 次 MAC，启用 SecureOn 时末尾追加密码
+fn build_magic_packet(mac_address: &str, secure_on_password: Option<&str>) -> Result<Vec<u8>> {
+    let mac_bytes = parse_mac(mac_address)?;
 
-    // 2. 构造魔术包 (6个FF + 16次MAC)
     let mut packet = vec![0xFF; 6];
     for _ in 0..16 {
         packet.extend_from_slice(&mac_bytes);
     }
+    if let Some(password) = secure_on_password {
+        packet.extend(parse_secure_on_password(password)?);
+    }
+    Ok(packet)
+}
 
-    // 3. 广播
-    let socket = std::net::UdpSocket::bind("0.0.0.0:0")?;
-    socket.set_broadcast(true)?;
-    socket.send_to(&packet, "255.255.255.255:9")?;
+// SecureOn 密码为 4 字节 (可写成 IPv4 形式) 或 6 字节 (同 MAC 格式)
+fn parse_secure_on_password(password: &str) -> Result<Vec<u8>> {
+    let password = password.trim();
+    if let Ok(ip) = password.parse::<Ipv4Addr>() {
+        return Ok(ip.octets().to_vec());
+    }
+    if let Ok(bytes) = parse_mac(password) {
+        return Ok(bytes.to_vec());
+    }
+    let bytes = hex::decode(password.replace([':', '-'], ""))
+        .context("Invalid SecureOn password format")?;
+    if bytes.len() != 4 {
+        return Err(anyhow::anyhow!("SecureOn password must be 4 or 6 bytes"));
+    }
+    Ok(bytes)
+}
 
-    Ok(())
+// 发送目标：(本地绑定地址, 目标地址)；定向广播从对应接口发出
+fn wake_targets(options: &WakeOptions) -> Result<Vec<(std::net::IpAddr, std::net::SocketAddr)>> {
+    let target = match &options.target_address {
+        Some(address) => parse_ip(address)?,
+        None => Ipv4Addr::BROADCAST.into(),
+    };
+    let bind = if target.is_ipv6() {
+        Ipv6Addr::UNSPECIFIED.into()
+    } else {
+        Ipv4Addr::UNSPECIFIED.into()
+    };
+    let mut targets = vec![(bind, std::net::SocketAddr::new(target, options.port))];
+
+    if options.directed_broadcast {
+        for iface in select_scan_interfaces(&[])? {
+            let Some(broadcast) = directed_broadcast(iface.ip, iface.prefix_len) else {
+                continue;
+            };
+            let address = std::net::SocketAddr::new(broadcast.into(), options.port);
+            if !targets.iter().any(|(_, existing)| *existing == address) {
+                targets.push((iface.ip.into(), address));
+            }
+        }
+    }
+    Ok(targets)
+}
+
+// 向每个目标发送一次，全部失败时返回第一个错误
+fn send_magic_packets(
+    packet: &[u8],
+    targets: &[(std::net::IpAddr, std::net::SocketAddr)],
+) -> Result<()> {
+    let mut first_error = None;
+    let mut sent = 0;
+    for (bind, target) in targets {
+        let result = std::net::UdpSocket::bind((*bind, 0)).and_then(|socket| {
+            socket.set_broadcast(true)?;
+            socket.send_to(packet, target)
+        });
+        match result {
+            Ok(_) => sent += 1,
+            Err(e) => {
+                println!("WOL: Failed to send to {}: {}", target, e);
+                first_error.get_or_insert(
+                    anyhow::Error::new(e).context(format!("Failed to send magic packet to {}", target)),
+                );
+            }
+        }
+    }
+    match first_error {
+        Some(e) if sent == 0 => Err(e),
+        _ => Ok(()),
+    }
+}
+
+// --- 唤醒并等待设备可控 ---
+
+/// 发送 Wake-on-LAN 并反复定向搜索，直到设备响应 SSDP 且 AVTransport 控制地址可用，返回最新的设备信息
+/// udn_or_ip 为设备 UDN (uuid:...) 或 IP；超时错误会说明停在哪个阶段
+pub async fn wake_and_wait(
+    mac_address: String,
+    udn_or_ip: String,
+    timeout_secs: u64,
+) -> Result<ProjectorInfo> {
+    let target = udn_or_ip.trim().to_string();
+    let is_udn = target.to_ascii_lowercase().starts_with("uuid:");
+    if !is_udn {
+        parse_ip(&target)?;
+    }
+    let wake_options = WakeOptions {
+        directed_broadcast: true,
+        repeat_count: 3,
+        repeat_interval_ms: 100,
+        ..WakeOptions::broadcast()
+    };
+    let deadline = Instant::now() + timeout_duration(timeout_secs);
+
+    // 阶段 1：等待设备响应 SSDP，每轮都重发魔术包 (网卡刚进入待机时可能错过)
+    let mut last_error = None;
+    let device = loop {
+        if Instant::now() >= deadline {
+            let stage = format!("waiting for {} to answer SSDP", target);
+            return Err(wake_timeout(timeout_secs, &stage, last_error));
+        }
+        wake_on_lan_with_options(mac_address.clone(), wake_options.clone())
+            .await
+            .context("Failed to send Wake-on-LAN packet")?;
+
+        let search_secs = deadline
+            .saturating_duration_since(Instant::now())
+            .as_secs()
+            .clamp(1, WAKE_SEARCH_TIMEOUT_SECS);
+        let found = if is_udn {
+            resolve_device(target.clone(), search_secs).await
+        } else {
+            probe_ip(target.clone(), search_secs).await
+        };
+        match found {
+            Ok(device) => break device,
+            Err(e) => last_error = Some(e),
+        }
+        tokio::time::sleep(WAKE_RETRY_INTERVAL.min(deadline.saturating_duration_since(Instant::now())))
+            .await;
+    };
+    println!("WOL: {} answered SSDP at {}", device.friendly_name, device.ip);
+
+    // 阶段 2：电视的渲染服务常在网络就绪后才启动，等待 AVTransport 能正常响应
+    if device.av_transport_url.is_none() {
+        return Err(anyhow::anyhow!(
+            "{} woke up but has no AVTransport service",
+            device.friendly_name
+        ));
+    }
+    let mut last_error = None;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            let stage = format!(
+                "waiting for the AVTransport control URL of {} to respond",
+                device.friendly_name
+            );
+            return Err(wake_timeout(timeout_secs, &stage, last_error));
+        }
+        match tokio::time::timeout(remaining.min(WAKE_CONTROL_TIMEOUT), device.get_transport_info()).await {
+            Ok(Ok(_)) => return Ok(device),
+            Ok(Err(e)) => last_error = Some(e),
+            Err(_) => last_error = Some(anyhow::anyhow!("GetTransportInfo timed out")),
+        }
+        tokio::time::sleep(WAKE_RETRY_INTERVAL.min(deadline.saturating_duration_since(Instant::now())))
+            .await;
+    }
+}
+
+fn wake_timeout(timeout_secs: u64, stage: &str, last_error: Option<anyhow::Error>) -> anyhow::Error {
+    match last_error {
+        Some(e) => anyhow::anyhow!("Timed out after {}s {}: {:#}", timeout_secs, stage, e),
+        None => anyhow::anyhow!("Timed out after {}s {}", timeout_secs, stage),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KODI: &str = include_str!("../../tests/fixtures/descriptions/kodi.xml");
+
+    fn kodi_device() -> ProjectorInfo {
+        let description = parse_description(KODI, "http://192.168.1.60:1151/").unwrap();
+        let av_transport_url = description
+            .find_service(AV_SERVICE)
+            .map(|service| service.control_url.clone());
+        let rendering_control_url = description
+            .find_service(RC_SERVICE)
+            .map(|service| service.control_url.clone());
+        ProjectorInfo {
+            friendly_name: description.friendly_name.clone(),
+            ip: "192.168.1.60".to_string(),
+            location_xml_url: "http://192.168.1.60:1151/".to_string(),
+            udn: description.udn.clone(),
+            kind: description.kind(),
+            capabilities: description
+                .capabilities(av_transport_url.as_deref(), rendering_control_url.as_deref()),
+            av_transport_url,
+            rendering_control_url,
+            description,
+            ssdp: parse_ssdp_message(
+                "HTTP/1.1 200 OK\r\nLOCATION: http://192.168.1.60:1151/\r\nBOOTID.UPNP.ORG: 7\r\n\r\n",
+            ),
+            mac_address: Some("a4:5e:60:c1:22:0f".to_string()),
+        }
+    }
+
+    #[test]
+    fn multicast_ttl_is_clamped() {
+        let mut options = ScanOptions::with_timeout(3);
+        assert_eq!(options.ttl(), DEFAULT_MULTICAST_TTL);
+        options.multicast_ttl = Some(0);
+        assert_eq!(options.ttl(), 1);
+        options.multicast_ttl = Some(1000);
+        assert_eq!(options.ttl(), 255);
+    }
+
+    #[test]
+    fn huge_timeouts_do_not_overflow_the_deadline() {
+        assert_eq!(timeout_duration(3), Duration::from_secs(3));
+        assert_eq!(timeout_duration(u64::MAX), MAX_TIMEOUT);
+        let _ = tokio::time::Instant::now() + timeout_duration(u64::MAX);
+    }
+
+    #[test]
+    fn devices_round_trip_through_json() {
+        let json = devices_to_json(vec![kodi_device()]).unwrap();
+        let devices = devices_from_json(json.clone()).unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].udn, kodi_device().udn);
+        assert_eq!(devices[0].ssdp.as_ref().unwrap().boot_id, Some(7));
+        assert_eq!(devices_to_json(devices).unwrap(), json);
+    }
+
+    #[test]
+    fn devices_from_json_accepts_the_original_format() {
+        // 最初的持久化格式没有 kind、capabilities、ssdp、mac_address 及 description.mac_address
+        let mut value = serde_json::to_value(vec![kodi_device()]).unwrap();
+        let device = value[0].as_object_mut().unwrap();
+        for field in ["kind", "capabilities", "ssdp", "mac_address"] {
+            device.remove(field);
+        }
+        device["description"]
+            .as_object_mut()
+            .unwrap()
+            .remove("mac_address");
+
+        let devices = devices_from_json(value.to_string()).unwrap();
+        assert_eq!(devices[0].kind, DeviceKind::MediaRenderer);
+        assert!(devices[0].capabilities.can_play);
+        assert!(devices[0].ssdp.is_none());
+        assert!(devices[0].mac_address.is_none());
+    }
+
+    #[test]
+    fn secure_on_password_is_4_or_6_bytes() {
+        assert_eq!(
+            parse_secure_on_password("192.168.1.1").unwrap(),
+            vec![192, 168, 1, 1]
+        );
+        assert_eq!(
+            parse_secure_on_password("c0a80101").unwrap(),
+            vec![0xc0, 0xa8, 0x01, 0x01]
+        );
+        assert_eq!(
+            parse_secure_on_password("01:02:03:04:05:06").unwrap(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(
+            parse_secure_on_password("010203040506").unwrap(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn secure_on_password_rejects_other_lengths() {
+        for password in ["", "c0a801", "c0a8010101", "01:02:03:04:05", "password"] {
+            assert!(parse_secure_on_password(password).is_err(), "{password}");
+        }
+    }
+
+    #[test]
+    fn magic_packet_layout() {
+        let mac = [0xaa, 0xbb, 0xcc, 0x11, 0x22, 0x33];
+        let packet = build_magic_packet("aabb.cc11.2233", None).unwrap();
+        assert_eq!(packet.len(), 102);
+        assert_eq!(packet[..6], [0xff; 6]);
+        assert!(packet[6..].chunks(6).all(|chunk| chunk == mac));
+
+        let packet = build_magic_packet("aa:bb:cc:11:22:33", Some("192.168.1.1")).unwrap();
+        assert_eq!(packet.len(), 106);
+        assert_eq!(packet[102..], [192, 168, 1, 1]);
+
+        let packet = build_magic_packet("aa-bb-cc-11-22-33", Some("01:02:03:04:05:06")).unwrap();
+        assert_eq!(packet.len(), 108);
+        assert_eq!(packet[102..], [1, 2, 3, 4, 5, 6]);
+
+        assert!(build_magic_packet("aa:bb-cc:11-22:33", None).is_err());
+        assert!(build_magic_packet("aa:bb:cc:11:22:33", Some("0102")).is_err());
+    }
 }
@@ -0,0 +1,534 @@
+use crate::api::network::normalize_mac;
+use anyhow::{Context, Result};
+use flutter_rust_bridge::frb;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use xml::reader::{EventReader, XmlEvent};
+
+// --- 数据模型 ---
+
+/// UPnP 设备描述文件 (description.xml) 中的设备信息
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceDescription {
+    pub device_type: String, // 如 urn:schemas-upnp-org:device:MediaRenderer:1
+    pub friendly_name: String,
+    pub manufacturer: String,
+    pub manufacturer_url: Option<String>,
+    pub model_description: Option<String>,
+    pub model_name: String,
+    pub model_number: Option<String>,
+    pub model_url: Option<String>,
+    pub serial_number: Option<String>,
+    pub udn: String,
+    pub presentation_url: Option<String>,
+    #[serde(default)]
+    pub mac_address: Option<String>, // 厂商扩展字段中的 MAC (如 <macAddress>、<wifiMac>)
+    pub icons: Vec<DeviceIcon>,
+    pub services: Vec<ServiceInfo>,
+    pub devices: Vec<DeviceDescription>, // 嵌入设备 (deviceList)
+}
+
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceIcon {
+    pub mime_type: String,
+    pub width: u32,
+    pub height: u32,
+    pub depth: u32,
+    pub url: String,
+}
+
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceInfo {
+    pub service_type: String, // 如 urn:schemas-upnp-org:service:AVTransport:1
+    pub service_id: String,
+    pub scpd_url: String,
+    pub control_url: String,
+    pub event_sub_url: String,
+}
+
+/// 按 deviceType 和服务列表推断的设备类别
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum DeviceKind {
+    MediaRenderer,         // 电视、投影仪、音箱等可投送设备
+    MediaServer,           // NAS、媒体库
+    InternetGatewayDevice, // 路由器
+    #[default]
+    Other,
+}
+
+/// 设备能力
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeviceCapabilities {
+    pub can_play: bool,           // 有 AVTransport 控制 URL
+    pub can_control_volume: bool, // 有 RenderingControl 控制 URL
+    pub can_subscribe: bool,      // AVTransport 或 RenderingControl 提供事件订阅 URL
+}
+
+const AV_TRANSPORT_PREFIX: &str = "urn:schemas-upnp-org:service:AVTransport:";
+const RENDERING_CONTROL_PREFIX: &str = "urn:schemas-upnp-org:service:RenderingControl:";
+const CONTENT_DIRECTORY_PREFIX: &str = "urn:schemas-upnp-org:service:ContentDirectory:";
+
+impl DeviceDescription {
+    // 在本设备及所有嵌入设备中查找指定类型的服务
+    pub(crate) fn find_service(&self, service_type: &str) -> Option<&ServiceInfo> {
+        self.services
+            .iter()
+            .find(|service| service.service_type == service_type)
+            .or_else(|| {
+                self.devices
+                    .iter()
+                    .find_map(|device| device.find_service(service_type))
+            })
+    }
+
+    // 推断设备类别：本设备或任一嵌入设备是渲染器 (或提供 AVTransport) 即视为渲染器，其次是媒体服务器、网关
+    pub(crate) fn kind(&self) -> DeviceKind {
+        let has_device_type =
+            |name: &str| self.all_device_types().iter().any(|t| device_type_is(t, name));
+        let has_service = |prefix: &str| {
+            self.all_services()
+                .iter()
+                .any(|service| service.service_type.starts_with(prefix))
+        };
+
+        if has_device_type("MediaRenderer") || has_service(AV_TRANSPORT_PREFIX) {
+            DeviceKind::MediaRenderer
+        } else if has_device_type("MediaServer") || has_service(CONTENT_DIRECTORY_PREFIX) {
+            DeviceKind::MediaServer
+        } else if has_device_type("InternetGatewayDevice") {
+            DeviceKind::InternetGatewayDevice
+        } else {
+            DeviceKind::Other
+        }
+    }
+
+    pub(crate) fn capabilities(
+        &self,
+        av_transport_url: Option<&str>,
+        rendering_control_url: Option<&str>,
+    ) -> DeviceCapabilities {
+        let can_subscribe = self.all_services().iter().any(|service| {
+            (service.service_type.starts_with(AV_TRANSPORT_PREFIX)
+                || service.service_type.starts_with(RENDERING_CONTROL_PREFIX))
+                && !service.event_sub_url.is_empty()
+        });
+        DeviceCapabilities {
+            can_play: av_transport_url.is_some_and(|url| !url.is_empty()),
+            can_control_volume: rendering_control_url.is_some_and(|url| !url.is_empty()),
+            can_subscribe,
+        }
+    }
+
+    pub(crate) fn all_device_types(&self) -> Vec<&str> {
+        let mut types = vec![self.device_type.as_str()];
+        for device in &self.devices {
+            types.extend(device.all_device_types());
+        }
+        types
+    }
+
+    // 本设备及所有嵌入设备的 UDN (嵌入设备以各自的 UDN 发送 NOTIFY)
+    pub(crate) fn all_udns(&self) -> Vec<&str> {
+        let mut udns = vec![self.udn.as_str()];
+        for device in &self.devices {
+            udns.extend(device.all_udns());
+        }
+        udns
+    }
+
+    // 本设备及所有嵌入设备的服务
+    pub(crate) fn all_services(&self) -> Vec<&ServiceInfo> {
+        let mut services: Vec<&ServiceInfo> = self.services.iter().collect();
+        for device in &self.devices {
+            services.extend(device.all_services());
+        }
+        services
+    }
+
+    // 将图标、SCPD、控制、事件订阅及 presentationURL 解析为绝对地址 (含嵌入设备)
+    fn resolve_urls(&mut self, base: &Url) {
+        for icon in &mut self.icons {
+            icon.url = resolve_url(base, &icon.url);
+        }
+        for service in &mut self.services {
+            service.scpd_url = resolve_url(base, &service.scpd_url);
+            service.control_url = resolve_url(base, &service.control_url);
+            service.event_sub_url = resolve_url(base, &service.event_sub_url);
+        }
+        if let Some(presentation_url) = &mut self.presentation_url {
+            *presentation_url = resolve_url(base, presentation_url);
+        }
+        for device in &mut self.devices {
+            device.resolve_urls(base);
+        }
+    }
+}
+
+// deviceType 形如 urn:schemas-upnp-org:device:MediaRenderer:1，比较其中的类型名
+fn device_type_is(device_type: &str, name: &str) -> bool {
+    device_type
+        .split(':')
+        .nth(3)
+        .is_some_and(|t| t.eq_ignore_ascii_case(name))
+}
+
+// --- 解析 ---
+
+// 解析 description.xml (实体由 xml-rs 解码，忽略命名空间前缀)，其中的 URL 均解析为绝对地址
+pub(crate) fn parse_description(xml: &str, location: &str) -> Result<DeviceDescription> {
+    let root = parse_tree(xml)?;
+    let device = root
+        .child("device")
+        .context("Device description has no <device> element")?;
+
+    // URLBase 在 UDA 1.1 中已废弃，但仍有旧设备依赖它；缺失或无效时以 LOCATION 为基准
+    let base = root
+        .child_text("URLBase")
+        .and_then(|url_base| Url::parse(&url_base).ok())
+        .or_else(|| Url::parse(location).ok());
+
+    let mut description = parse_device(device);
+    if let Some(base) = &base {
+        description.resolve_urls(base);
+    }
+    Ok(description)
+}
+
+// 按 RFC 3986 将引用解析为绝对 URL；空值保持为空，无法解析时保留原值
+pub(crate) fn resolve_url(base: &Url, reference: &str) -> String {
+    if reference.is_empty() {
+        return String::new();
+    }
+    base.join(reference)
+        .map(String::from)
+        .unwrap_or_else(|_| reference.to_string())
+}
+
+fn parse_device(element: &XmlElement) -> DeviceDescription {
+    let text = |name: &str| element.child_text(name).unwrap_or_default();
+
+    DeviceDescription {
+        device_type: text("deviceType"),
+        friendly_name: text("friendlyName"),
+        manufacturer: text("manufacturer"),
+        manufacturer_url: element.child_text("manufacturerURL"),
+        model_description: element.child_text("modelDescription"),
+        model_name: text("modelName"),
+        model_number: element.child_text("modelNumber"),
+        model_url: element.child_text("modelURL"),
+        serial_number: element.child_text("serialNumber"),
+        udn: text("UDN"),
+        presentation_url: element.child_text("presentationURL"),
+        mac_address: element
+            .children
+            .iter()
+            .filter(|child| child.name.to_ascii_lowercase().contains("mac"))
+            .find_map(|child| normalize_mac(&child.text)),
+        icons: list_items(element, "iconList", "icon")
+            .map(|icon| {
+                let number = |name: &str| {
+                    icon.child_text(name)
+                        .and_then(|value| value.parse().ok())
+                        .unwrap_or(0)
+                };
+                DeviceIcon {
+                    mime_type: icon.child_text("mimetype").unwrap_or_default(),
+                    width: number("width"),
+                    height: number("height"),
+                    depth: number("depth"),
+                    url: icon.child_text("url").unwrap_or_default(),
+                }
+            })
+            .collect(),
+        services: list_items(element, "serviceList", "service")
+            .map(|service| {
+                let text = |name: &str| service.child_text(name).unwrap_or_default();
+                ServiceInfo {
+                    service_type: text("serviceType"),
+                    service_id: text("serviceId"),
+                    scpd_url: text("SCPDURL"),
+                    control_url: text("controlURL"),
+                    event_sub_url: text("eventSubURL"),
+                }
+            })
+            .collect(),
+        devices: list_items(element, "deviceList", "device")
+            .map(parse_device)
+            .collect(),
+    }
+}
+
+// 遍历 <list><item/>...</list> 形式的子元素
+pub(crate) fn list_items<'a>(
+    element: &'a XmlElement,
+    list: &'a str,
+    item: &'a str,
+) -> impl Iterator<Item = &'a XmlElement> + 'a {
+    element
+        .child(list)
+        .into_iter()
+        .flat_map(move |list| list.children.iter().filter(move |c| c.name == item))
+}
+
+// --- 简易 XML 树 ---
+
+pub(crate) struct XmlElement {
+    pub(crate) name: String, // 本地名 (不含命名空间前缀)
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) text: String,
+    pub(crate) children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    pub(crate) fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // 子元素的文本内容，去掉首尾空白后为空时返回 None
+    pub(crate) fn child_text(&self, name: &str) -> Option<String> {
+        self.child(name)
+            .map(|c| c.text.trim().to_string())
+            .filter(|text| !text.is_empty())
+    }
+}
+
+pub(crate) fn parse_tree(xml: &str) -> Result<XmlElement> {
+    let mut stack: Vec<XmlElement> = Vec::new();
+
+    for event in EventReader::new(xml.trim_start_matches('\u{feff}').as_bytes()) {
+        match event.context("Malformed UPnP XML document")? {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => stack.push(XmlElement {
+                name: name.local_name,
+                attributes: attributes
+                    .into_iter()
+                    .map(|attr| (attr.name.local_name, attr.value))
+                    .collect(),
+                text: String::new(),
+                children: Vec::new(),
+            }),
+            XmlEvent::Characters(text) | XmlEvent::CData(text) => {
+                if let Some(current) = stack.last_mut() {
+                    current.text.push_str(&text);
+                }
+            }
+            XmlEvent::EndElement { .. } => {
+                let element = stack.pop().context("Unbalanced XML element")?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => return Ok(element),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err(anyhow::anyhow!("UPnP XML document has no root element"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMSUNG: &str = include_str!("../../tests/fixtures/descriptions/samsung_tv.xml");
+    const LG: &str = include_str!("../../tests/fixtures/descriptions/lg_webos.xml");
+    const SONY: &str = include_str!("../../tests/fixtures/descriptions/sony_bravia.xml");
+    const XIAOMI: &str = include_str!("../../tests/fixtures/descriptions/xiaomi_tv.xml");
+    const KODI: &str = include_str!("../../tests/fixtures/descriptions/kodi.xml");
+
+    const AV_TRANSPORT: &str = "urn:schemas-upnp-org:service:AVTransport:1";
+
+    fn service<'a>(description: &'a DeviceDescription, service_type: &str) -> &'a ServiceInfo {
+        description.find_service(service_type).unwrap()
+    }
+
+    #[test]
+    fn samsung_resolves_relative_scpd_against_location() {
+        let d = parse_description(SAMSUNG, "http://192.168.1.20:9197/dmr").unwrap();
+        assert_eq!(d.friendly_name, "[TV] Samsung Q60 Series (55)");
+        assert_eq!(d.model_name, "QE55Q60RATXXU");
+        assert_eq!(d.udn, "uuid:a1f1b3c7-5e2d-4a8f-9c1b-80a5897e0c2e");
+        assert_eq!(d.icons.len(), 4);
+        assert_eq!(d.icons[3].url, "http://192.168.1.20:9197/dmr/icon_LRG.png");
+        assert_eq!((d.icons[3].width, d.icons[3].height), (120, 120));
+
+        let av = service(&d, AV_TRANSPORT);
+        assert_eq!(av.scpd_url, "http://192.168.1.20:9197/AVTransport_1.xml");
+        assert_eq!(
+            av.control_url,
+            "http://192.168.1.20:9197/upnp/control/AVTransport1"
+        );
+        assert_eq!(
+            av.event_sub_url,
+            "http://192.168.1.20:9197/upnp/event/AVTransport1"
+        );
+    }
+
+    #[test]
+    fn lg_absolute_path_ignores_nested_location() {
+        let d = parse_description(LG, "http://192.168.1.40:1527/rdr/description.xml").unwrap();
+        assert_eq!(d.manufacturer, "LG Electronics");
+
+        let av = service(&d, AV_TRANSPORT);
+        assert_eq!(
+            av.control_url,
+            "http://192.168.1.40:1527/upnp/control/AVTransport1"
+        );
+        assert_eq!(
+            av.scpd_url,
+            "http://192.168.1.40:1527/rdr/AVTransport/desc.xml"
+        );
+        assert_eq!(
+            d.icons[0].url,
+            "http://192.168.1.40:1527/rdr/icon/icon120.png"
+        );
+    }
+
+    #[test]
+    fn sony_ignores_vendor_namespaced_elements() {
+        let d = parse_description(SONY, "http://192.168.1.30:52323/dmr.xml").unwrap();
+        assert_eq!(d.friendly_name, "KD-55X85J");
+        assert_eq!(d.manufacturer, "Sony Corporation");
+        assert_eq!(d.serial_number, None);
+        assert_eq!(d.services.len(), 3);
+        assert_eq!(
+            service(&d, AV_TRANSPORT).control_url,
+            "http://192.168.1.30:52323/upnp/control/AVTransport"
+        );
+    }
+
+    #[test]
+    fn xiaomi_honors_url_base() {
+        let d = parse_description(XIAOMI, "http://192.168.1.50:49152/description.xml").unwrap();
+        assert_eq!(d.friendly_name, "客厅的小米电视");
+        assert_eq!(
+            d.presentation_url.as_deref(),
+            Some("http://192.168.1.50:49152/")
+        );
+
+        let av = service(&d, AV_TRANSPORT);
+        assert_eq!(
+            av.control_url,
+            "http://192.168.1.50:49152/_urn:schemas-upnp-org:service:AVTransport_control"
+        );
+        assert_eq!(
+            av.scpd_url,
+            "http://192.168.1.50:49152/_urn:schemas-upnp-org:service:AVTransport_scpd.xml"
+        );
+    }
+
+    #[test]
+    fn kodi_decodes_entities_and_keeps_absolute_urls() {
+        let d = parse_description(KODI, "http://192.168.1.60:1151/").unwrap();
+        assert_eq!(d.friendly_name, "Kodi (Living Room & Den)");
+        assert_eq!(d.serial_number.as_deref(), Some("<none>"));
+        assert_eq!(
+            d.presentation_url.as_deref(),
+            Some("http://192.168.1.60:8080/")
+        );
+        assert_eq!(
+            d.icons[0].url,
+            "http://192.168.1.60:1151/images/kodi-icon-256x256.png"
+        );
+        assert_eq!(
+            service(&d, AV_TRANSPORT).event_sub_url,
+            "http://192.168.1.60:1151/AVTransport/d5c6e2a8-9f1b-4c3d-a7e6-0b1c2d3e4f5a/event.xml"
+        );
+    }
+
+    #[test]
+    fn mac_address_comes_from_vendor_mac_elements_only() {
+        // ProductCap 中的 P2P MAC 不是设备网卡地址，不应被采用
+        let samsung = parse_description(SAMSUNG, "http://192.168.1.20:9197/dmr").unwrap();
+        assert_eq!(samsung.mac_address, None);
+
+        let xml = r#"<?xml version="1.0"?>
+<root xmlns="urn:schemas-upnp-org:device-1-0" xmlns:x="urn:vendor">
+  <device>
+    <deviceType>urn:schemas-upnp-org:device:MediaRenderer:1</deviceType>
+    <friendlyName>Projector</friendlyName>
+    <UDN>uuid:projector</UDN>
+    <x:macAddress>unknown</x:macAddress>
+    <x:wiredMac>A4-5E-60-C1-22-0F</x:wiredMac>
+  </device>
+</root>"#;
+        let d = parse_description(xml, "http://10.0.0.3:49152/description.xml").unwrap();
+        assert_eq!(d.mac_address.as_deref(), Some("a4:5e:60:c1:22:0f"));
+    }
+
+    #[test]
+    fn embedded_devices_are_parsed_and_resolved() {
+        let xml = r#"<?xml version="1.0"?>
+<root xmlns="urn:schemas-upnp-org:device-1-0">
+  <URLBase>http://10.0.0.2:8200/base/</URLBase>
+  <device>
+    <deviceType>urn:schemas-upnp-org:device:Basic:1</deviceType>
+    <friendlyName>Media Hub</friendlyName>
+    <UDN>uuid:root</UDN>
+    <deviceList>
+      <device>
+        <deviceType>urn:schemas-upnp-org:device:MediaRenderer:1</deviceType>
+        <friendlyName>Media Hub Renderer</friendlyName>
+        <UDN>uuid:renderer</UDN>
+        <serviceList>
+          <service>
+            <serviceType>urn:schemas-upnp-org:service:AVTransport:1</serviceType>
+            <serviceId>urn:upnp-org:serviceId:AVTransport</serviceId>
+            <SCPDURL>AVT.xml</SCPDURL>
+            <controlURL>http-control/AVTransport</controlURL>
+            <eventSubURL></eventSubURL>
+          </service>
+        </serviceList>
+      </device>
+    </deviceList>
+  </device>
+</root>"#;
+        let d = parse_description(xml, "http://10.0.0.2:8200/rootDesc.xml").unwrap();
+        assert_eq!(d.devices.len(), 1);
+        assert_eq!(d.devices[0].udn, "uuid:renderer");
+        assert_eq!(d.kind(), DeviceKind::MediaRenderer);
+
+        let av = service(&d, AV_TRANSPORT);
+        assert_eq!(av.scpd_url, "http://10.0.0.2:8200/base/AVT.xml");
+        assert_eq!(
+            av.control_url,
+            "http://10.0.0.2:8200/base/http-control/AVTransport"
+        );
+        assert_eq!(av.event_sub_url, "");
+    }
+
+    #[test]
+    fn resolve_url_follows_rfc3986() {
+        let base = Url::parse("http://192.168.1.2:1400/xml/device_description.xml").unwrap();
+        assert_eq!(
+            resolve_url(&base, "/MediaRenderer/AVTransport/Control"),
+            "http://192.168.1.2:1400/MediaRenderer/AVTransport/Control"
+        );
+        assert_eq!(
+            resolve_url(&base, "control"),
+            "http://192.168.1.2:1400/xml/control"
+        );
+        assert_eq!(
+            resolve_url(&base, "../img/icon.png"),
+            "http://192.168.1.2:1400/img/icon.png"
+        );
+        assert_eq!(
+            resolve_url(&base, "http://192.168.1.3/ctl"),
+            "http://192.168.1.3/ctl"
+        );
+        assert_eq!(resolve_url(&base, ""), "");
+    }
+}
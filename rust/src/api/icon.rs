@@ -0,0 +1,115 @@
+use crate::api::cast::http_request;
+use crate::api::description::{DeviceDescription, DeviceIcon};
+use anyhow::{Context, Result};
+use reqwest::Method;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+// 单个设备已下载的图标：图标 URL -> 图片数据
+type DeviceIconCache = HashMap<String, Vec<u8>>;
+
+// 按 UDN 缓存已下载的图标
+static ICON_CACHE: LazyLock<Mutex<HashMap<String, DeviceIconCache>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// 设备声明的图标；根设备没有 iconList 时使用嵌入设备的图标
+pub(crate) fn device_icons(description: &DeviceDescription) -> Vec<DeviceIcon> {
+    if !description.icons.is_empty() {
+        return description.icons.clone();
+    }
+    description.devices.iter().flat_map(device_icons).collect()
+}
+
+// 选择最接近 preferred_size 的图标：优先不小于目标尺寸的最小图标 (缩小比放大清晰)，
+// 都偏小时取最大的；尺寸相同时 PNG、色深更高者优先
+pub(crate) fn best_icon(icons: &[DeviceIcon], preferred_size: u32) -> Option<&DeviceIcon> {
+    icons
+        .iter()
+        .filter(|icon| !icon.url.is_empty())
+        .min_by_key(|icon| {
+            let size = icon.width.max(icon.height);
+            (
+                size < preferred_size,
+                size.abs_diff(preferred_size),
+                icon.mime_type != "image/png",
+                Reverse(icon.depth),
+            )
+        })
+}
+
+// 下载图标，同一设备的同一图标只下载一次
+pub(crate) async fn fetch_icon_cached(udn: &str, url: &str) -> Result<Vec<u8>> {
+    if let Some(data) = ICON_CACHE
+        .lock()
+        .unwrap()
+        .get(udn)
+        .and_then(|icons| icons.get(url))
+    {
+        return Ok(data.clone());
+    }
+
+    let data = http_request(Method::GET, url)?
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("Failed to download icon {}", url))?
+        .bytes()
+        .await?
+        .to_vec();
+
+    ICON_CACHE
+        .lock()
+        .unwrap()
+        .entry(udn.to_string())
+        .or_default()
+        .insert(url.to_string(), data.clone());
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(mime_type: &str, size: u32, url: &str) -> DeviceIcon {
+        DeviceIcon {
+            mime_type: mime_type.to_string(),
+            width: size,
+            height: size,
+            depth: 24,
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn prefers_the_smallest_icon_not_below_the_requested_size() {
+        let icons = [
+            icon("image/png", 48, "/48.png"),
+            icon("image/png", 256, "/256.png"),
+            icon("image/png", 120, "/120.png"),
+        ];
+        assert_eq!(best_icon(&icons, 100).unwrap().url, "/120.png");
+        assert_eq!(best_icon(&icons, 48).unwrap().url, "/48.png");
+    }
+
+    #[test]
+    fn falls_back_to_the_largest_icon_when_all_are_too_small() {
+        let icons = [icon("image/png", 48, "/48.png"), icon("image/png", 120, "/120.png")];
+        assert_eq!(best_icon(&icons, 512).unwrap().url, "/120.png");
+    }
+
+    #[test]
+    fn png_wins_a_size_tie() {
+        let icons = [icon("image/jpeg", 120, "/120.jpg"), icon("image/png", 120, "/120.png")];
+        assert_eq!(best_icon(&icons, 120).unwrap().url, "/120.png");
+    }
+
+    #[test]
+    fn icons_without_url_are_skipped() {
+        let icons = [icon("image/png", 120, ""), icon("image/jpeg", 48, "/48.jpg")];
+        assert_eq!(best_icon(&icons, 120).unwrap().url, "/48.jpg");
+        assert!(best_icon(&icons[..1], 120).is_none());
+    }
+}
@@ -1 +1,9 @@
 pub mod cast;
+pub mod description;
+pub mod icon;
+pub mod monitor;
+pub mod network;
+pub mod notify;
+pub mod scpd;
+pub mod ssdp;
+pub mod sweep;
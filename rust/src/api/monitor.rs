@@ -0,0 +1,265 @@
+use crate::api::cast::{
+    fill_missing_udn, http_request, parse_device_xml, parse_ip, unicast_search, wait_cancelled,
+    ProjectorInfo,
+};
+use crate::api::scpd::forget_service_descriptions;
+use crate::api::ssdp::{DeviceChange, SsdpMessage};
+use crate::frb_generated::StreamSink;
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use reqwest::Method;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+// 后台监控时每次检查的超时
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+// 连续失败多少次才判定为离线，避免偶发丢包造成状态抖动
+const OFFLINE_AFTER_FAILURES: u32 = 2;
+
+// --- 数据模型 ---
+
+/// 设备可达性检查结果
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct DeviceStatus {
+    pub online: bool,                 // 任一检查成功即视为在线
+    pub http_latency_ms: Option<u32>, // 描述文件请求的耗时，失败时为 None
+    pub ssdp_latency_ms: Option<u32>, // 单播 M-SEARCH 的响应耗时，失败时为 None
+    pub error: Option<String>,        // 两项检查都失败时的原因
+    pub ssdp: Option<SsdpMessage>,    // 单播 M-SEARCH 的响应 (含 BOOTID/CONFIGID)
+}
+
+// 在线/离线状态变化事件
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub enum ReachabilityEvent {
+    Online {
+        device: ProjectorInfo,
+        status: DeviceStatus,
+    },
+    Offline {
+        device: ProjectorInfo,
+        status: DeviceStatus,
+    },
+    // BOOTID.UPNP.ORG 变化，device 为重新解析描述文件后的设备信息
+    Rebooted {
+        device: ProjectorInfo,
+        status: DeviceStatus,
+    },
+    // CONFIGID.UPNP.ORG 变化，device 为重新解析描述文件后的设备信息
+    DescriptionChanged {
+        device: ProjectorInfo,
+        status: DeviceStatus,
+    },
+}
+
+struct MonitorState {
+    online: Option<bool>, // None 表示尚未检查过
+    failures: u32,
+}
+
+// --- 设备在线监控 ---
+
+/// 定期检查一组设备的可达性，推送上线/离线变化。一个 DeviceMonitor 只对应一次监控，停止后需重新创建
+#[frb(opaque)]
+pub struct DeviceMonitor {
+    devices: Vec<ProjectorInfo>,
+    interval_secs: u64,
+    stop_tx: watch::Sender<bool>,
+}
+
+impl DeviceMonitor {
+    /// interval_secs 为检查间隔 (最小 1 秒)
+    #[frb(sync)]
+    pub fn new(devices: Vec<ProjectorInfo>, interval_secs: u64) -> Self {
+        let (stop_tx, _) = watch::channel(false);
+        Self {
+            devices,
+            interval_secs: interval_secs.max(1),
+            stop_tx,
+        }
+    }
+
+    /// 开始监控，直到调用 stop() 为止；首次检查会推送每个设备的初始状态，
+    /// 设备重启或描述变化时推送刷新后的设备信息
+    pub async fn run(&self, sink: StreamSink<ReachabilityEvent>) -> Result<()> {
+        let mut stop_rx = self.stop_tx.subscribe();
+        let mut timer = tokio::time::interval(Duration::from_secs(self.interval_secs));
+        let mut states: HashMap<String, MonitorState> = HashMap::new();
+        // 重启后的设备信息会替换这里的副本，后续事件使用最新的控制 URL
+        let mut devices = self.devices.clone();
+
+        loop {
+            tokio::select! {
+                // run() 开始前已调用 stop() 时立即返回
+                _ = wait_cancelled(&mut stop_rx) => break,
+                _ = timer.tick() => {
+                    let statuses = futures::future::join_all(
+                        devices.iter().map(|device| check_device(device, CHECK_TIMEOUT)),
+                    )
+                    .await;
+                    for (device, status) in devices.iter_mut().zip(statuses) {
+                        let change = track_boot_state(device, &status).await;
+                        let state = states.entry(device.udn.clone()).or_insert(MonitorState {
+                            online: None,
+                            failures: 0,
+                        });
+                        if let Some(event) = update_state(state, device, status.clone()) {
+                            let _ = sink.add(event);
+                        }
+                        if let Some(change) = change {
+                            let device = device.clone();
+                            let _ = sink.add(match change {
+                                DeviceChange::Rebooted => ReachabilityEvent::Rebooted { device, status },
+                                DeviceChange::DescriptionChanged => {
+                                    ReachabilityEvent::DescriptionChanged { device, status }
+                                }
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        println!("Monitor: Stopped");
+        Ok(())
+    }
+
+    /// 停止监控；在 run() 开始前调用时 run() 会立即返回
+    #[frb(sync)]
+    pub fn stop(&self) {
+        let _ = self.stop_tx.send_replace(true);
+    }
+}
+
+// 根据本次检查结果更新状态，状态发生变化时返回事件
+fn update_state(
+    state: &mut MonitorState,
+    device: &ProjectorInfo,
+    status: DeviceStatus,
+) -> Option<ReachabilityEvent> {
+    if status.online {
+        state.failures = 0;
+        if state.online == Some(true) {
+            return None;
+        }
+        state.online = Some(true);
+        return Some(ReachabilityEvent::Online {
+            device: device.clone(),
+            status,
+        });
+    }
+
+    state.failures += 1;
+    let went_offline = match state.online {
+        None => true,
+        Some(true) => state.failures >= OFFLINE_AFTER_FAILURES,
+        Some(false) => false,
+    };
+    if !went_offline {
+        return None;
+    }
+    state.online = Some(false);
+    Some(ReachabilityEvent::Offline {
+        device: device.clone(),
+        status,
+    })
+}
+
+// 比较 SSDP 响应中的 BOOTID/CONFIGID，变化时重新解析描述文件并替换 device
+async fn track_boot_state(
+    device: &mut ProjectorInfo,
+    status: &DeviceStatus,
+) -> Option<DeviceChange> {
+    let current = status.ssdp.as_ref()?;
+    let change = device
+        .ssdp
+        .as_ref()
+        .and_then(|previous| current.change_since(previous));
+    let Some(change) = change else {
+        device.ssdp = Some(current.clone());
+        return None;
+    };
+
+    let location = current
+        .location
+        .clone()
+        .unwrap_or_else(|| device.location_xml_url.clone());
+    // 设备重启或描述变化后，旧的 SCPD 缓存可能已失效
+    forget_service_descriptions(&device.description);
+    match parse_device_xml(&location).await {
+        Ok(mut info) => {
+            fill_missing_udn(&mut info, current.usn.as_deref());
+            *device = info;
+        }
+        Err(e) => println!("Monitor: Failed to refresh {}: {}", device.friendly_name, e),
+    }
+    // 刷新失败时也记录新的 BOOTID/CONFIGID，避免每次检查重复推送
+    device.ssdp = Some(current.clone());
+    Some(change)
+}
+
+// --- 可达性检查 ---
+
+// 同时检查描述文件和 SSDP，两者任一成功即视为在线
+pub(crate) async fn check_device(device: &ProjectorInfo, timeout: Duration) -> DeviceStatus {
+    let (http, ssdp) = tokio::join!(
+        check_description(&device.location_xml_url, timeout),
+        check_ssdp(device, timeout)
+    );
+
+    let error = match (&http, &ssdp) {
+        (Err(http_err), Err(ssdp_err)) => {
+            Some(format!("HTTP: {:#}; SSDP: {:#}", http_err, ssdp_err))
+        }
+        _ => None,
+    };
+    let (ssdp_latency_ms, ssdp) = ssdp.ok().unzip();
+    DeviceStatus {
+        online: error.is_none(),
+        http_latency_ms: http.ok(),
+        ssdp_latency_ms,
+        error,
+        ssdp,
+    }
+}
+
+// 请求描述文件，部分设备不支持 HEAD，失败时改用 GET
+async fn check_description(location: &str, timeout: Duration) -> Result<u32> {
+    let start = Instant::now();
+
+    let head = http_request(Method::HEAD, location)?.timeout(timeout).send().await;
+    if matches!(&head, Ok(response) if response.status().is_success()) {
+        return Ok(elapsed_ms(start));
+    }
+
+    let remaining = timeout.saturating_sub(start.elapsed());
+    if remaining.is_zero() {
+        return Err(anyhow::anyhow!("Description request timed out"));
+    }
+    http_request(Method::GET, location)?
+        .timeout(remaining)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(elapsed_ms(start))
+}
+
+// 向设备 IP 发送单播 M-SEARCH，有 UDN 时按 UDN 搜索以确认仍是同一台设备
+async fn check_ssdp(device: &ProjectorInfo, timeout: Duration) -> Result<(u32, SsdpMessage)> {
+    let target_ip = parse_ip(&device.ip)?;
+    let search_target = if device.udn.to_ascii_lowercase().starts_with("uuid:") {
+        device.udn.as_str()
+    } else {
+        "upnp:rootdevice"
+    };
+
+    let start = Instant::now();
+    let (_, message) = unicast_search(target_ip, search_target, timeout).await?;
+    Ok((elapsed_ms(start), message))
+}
+
+fn elapsed_ms(start: Instant) -> u32 {
+    start.elapsed().as_millis().min(u32::MAX as u128) as u32
+}
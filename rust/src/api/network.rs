@@ -0,0 +1,802 @@
+use anyhow::{Context, Result};
+use flutter_rust_bridge::frb;
+use if_addrs::{IfAddr, Interface};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::{LazyLock, Mutex};
+
+// 链路本地 IPv6 地址 -> 所在接口索引 (scope id)；URL 无法携带 %zone，连接时从这里查找
+static LINK_LOCAL_SCOPES: LazyLock<Mutex<HashMap<Ipv6Addr, u32>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// --- 数据模型 ---
+
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct NetworkInterface {
+    pub name: String, // 接口名 (如 en0、wlan0、eth0)
+    pub index: Option<u32>,
+    pub ip: String,
+    pub prefix_len: u8,
+    pub is_ipv6: bool,
+    pub is_up: bool,
+    pub is_loopback: bool,
+    pub eligible: bool, // 是否参与默认扫描 (排除回环、IPv4 链路本地、VPN/点对点接口)
+}
+
+// 参与 SSDP 扫描的接口
+#[derive(Debug, Clone)]
+pub(crate) struct ScanInterface {
+    pub(crate) name: String,
+    pub(crate) ip: Ipv4Addr,
+    pub(crate) prefix_len: u8,
+}
+
+// --- 接口枚举 ---
+
+/// 列出本机所有网络接口地址 (IPv4 与 IPv6)
+pub fn list_network_interfaces() -> Result<Vec<NetworkInterface>> {
+    let interfaces = if_addrs::get_if_addrs().context("Failed to enumerate network interfaces")?;
+
+    Ok(interfaces
+        .iter()
+        .map(|iface| {
+            let prefix_len = match &iface.addr {
+                IfAddr::V4(addr) => addr.prefixlen,
+                IfAddr::V6(addr) => addr.prefixlen,
+            };
+            NetworkInterface {
+                name: iface.name.clone(),
+                index: iface.index,
+                ip: iface.ip().to_string(),
+                prefix_len,
+                is_ipv6: iface.ip().is_ipv6(),
+                is_up: iface.is_oper_up(),
+                is_loopback: iface.is_loopback(),
+                eligible: is_eligible(iface),
+            }
+        })
+        .collect())
+}
+
+// 选择扫描使用的接口：selected 为空时返回所有可用接口，否则按接口名或 IP 匹配
+pub(crate) fn select_scan_interfaces(selected: &[String]) -> Result<Vec<ScanInterface>> {
+    let interfaces = if_addrs::get_if_addrs().context("Failed to enumerate network interfaces")?;
+
+    let mut result = Vec::new();
+    for iface in &interfaces {
+        let IfAddr::V4(addr) = &iface.addr else {
+            continue;
+        };
+        let wanted = if selected.is_empty() {
+            is_eligible(iface)
+        } else {
+            iface.is_oper_up()
+                && selected
+                    .iter()
+                    .any(|s| *s == iface.name || *s == addr.ip.to_string())
+        };
+        if wanted {
+            result.push(ScanInterface {
+                name: iface.name.clone(),
+                ip: addr.ip,
+                prefix_len: addr.prefixlen,
+            });
+        }
+    }
+
+    if !selected.is_empty() && result.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No active network interface matches {:?}",
+            selected
+        ));
+    }
+    Ok(result)
+}
+
+// 选择 IPv6 扫描使用的接口索引 (同一接口的多个 IPv6 地址只保留一个)
+pub(crate) fn select_ipv6_interfaces(selected: &[String]) -> Result<Vec<u32>> {
+    let interfaces = if_addrs::get_if_addrs().context("Failed to enumerate network interfaces")?;
+
+    let mut result = Vec::new();
+    for iface in &interfaces {
+        let (IfAddr::V6(addr), Some(index)) = (&iface.addr, iface.index) else {
+            continue;
+        };
+        let wanted = if selected.is_empty() {
+            is_eligible(iface)
+        } else {
+            iface.is_oper_up()
+                && selected
+                    .iter()
+                    .any(|s| *s == iface.name || *s == addr.ip.to_string())
+        };
+        if wanted && !result.contains(&index) {
+            result.push(index);
+        }
+    }
+
+    if result.is_empty() {
+        return Err(anyhow::anyhow!("No active IPv6 network interface available"));
+    }
+    Ok(result)
+}
+
+// 根据接口地址和前缀长度计算定向广播地址 (/31、/32 没有广播地址)
+pub(crate) fn directed_broadcast(ip: Ipv4Addr, prefix_len: u8) -> Option<Ipv4Addr> {
+    if prefix_len >= 31 {
+        return None;
+    }
+    let mask = u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or(0);
+    Some(Ipv4Addr::from(u32::from(ip) | !mask))
+}
+
+// --- 链路本地 IPv6 ---
+
+// fe80::/10
+pub(crate) fn is_link_local_v6(ip: &Ipv6Addr) -> bool {
+    ip.segments()[0] & 0xffc0 == 0xfe80
+}
+
+// 记录 SSDP 消息来源的接口：源地址，以及 LOCATION 中的链路本地地址 (可能是同一接口上的另一个地址)
+pub(crate) fn remember_link_local_scope(source: SocketAddr, location: Option<&str>) {
+    let SocketAddr::V6(source) = source else {
+        return;
+    };
+    if source.scope_id() == 0 || !is_link_local_v6(source.ip()) {
+        return;
+    }
+    let mut scopes = LINK_LOCAL_SCOPES.lock().unwrap();
+    scopes.insert(*source.ip(), source.scope_id());
+    if let Some(ip) = location.and_then(url_ipv6).filter(is_link_local_v6) {
+        scopes.insert(ip, source.scope_id());
+    }
+}
+
+// URL 主机为 IPv6 地址时返回该地址 (http://[fe80::1]:49152/ -> fe80::1)
+pub(crate) fn url_ipv6(url: &str) -> Option<Ipv6Addr> {
+    let url = reqwest::Url::parse(url).ok()?;
+    url.host_str()?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+        .ok()
+}
+
+pub(crate) fn set_link_local_scope(ip: Ipv6Addr, scope_id: u32) {
+    LINK_LOCAL_SCOPES.lock().unwrap().insert(ip, scope_id);
+}
+
+pub(crate) fn link_local_scope(ip: &Ipv6Addr) -> Option<u32> {
+    LINK_LOCAL_SCOPES.lock().unwrap().get(ip).copied()
+}
+
+// 解析地址中的 %zone：接口索引 (%3) 或接口名 (%en0)
+pub(crate) fn parse_zone(zone: &str) -> Result<u32> {
+    if let Ok(index) = zone.parse() {
+        return Ok(index);
+    }
+    if_addrs::get_if_addrs()
+        .context("Failed to enumerate network interfaces")?
+        .iter()
+        .find(|iface| iface.name == zone)
+        .and_then(|iface| iface.index)
+        .ok_or_else(|| anyhow::anyhow!("Unknown network interface: {}", zone))
+}
+
+// --- 邻居表 (ARP / NDP) ---
+//
+// 各平台读取系统邻居表的方式：
+// - Linux/Android：netlink RTM_GETNEIGH，失败时读取 /proc/net/arp。Android 10 起应用无法读取
+//   /proc/net/arp，Android 11 起 (targetSdk >= 30) netlink 邻居表也被禁止，此时查不到 MAC
+// - macOS：sysctl (NET_RT_FLAGS + RTF_LLINFO)
+// - Windows：GetIpNetTable2
+// - iOS：系统禁止访问 ARP 缓存，始终查不到
+// 查不到时调用方回退到描述文件中的 MAC
+
+// 邻居表中的一项
+#[derive(Debug, Clone, PartialEq)]
+struct Neighbor {
+    ip: IpAddr,
+    mac: Vec<u8>,
+}
+
+// 从系统邻居表查找 IP 对应的 MAC；读取邻居表是阻塞调用 (netlink 最长等待 1 秒)，在阻塞线程池中执行
+pub(crate) async fn lookup_neighbor_mac(ip: &str) -> Option<String> {
+    let ip: IpAddr = ip.parse().ok()?;
+    tokio::task::spawn_blocking(move || neighbor_mac(ip))
+        .await
+        .ok()
+        .flatten()
+}
+
+fn neighbor_mac(ip: IpAddr) -> Option<String> {
+    match read_neighbors() {
+        Ok(neighbors) => neighbors
+            .iter()
+            .filter(|neighbor| neighbor.ip == ip)
+            .find_map(|neighbor| format_mac(&neighbor.mac)),
+        Err(e) => {
+            // 不支持的平台没有邻居表可读，不算错误
+            if e.kind() != std::io::ErrorKind::Unsupported {
+                println!("ARP: Failed to read neighbor table: {}", e);
+            }
+            fallback_neighbor_mac(ip)
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn read_neighbors() -> std::io::Result<Vec<Neighbor>> {
+    use socket2::{Domain, Protocol, Socket, Type};
+    use std::io::Read;
+
+    let socket = Socket::new(
+        Domain::from(libc::AF_NETLINK),
+        Type::RAW,
+        Some(Protocol::from(libc::NETLINK_ROUTE)),
+    )?;
+    socket.set_read_timeout(Some(std::time::Duration::from_secs(1)))?;
+
+    // nlmsghdr (16 字节) + ndmsg (12 字节)；ndm_family 为 AF_UNSPEC，同时返回 IPv4 与 IPv6 邻居
+    let mut request = [0u8; NLMSG_HEADER_LEN + NDMSG_LEN];
+    request[0..4].copy_from_slice(&((NLMSG_HEADER_LEN + NDMSG_LEN) as u32).to_ne_bytes());
+    request[4..6].copy_from_slice(&libc::RTM_GETNEIGH.to_ne_bytes());
+    let flags = (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16;
+    request[6..8].copy_from_slice(&flags.to_ne_bytes());
+    request[8..12].copy_from_slice(&1u32.to_ne_bytes());
+    // 未 connect 的 netlink socket 默认发往内核
+    socket.send(&request)?;
+
+    let mut neighbors = Vec::new();
+    let mut buffer = vec![0u8; 32 * 1024];
+    loop {
+        let size = (&socket).read(&mut buffer)?;
+        if size == 0 || parse_netlink_neighbors(&buffer[..size], &mut neighbors)? {
+            return Ok(neighbors);
+        }
+    }
+}
+
+// netlink 不可用时读取 /proc/net/arp (仅 IPv4)
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn fallback_neighbor_mac(ip: IpAddr) -> Option<String> {
+    let table = std::fs::read_to_string("/proc/net/arp").ok()?;
+    parse_arp_table(&table, &ip.to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn read_neighbors() -> std::io::Result<Vec<Neighbor>> {
+    let mut mib = [
+        libc::CTL_NET,
+        libc::PF_ROUTE,
+        0,
+        libc::AF_INET,
+        libc::NET_RT_FLAGS,
+        libc::RTF_LLINFO,
+    ];
+    // 第一次调用获取所需长度，第二次读取 (两次之间表可能变大，多留一些余量)
+    let mut len: libc::size_t = 0;
+    let status = unsafe {
+        libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as libc::c_uint,
+            std::ptr::null_mut(),
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+    if status < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    len += len / 2;
+    let mut buffer = vec![0u8; len];
+    let status = unsafe {
+        libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as libc::c_uint,
+            buffer.as_mut_ptr().cast(),
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+    if status < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    buffer.truncate(len);
+    Ok(parse_route_dump(
+        &buffer,
+        std::mem::size_of::<libc::rt_msghdr>(),
+    ))
+}
+
+#[cfg(windows)]
+fn read_neighbors() -> std::io::Result<Vec<Neighbor>> {
+    // MIB_IPNET_ROW2 / MIB_IPNET_TABLE2 (netioapi.h)
+    #[repr(C)]
+    struct MibIpnetRow2 {
+        address: [u8; 28], // SOCKADDR_INET
+        interface_index: u32,
+        interface_luid: u64,
+        physical_address: [u8; 32],
+        physical_address_length: u32,
+        state: i32, // NL_NEIGHBOR_STATE
+        flags: u8,
+        reachability_time: u32,
+    }
+    #[repr(C)]
+    struct MibIpnetTable2 {
+        num_entries: u32,
+        table: [MibIpnetRow2; 1],
+    }
+    #[link(name = "iphlpapi")]
+    extern "system" {
+        fn GetIpNetTable2(family: u16, table: *mut *mut MibIpnetTable2) -> u32;
+        fn FreeMibTable(memory: *const std::ffi::c_void);
+    }
+    const AF_UNSPEC: u16 = 0;
+    const AF_INET: u16 = 2;
+    const AF_INET6: u16 = 23;
+    // NlnsUnreachable = 0、NlnsIncomplete = 1，其余状态都有有效的物理地址
+    const NLNS_INCOMPLETE: i32 = 1;
+
+    let mut table = std::ptr::null_mut();
+    let status = unsafe { GetIpNetTable2(AF_UNSPEC, &mut table) };
+    if status != 0 {
+        return Err(std::io::Error::from_raw_os_error(status as i32));
+    }
+    let rows = unsafe {
+        std::slice::from_raw_parts(
+            std::ptr::addr_of!((*table).table).cast::<MibIpnetRow2>(),
+            (*table).num_entries as usize,
+        )
+    };
+    let neighbors = rows
+        .iter()
+        .filter(|row| row.state > NLNS_INCOMPLETE)
+        .filter_map(|row| {
+            let address = &row.address;
+            let ip: IpAddr = match u16::from_ne_bytes([address[0], address[1]]) {
+                AF_INET => <[u8; 4]>::try_from(&address[4..8]).ok()?.into(),
+                AF_INET6 => <[u8; 16]>::try_from(&address[8..24]).ok()?.into(),
+                _ => return None,
+            };
+            let len = (row.physical_address_length as usize).min(row.physical_address.len());
+            Some(Neighbor {
+                ip,
+                mac: row.physical_address[..len].to_vec(),
+            })
+        })
+        .collect();
+    unsafe { FreeMibTable(table.cast()) };
+    Ok(neighbors)
+}
+
+// iOS 等平台没有可访问的邻居表
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    windows
+)))]
+fn read_neighbors() -> std::io::Result<Vec<Neighbor>> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "the neighbor table is not accessible on this platform",
+    ))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn fallback_neighbor_mac(_ip: IpAddr) -> Option<String> {
+    None
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", test))]
+const NLMSG_HEADER_LEN: usize = 16;
+#[cfg(any(target_os = "linux", target_os = "android", test))]
+const NDMSG_LEN: usize = 12;
+
+// 解析一批 netlink 消息，收到 NLMSG_DONE 时返回 true
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parse_netlink_neighbors(data: &[u8], neighbors: &mut Vec<Neighbor>) -> std::io::Result<bool> {
+    let mut offset = 0;
+    while offset + NLMSG_HEADER_LEN <= data.len() {
+        let len = u32::from_ne_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        let kind = u16::from_ne_bytes(data[offset + 4..offset + 6].try_into().unwrap());
+        if len < NLMSG_HEADER_LEN || offset + len > data.len() {
+            break;
+        }
+        let payload = &data[offset + NLMSG_HEADER_LEN..offset + len];
+        match kind as libc::c_int {
+            libc::NLMSG_DONE => return Ok(true),
+            libc::NLMSG_ERROR => {
+                let errno = payload
+                    .get(0..4)
+                    .map_or(0, |bytes| i32::from_ne_bytes(bytes.try_into().unwrap()));
+                if errno != 0 {
+                    return Err(std::io::Error::from_raw_os_error(-errno));
+                }
+            }
+            _ if kind == libc::RTM_NEWNEIGH => neighbors.extend(parse_ndmsg(payload)),
+            _ => {}
+        }
+        offset += align4(len);
+    }
+    Ok(false)
+}
+
+// ndmsg：family(1) pad(3) ifindex(4) state(2) flags(1) type(1)，之后为 rtattr (NDA_DST、NDA_LLADDR 等)
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parse_ndmsg(payload: &[u8]) -> Option<Neighbor> {
+    let state = u16::from_ne_bytes(payload.get(8..10)?.try_into().ok()?);
+    // 未解析完成、解析失败或不使用链路层地址 (如回环、点对点) 的条目没有可用的 MAC
+    if state & (libc::NUD_INCOMPLETE | libc::NUD_FAILED | libc::NUD_NOARP) != 0 {
+        return None;
+    }
+
+    let mut ip = None;
+    let mut mac = None;
+    let mut offset = NDMSG_LEN;
+    while offset + 4 <= payload.len() {
+        let len = u16::from_ne_bytes(payload[offset..offset + 2].try_into().unwrap()) as usize;
+        let kind = u16::from_ne_bytes(payload[offset + 2..offset + 4].try_into().unwrap());
+        if len < 4 || offset + len > payload.len() {
+            break;
+        }
+        let value = &payload[offset + 4..offset + len];
+        match kind {
+            libc::NDA_DST => {
+                ip = match value.len() {
+                    4 => Some(IpAddr::from(<[u8; 4]>::try_from(value).ok()?)),
+                    16 => Some(IpAddr::from(<[u8; 16]>::try_from(value).ok()?)),
+                    _ => None,
+                }
+            }
+            libc::NDA_LLADDR => mac = Some(value.to_vec()),
+            _ => {}
+        }
+        offset += align4(len);
+    }
+    Some(Neighbor { ip: ip?, mac: mac? })
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn align4(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+// 解析 sysctl 路由表导出：每条记录为 rt_msghdr + sockaddr_in (目标 IP) + sockaddr_dl (MAC)
+// header_len 为 rt_msghdr 的大小；sockaddr 按 4 字节对齐
+#[cfg(any(target_os = "macos", test))]
+fn parse_route_dump(data: &[u8], header_len: usize) -> Vec<Neighbor> {
+    const AF_INET: u8 = 2;
+    const AF_LINK: u8 = 18;
+
+    let mut neighbors = Vec::new();
+    let mut offset = 0;
+    while offset + 2 <= data.len() {
+        let msg_len = u16::from_ne_bytes([data[offset], data[offset + 1]]) as usize;
+        if msg_len == 0 || offset + msg_len > data.len() {
+            break;
+        }
+        let message = &data[offset..offset + msg_len];
+        offset += msg_len;
+
+        // sockaddr_in：len(1) family(1) port(2) addr(4)
+        let Some(sin) = message.get(header_len..).filter(|sin| sin.len() >= 8) else {
+            continue;
+        };
+        if sin[1] != AF_INET {
+            continue;
+        }
+        let ip = Ipv4Addr::new(sin[4], sin[5], sin[6], sin[7]);
+        let sin_len = sin[0] as usize;
+        let dl_offset = if sin_len == 0 { 4 } else { (sin_len + 3) & !3 };
+
+        // sockaddr_dl：len(1) family(1) index(2) type(1) nlen(1) alen(1) slen(1) data(接口名 + 地址)
+        let Some(dl) = sin.get(dl_offset..).filter(|dl| dl.len() >= 8) else {
+            continue;
+        };
+        if dl[1] != AF_LINK {
+            continue;
+        }
+        let (name_len, address_len) = (dl[5] as usize, dl[6] as usize);
+        if let Some(mac) = dl.get(8 + name_len..8 + name_len + address_len) {
+            neighbors.push(Neighbor {
+                ip: ip.into(),
+                mac: mac.to_vec(),
+            });
+        }
+    }
+    neighbors
+}
+
+// /proc/net/arp 每行：IP address  HW type  Flags  HW address  Mask  Device
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parse_arp_table(table: &str, ip: &str) -> Option<String> {
+    table.lines().skip(1).find_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        // Flags 为 0x0 表示地址尚未解析完成
+        if fields.len() < 4 || fields[0] != ip || fields[2] == "0x0" {
+            return None;
+        }
+        normalize_mac(fields[3])
+    })
+}
+
+// 6 字节且不全为 0 的 MAC 格式化为 aa:bb:cc:dd:ee:ff
+fn format_mac(bytes: &[u8]) -> Option<String> {
+    if bytes.len() != 6 || bytes.iter().all(|&b| b == 0) {
+        return None;
+    }
+    Some(
+        bytes
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(":"),
+    )
+}
+
+// 统一 MAC 格式为小写冒号分隔 (aa:bb:cc:dd:ee:ff)，格式不符或全 0 时返回 None
+pub(crate) fn normalize_mac(mac: &str) -> Option<String> {
+    format_mac(&parse_mac(mac).ok()?)
+}
+
+// 解析 MAC 地址，支持 AA:BB:CC:DD:EE:FF、AA-BB-CC-DD-EE-FF、aabb.ccdd.eeff 和 aabbccddeeff
+pub(crate) fn parse_mac(mac: &str) -> Result<[u8; 6]> {
+    let mac = mac.trim();
+    // 分隔符只能是一种：':'/'-' 为 2 位一组共 6 组，'.' 为 4 位一组共 3 组，或不分组
+    let separator = mac.chars().find(|c| !c.is_ascii_hexdigit());
+    let groups: Vec<&str> = match separator {
+        Some(separator) => mac.split(separator).collect(),
+        None => vec![mac],
+    };
+    let well_formed = match (separator, groups.len()) {
+        (None, 1) => mac.len() == 12,
+        (Some('.'), 3) => groups.iter().all(|group| group.len() == 4),
+        (Some(':' | '-'), 6) => groups.iter().all(|group| group.len() == 2),
+        _ => false,
+    };
+    if !well_formed {
+        return Err(anyhow::anyhow!("Invalid MAC address format: {}", mac));
+    }
+
+    let bytes = hex::decode(groups.concat())
+        .with_context(|| format!("Invalid MAC address format: {}", mac))?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("MAC address must be 6 bytes"))
+}
+
+// 判断接口是否适合做 SSDP 发现
+fn is_eligible(iface: &Interface) -> bool {
+    if !iface.is_oper_up() || iface.is_loopback() || iface.is_p2p() {
+        return false;
+    }
+    match iface.ip() {
+        IpAddr::V4(ip) => !ip.is_link_local() && !ip.is_unspecified() && !is_vpn_ip(&ip),
+        // IPv6 的 SSDP 本身就运行在链路本地组播 (FF02::C) 上，链路本地地址也可用
+        IpAddr::V6(ip) => !ip.is_unspecified(),
+    }
+}
+
+// 判断是否是 VPN/代理软件使用的虚拟 IP 地址
+pub(crate) fn is_vpn_ip(ip: &Ipv4Addr) -> bool {
+    let octets = ip.octets();
+    // 198.18.0.0/15 - 通常被 Surge、Shadowrocket 等代理软件使用
+    if octets[0] == 198 && (octets[1] == 18 || octets[1] == 19) {
+        return true;
+    }
+    // 100.64.0.0/10 - CGNAT，有时也被 VPN 使用
+    if octets[0] == 100 && octets[1] >= 64 && octets[1] <= 127 {
+        return true;
+    }
+    // 10.8.0.0/24, 10.9.0.0/24 - 常见 OpenVPN 地址段
+    if octets[0] == 10 && (octets[1] == 8 || octets[1] == 9) && octets[2] == 0 {
+        return true;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directed_broadcast_covers_prefix_lengths() {
+        let ip = Ipv4Addr::new(192, 168, 5, 77);
+        assert_eq!(directed_broadcast(ip, 24), Some(Ipv4Addr::new(192, 168, 5, 255)));
+        assert_eq!(directed_broadcast(ip, 22), Some(Ipv4Addr::new(192, 168, 7, 255)));
+        assert_eq!(directed_broadcast(ip, 16), Some(Ipv4Addr::new(192, 168, 255, 255)));
+        // /0 时移位 32 位溢出，掩码应为 0
+        assert_eq!(directed_broadcast(ip, 0), Some(Ipv4Addr::BROADCAST));
+        // 点对点链路和单主机地址没有广播地址
+        assert_eq!(directed_broadcast(ip, 31), None);
+        assert_eq!(directed_broadcast(ip, 32), None);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    const ARP_TABLE: &str = "\
+IP address       HW type     Flags       HW address            Mask     Device
+192.168.1.20     0x1         0x0         00:00:00:00:00:00     *        wlan0
+192.168.1.30     0x1         0x2         00:00:00:00:00:00     *        wlan0
+192.168.1.40     0x1         0x2         A4:5E:60:01:02:03     *        wlan0
+";
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn arp_table_returns_completed_entries() {
+        assert_eq!(
+            parse_arp_table(ARP_TABLE, "192.168.1.40").as_deref(),
+            Some("a4:5e:60:01:02:03")
+        );
+        assert_eq!(parse_arp_table(ARP_TABLE, "192.168.1.4"), None);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn arp_table_skips_incomplete_and_zero_entries() {
+        // Flags 0x0：尚未解析完成
+        assert_eq!(parse_arp_table(ARP_TABLE, "192.168.1.20"), None);
+        // 已完成但 MAC 全为 0
+        assert_eq!(parse_arp_table(ARP_TABLE, "192.168.1.30"), None);
+    }
+
+    // 构造一条 rtattr (长度按 4 字节对齐)
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn rtattr(kind: u16, value: &[u8]) -> Vec<u8> {
+        let mut attr = Vec::new();
+        attr.extend_from_slice(&((4 + value.len()) as u16).to_ne_bytes());
+        attr.extend_from_slice(&kind.to_ne_bytes());
+        attr.extend_from_slice(value);
+        attr.resize(align4(attr.len()), 0);
+        attr
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn netlink_message(kind: u16, payload: &[u8]) -> Vec<u8> {
+        let mut message = Vec::new();
+        message.extend_from_slice(&((NLMSG_HEADER_LEN + payload.len()) as u32).to_ne_bytes());
+        message.extend_from_slice(&kind.to_ne_bytes());
+        message.extend_from_slice(&[0; 10]);
+        message.extend_from_slice(payload);
+        message
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn neighbor_message(state: u16, ip: &[u8], mac: &[u8]) -> Vec<u8> {
+        let mut ndmsg = vec![0u8; NDMSG_LEN];
+        ndmsg[8..10].copy_from_slice(&state.to_ne_bytes());
+        ndmsg.extend(rtattr(libc::NDA_DST, ip));
+        ndmsg.extend(rtattr(libc::NDA_LLADDR, mac));
+        netlink_message(libc::RTM_NEWNEIGH, &ndmsg)
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn netlink_dump_yields_reachable_neighbors() {
+        let mac = [0xa4, 0x5e, 0x60, 0x01, 0x02, 0x03];
+        let v6: Ipv6Addr = "fe80::1".parse().unwrap();
+        let mut data = neighbor_message(libc::NUD_REACHABLE, &[192, 168, 1, 40], &mac);
+        data.extend(neighbor_message(libc::NUD_STALE, &v6.octets(), &mac));
+        data.extend(neighbor_message(libc::NUD_INCOMPLETE, &[192, 168, 1, 20], &[0; 6]));
+        data.extend(neighbor_message(libc::NUD_FAILED, &[192, 168, 1, 21], &mac));
+
+        let mut neighbors = Vec::new();
+        assert!(!parse_netlink_neighbors(&data, &mut neighbors).unwrap());
+        assert_eq!(
+            neighbors,
+            vec![
+                Neighbor {
+                    ip: Ipv4Addr::new(192, 168, 1, 40).into(),
+                    mac: mac.to_vec(),
+                },
+                Neighbor {
+                    ip: v6.into(),
+                    mac: mac.to_vec(),
+                },
+            ]
+        );
+
+        let done = netlink_message(libc::NLMSG_DONE as u16, &[0; 4]);
+        assert!(parse_netlink_neighbors(&done, &mut neighbors).unwrap());
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn netlink_error_is_reported() {
+        let error = netlink_message(libc::NLMSG_ERROR as u16, &(-libc::EPERM).to_ne_bytes());
+        let err = parse_netlink_neighbors(&error, &mut Vec::new()).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(libc::EPERM));
+    }
+
+    // 构造一条 rt_msghdr + sockaddr_in + sockaddr_dl 记录 (rt_msghdr 内容除长度外均为 0)
+    fn route_message(header_len: usize, ip: [u8; 4], interface: &str, mac: &[u8]) -> Vec<u8> {
+        let mut message = vec![0u8; header_len];
+        message.extend_from_slice(&[16, 2, 0, 0]);
+        message.extend_from_slice(&ip);
+        message.extend_from_slice(&[0; 8]);
+        let dl_len = 8 + interface.len() + mac.len();
+        message.extend_from_slice(&[dl_len as u8, 18, 4, 0, 6, interface.len() as u8, mac.len() as u8, 0]);
+        message.extend_from_slice(interface.as_bytes());
+        message.extend_from_slice(mac);
+        message.resize((message.len() + 3) & !3, 0);
+        let len = message.len() as u16;
+        message[0..2].copy_from_slice(&len.to_ne_bytes());
+        message
+    }
+
+    #[test]
+    fn route_dump_yields_link_layer_addresses() {
+        let header_len = 92;
+        let mac = [0xa4, 0x5e, 0x60, 0x01, 0x02, 0x03];
+        let mut data = route_message(header_len, [192, 168, 1, 40], "en0", &mac);
+        // 尚未解析的条目没有链路层地址
+        data.extend(route_message(header_len, [192, 168, 1, 20], "en0", &[]));
+
+        let neighbors = parse_route_dump(&data, header_len);
+        assert_eq!(neighbors.len(), 2);
+        assert_eq!(neighbors[0].ip, IpAddr::from([192, 168, 1, 40]));
+        assert_eq!(format_mac(&neighbors[0].mac).as_deref(), Some("a4:5e:60:01:02:03"));
+        assert_eq!(format_mac(&neighbors[1].mac), None);
+    }
+
+    const MAC: [u8; 6] = [0xaa, 0xbb, 0xcc, 0x11, 0x22, 0x33];
+
+    #[test]
+    fn parse_mac_accepts_common_formats() {
+        for mac in [
+            "AA:BB:CC:11:22:33",
+            "aa-bb-cc-11-22-33",
+            "aabb.cc11.2233",
+            "aabbcc112233",
+            "  aa:bb:cc:11:22:33\n",
+        ] {
+            assert_eq!(parse_mac(mac).unwrap(), MAC, "{mac}");
+        }
+    }
+
+    #[test]
+    fn parse_mac_rejects_mixed_separators() {
+        // 旧实现会去掉所有 ':' 和 '-' 后再解析，现在要求分隔符一致
+        assert!(parse_mac("aa:bb-cc:11-22:33").is_err());
+        assert!(parse_mac("aa-bb-cc:11:22:33").is_err());
+        assert!(parse_mac("aabb.cc11:2233").is_err());
+    }
+
+    #[test]
+    fn parse_mac_rejects_wrong_grouping_and_length() {
+        for mac in [
+            "",
+            "aa:bb:cc:11:22",
+            "aa:bb:cc:11:22:33:44",
+            "aabb:cc11:2233",
+            "a:bb:cc:11:22:333",
+            "aabbcc11223",
+            "aabb.cc11.22334",
+            "gg:bb:cc:11:22:33",
+        ] {
+            assert!(parse_mac(mac).is_err(), "{mac}");
+        }
+    }
+
+    #[test]
+    fn normalize_mac_lowercases_and_rejects_zero() {
+        assert_eq!(
+            normalize_mac("AABB.CC11.2233").as_deref(),
+            Some("aa:bb:cc:11:22:33")
+        );
+        assert_eq!(normalize_mac("00:00:00:00:00:00"), None);
+        assert_eq!(normalize_mac("not a mac"), None);
+    }
+
+    #[test]
+    fn format_mac_rejects_zero_and_wrong_length() {
+        assert_eq!(format_mac(&[0; 6]), None);
+        assert_eq!(format_mac(&[1, 2, 3, 4, 5]), None);
+        assert_eq!(format_mac(&[0; 8]), None);
+    }
+}
@@ -0,0 +1,471 @@
+use crate::api::cast::{
+    create_ssdp_socket, create_ssdp_socket_v6, fill_missing_udn, into_tokio_socket,
+    matches_search_target, multicast_interface_addrs, parse_device_xml, udn_from_usn,
+    wait_cancelled, ProjectorInfo, MAX_CONCURRENT_FETCHES, SSDP_PORT,
+};
+use crate::api::network::{remember_link_local_scope, select_ipv6_interfaces};
+use crate::api::scpd::forget_service_descriptions;
+use crate::api::ssdp::{parse_ssdp_message, DeviceChange, SsdpMessage};
+use crate::frb_generated::StreamSink;
+use anyhow::{Context, Result};
+use flutter_rust_bridge::frb;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+// CACHE-CONTROL 缺失时使用 UPnP 规范的建议值
+const DEFAULT_MAX_AGE_SECS: u64 = 1800;
+
+// --- 数据模型 ---
+
+// 设备注册表变化事件
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub enum DeviceEvent {
+    Added(ProjectorInfo),              // 收到 ssdp:alive 的新设备
+    Updated(ProjectorInfo),            // LOCATION 发生变化，已重新解析描述文件
+    Removed(ProjectorInfo),            // 收到 ssdp:byebye 或 max-age 过期
+    Rebooted(ProjectorInfo),           // BOOTID.UPNP.ORG 变化 (设备重启)，已重新解析描述文件
+    DescriptionChanged(ProjectorInfo), // CONFIGID.UPNP.ORG 变化，已重新解析描述文件
+}
+
+struct RegistryEntry {
+    info: ProjectorInfo,
+    expires_at: Instant,
+}
+
+// 设备表。根设备和每个嵌入设备都以各自的 UDN 发送 NOTIFY (共用同一个 LOCATION)，
+// 设备表以描述文件中根设备的 UDN 为键，aliases 记录 USN 中的 UDN 对应的键
+#[derive(Default)]
+struct Registry {
+    devices: HashMap<String, RegistryEntry>,
+    aliases: HashMap<String, String>,
+}
+
+impl Registry {
+    // USN 中的 UDN 对应的设备键；尚未见过的 UDN 按 LOCATION 匹配已有设备
+    fn key_for(&mut self, udn: &str, location: Option<&str>) -> Option<String> {
+        if let Some(key) = self.aliases.get(udn) {
+            return Some(key.clone());
+        }
+        let location = location?;
+        let key = self
+            .devices
+            .iter()
+            .find(|(_, entry)| entry.info.location_xml_url == location)
+            .map(|(key, _)| key.clone())?;
+        self.aliases.insert(udn.to_string(), key.clone());
+        Some(key)
+    }
+
+    // 写入设备，描述文件中所有设备的 UDN 及 USN 中的 UDN 都指向该设备
+    fn insert(&mut self, usn_udn: &str, entry: RegistryEntry) -> Option<RegistryEntry> {
+        let key = entry.info.udn.clone();
+        for udn in entry.info.description.all_udns() {
+            if !udn.is_empty() {
+                self.aliases.insert(udn.to_string(), key.clone());
+            }
+        }
+        self.aliases.insert(usn_udn.to_string(), key.clone());
+        self.devices.insert(key, entry)
+    }
+
+    fn remove(&mut self, key: &str) -> Option<RegistryEntry> {
+        self.aliases.retain(|_, alias_of| alias_of != key);
+        self.devices.remove(key)
+    }
+}
+
+// 需要下载描述文件的 alive 消息 (新设备、LOCATION 变化、重启或描述变化)
+struct PendingFetch {
+    udn: String,
+    location: String,
+    usn: String,
+    message: SsdpMessage,
+    expires_at: Instant,
+    change: Option<DeviceChange>,
+}
+
+// --- 2. 被动监听 (SSDP NOTIFY) ---
+
+/// 监听 239.255.255.250:1900 上的 NOTIFY 广播，维护在线设备表。一个 NotifyListener 只对应一次监听，停止后需重新创建
+#[frb(opaque)]
+pub struct NotifyListener {
+    registry: Arc<Mutex<Registry>>,
+    notify_targets: Vec<String>,
+    stop_tx: watch::Sender<bool>,
+}
+
+impl NotifyListener {
+    /// notify_targets 为关注的 NT (如 urn:schemas-upnp-org:device:MediaRenderer:1)，为空时接收所有设备
+    #[frb(sync)]
+    pub fn new(notify_targets: Vec<String>) -> Self {
+        let (stop_tx, _) = watch::channel(false);
+        Self {
+            registry: Arc::new(Mutex::new(Registry::default())),
+            notify_targets,
+            stop_tx,
+        }
+    }
+
+    /// 开始监听，直到调用 stop() 为止；设备变化通过 sink 推送
+    pub async fn run(&self, sink: StreamSink<DeviceEvent>) -> Result<()> {
+        // 在所有可用接口上加入多播组，收听各个网段的广播
+        let interfaces = multicast_interface_addrs(&[])?;
+        let socket = into_tokio_socket(create_ssdp_socket(SSDP_PORT, &interfaces)?)?;
+        println!("SSDP NOTIFY: Listening on port {}", SSDP_PORT);
+
+        // IPv6 (FF02::C / FF05::C) 为可选，网络不支持时只监听 IPv4
+        let socket_v6 = match select_ipv6_interfaces(&[])
+            .and_then(|interfaces| create_ssdp_socket_v6(SSDP_PORT, &interfaces))
+            .and_then(into_tokio_socket)
+        {
+            Ok(socket) => Some(socket),
+            Err(e) => {
+                println!("SSDP NOTIFY: IPv6 listening unavailable: {}", e);
+                None
+            }
+        };
+
+        let mut stop_rx = self.stop_tx.subscribe();
+        let mut expiry_timer = tokio::time::interval(Duration::from_secs(1));
+        let mut buffer = [0u8; 2048];
+        let mut buffer_v6 = [0u8; 2048];
+
+        // 描述文件在后台并发下载，接收、过期检查和 stop() 不会被慢设备阻塞
+        // (路由器或电视重启后会集中收到大量 alive)
+        let mut queued: VecDeque<PendingFetch> = VecDeque::new();
+        let mut fetches = FuturesUnordered::new();
+        // 正在下载的 LOCATION 及触发下载的 UDN，期间同一设备 (含嵌入设备) 的其他 alive 不再重复下载
+        let mut in_flight: HashMap<String, String> = HashMap::new();
+
+        loop {
+            while fetches.len() < MAX_CONCURRENT_FETCHES {
+                let Some(pending) = queued.pop_front() else {
+                    break;
+                };
+                fetches.push(async move {
+                    let result = parse_device_xml(&pending.location).await;
+                    (pending, result)
+                });
+            }
+
+            let event = tokio::select! {
+                // run() 开始前已调用 stop() 时立即返回
+                _ = wait_cancelled(&mut stop_rx) => break,
+                _ = expiry_timer.tick() => {
+                    for info in self.remove_expired() {
+                        println!("SSDP NOTIFY: {} expired", info.friendly_name);
+                        let _ = sink.add(DeviceEvent::Removed(info));
+                    }
+                    None
+                }
+                received = socket.recv_from(&mut buffer) => {
+                    let (size, source) = received.context("SSDP NOTIFY receive error")?;
+                    self.handle_datagram(&buffer[..size], source, &mut queued, &mut in_flight)
+                }
+                received = recv_optional(socket_v6.as_ref(), &mut buffer_v6) => {
+                    let (size, source) = received.context("SSDP NOTIFY IPv6 receive error")?;
+                    self.handle_datagram(&buffer_v6[..size], source, &mut queued, &mut in_flight)
+                }
+                Some((pending, result)) = fetches.next(), if !fetches.is_empty() => {
+                    // 下载期间收到 byebye 时设备已从 in_flight 移除，丢弃结果
+                    if in_flight.remove(&pending.location).is_none() {
+                        continue;
+                    }
+                    match result {
+                        Ok(info) => Some(self.complete_fetch(pending, info)),
+                        Err(e) => {
+                            println!("SSDP NOTIFY: Failed to fetch {}: {}", pending.location, e);
+                            None
+                        }
+                    }
+                }
+            };
+
+            if let Some(event) = event {
+                let _ = sink.add(event);
+            }
+        }
+
+        println!("SSDP NOTIFY: Listener stopped");
+        Ok(())
+    }
+
+    /// 停止监听；在 run() 开始前调用时 run() 会立即返回
+    #[frb(sync)]
+    pub fn stop(&self) {
+        let _ = self.stop_tx.send_replace(true);
+    }
+
+    /// 当前在线设备快照
+    #[frb(sync)]
+    pub fn devices(&self) -> Vec<ProjectorInfo> {
+        let registry = self.registry.lock().unwrap();
+        registry
+            .devices
+            .values()
+            .map(|entry| entry.info.clone())
+            .collect()
+    }
+
+    fn handle_datagram(
+        &self,
+        data: &[u8],
+        source: SocketAddr,
+        queued: &mut VecDeque<PendingFetch>,
+        in_flight: &mut HashMap<String, String>,
+    ) -> Option<DeviceEvent> {
+        let message = parse_ssdp_message(std::str::from_utf8(data).ok()?)?;
+        // 链路本地 (FF02::C) 设备的 LOCATION 为 fe80:: 地址，需记录接收接口才能下载描述文件
+        remember_link_local_scope(source, message.location.as_deref());
+        self.handle_notify(message, queued, in_flight)
+    }
+
+    // 处理一条 NOTIFY 消息，返回需要立即推送的事件；需要下载描述文件时加入 queued
+    fn handle_notify(
+        &self,
+        message: SsdpMessage,
+        queued: &mut VecDeque<PendingFetch>,
+        in_flight: &mut HashMap<String, String>,
+    ) -> Option<DeviceEvent> {
+        if message.method.as_deref() != Some("NOTIFY") {
+            return None;
+        }
+        let nts = message.notification_sub_type.clone()?;
+        let usn = message.usn.clone()?;
+        let udn = udn_from_usn(&usn).to_string();
+
+        match nts.as_str() {
+            "ssdp:alive" => {
+                if !self.notify_targets.is_empty() {
+                    let nt = message.notification_type.as_deref()?;
+                    let targets: Vec<&str> =
+                        self.notify_targets.iter().map(String::as_str).collect();
+                    if !matches_search_target(&targets, nt) {
+                        return None;
+                    }
+                }
+                let location = message.location.clone()?;
+                if in_flight.contains_key(&location) {
+                    return None;
+                }
+                let max_age = message
+                    .max_age
+                    .map(u64::from)
+                    .unwrap_or(DEFAULT_MAX_AGE_SECS);
+                let expires_at = Instant::now() + Duration::from_secs(max_age);
+
+                // 同一设备会为 rootdevice、设备类型、每个服务各发一条 alive，
+                // LOCATION、BOOTID、CONFIGID 都未变化时只刷新过期时间
+                let change = {
+                    let mut registry = self.registry.lock().unwrap();
+                    let key = registry.key_for(&udn, Some(&location));
+                    match key.and_then(|key| registry.devices.get_mut(&key)) {
+                        Some(entry) => {
+                            let change = entry
+                                .info
+                                .ssdp
+                                .as_ref()
+                                .and_then(|previous| message.change_since(previous));
+                            if change.is_none() && entry.info.location_xml_url == location {
+                                entry.expires_at = expires_at;
+                                entry.info.ssdp = Some(message);
+                                return None;
+                            }
+                            change
+                        }
+                        None => None,
+                    }
+                };
+
+                in_flight.insert(location.clone(), udn.clone());
+                queued.push_back(PendingFetch {
+                    udn,
+                    location,
+                    usn,
+                    message,
+                    expires_at,
+                    change,
+                });
+                None
+            }
+            // 设备即将改用 NEXTBOOTID (如网络接口变化)，记录下来以免之后的 alive 被误判为重启
+            "ssdp:update" => {
+                let mut registry = self.registry.lock().unwrap();
+                let key = registry.key_for(&udn, message.location.as_deref());
+                if let Some(entry) = key.and_then(|key| registry.devices.get_mut(&key)) {
+                    entry.info.ssdp = Some(message);
+                }
+                None
+            }
+            // byebye 不按 NT 过滤：设备 (含嵌入设备) 任意一条 byebye 都表示整个设备下线
+            "ssdp:byebye" => {
+                let mut registry = self.registry.lock().unwrap();
+                let key = registry.key_for(&udn, None).unwrap_or(udn);
+                in_flight.retain(|_, pending_udn| {
+                    *pending_udn != key && registry.aliases.get(pending_udn) != Some(&key)
+                });
+                registry
+                    .remove(&key)
+                    .map(|entry| DeviceEvent::Removed(entry.info))
+            }
+            _ => None,
+        }
+    }
+
+    // 描述文件下载完成后写入设备表，返回需要推送的事件
+    fn complete_fetch(&self, pending: PendingFetch, mut info: ProjectorInfo) -> DeviceEvent {
+        fill_missing_udn(&mut info, Some(&pending.usn));
+        info.ssdp = Some(pending.message);
+
+        let mut registry = self.registry.lock().unwrap();
+        let previous = registry.insert(
+            &pending.udn,
+            RegistryEntry {
+                info: info.clone(),
+                expires_at: pending.expires_at,
+            },
+        );
+        // 设备重启或描述变化后，旧的 SCPD 缓存可能已失效
+        if let (Some(previous), Some(_)) = (&previous, pending.change) {
+            forget_service_descriptions(&previous.info.description);
+        }
+        match (previous, pending.change) {
+            (Some(_), Some(DeviceChange::Rebooted)) => {
+                println!("SSDP NOTIFY: {} rebooted", info.friendly_name);
+                DeviceEvent::Rebooted(info)
+            }
+            (Some(_), Some(DeviceChange::DescriptionChanged)) => {
+                println!("SSDP NOTIFY: {} description changed", info.friendly_name);
+                DeviceEvent::DescriptionChanged(info)
+            }
+            (Some(_), None) => DeviceEvent::Updated(info),
+            (None, _) => DeviceEvent::Added(info),
+        }
+    }
+
+    // 移除所有已过期的设备
+    fn remove_expired(&self) -> Vec<ProjectorInfo> {
+        let now = Instant::now();
+        let mut registry = self.registry.lock().unwrap();
+        let expired: Vec<String> = registry
+            .devices
+            .iter()
+            .filter(|(_, entry)| entry.expires_at <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|key| registry.remove(&key))
+            .map(|entry| entry.info)
+            .collect()
+    }
+}
+
+// --- 辅助函数 ---
+
+// 未启用的 socket 永远不会就绪
+async fn recv_optional(
+    socket: Option<&tokio::net::UdpSocket>,
+    buffer: &mut [u8],
+) -> std::io::Result<(usize, SocketAddr)> {
+    match socket {
+        Some(socket) => socket.recv_from(buffer).await,
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cast::projector_info_from_description;
+    use crate::api::description::parse_description;
+
+    const ROOT_UDN: &str = "uuid:RINCON_000E58A1B2C301400";
+    const LOCATION: &str = "http://10.0.0.2:1400/xml/device_description.xml";
+    const HUB: &str = r#"<?xml version="1.0"?>
+<root xmlns="urn:schemas-upnp-org:device-1-0">
+  <device>
+    <deviceType>urn:schemas-upnp-org:device:ZonePlayer:1</deviceType>
+    <friendlyName>Living Room</friendlyName>
+    <UDN>uuid:RINCON_000E58A1B2C301400</UDN>
+    <deviceList>
+      <device>
+        <deviceType>urn:schemas-upnp-org:device:MediaRenderer:1</deviceType>
+        <friendlyName>Living Room - Renderer</friendlyName>
+        <UDN>uuid:RINCON_000E58A1B2C301400_MR</UDN>
+      </device>
+    </deviceList>
+  </device>
+</root>"#;
+
+    fn notify(nts: &str, usn: &str) -> SsdpMessage {
+        parse_ssdp_message(&format!(
+            "NOTIFY * HTTP/1.1\r\nHOST: 239.255.255.250:1900\r\nCACHE-CONTROL: max-age=1800\r\n\
+             LOCATION: {}\r\nNT: upnp:rootdevice\r\nNTS: {}\r\nUSN: {}\r\n\r\n",
+            LOCATION, nts, usn
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn embedded_device_notifies_map_to_the_root_device() {
+        let listener = NotifyListener::new(Vec::new());
+        let mut queued = VecDeque::new();
+        let mut in_flight = HashMap::new();
+        let root_usn = "uuid:RINCON_000E58A1B2C301400::upnp:rootdevice";
+        let renderer_usn =
+            "uuid:RINCON_000E58A1B2C301400_MR::urn:schemas-upnp-org:device:MediaRenderer:1";
+
+        // 同一 LOCATION 只下载一次
+        for usn in [root_usn, renderer_usn] {
+            let message = notify("ssdp:alive", usn);
+            let event = listener.handle_notify(message, &mut queued, &mut in_flight);
+            assert!(event.is_none());
+        }
+        assert_eq!(queued.len(), 1);
+
+        let pending = queued.pop_front().unwrap();
+        in_flight.remove(&pending.location);
+        let description = parse_description(HUB, LOCATION).unwrap();
+        let info = projector_info_from_description(description, LOCATION);
+        assert!(matches!(listener.complete_fetch(pending, info), DeviceEvent::Added(_)));
+
+        // 嵌入设备之后的 alive 只刷新过期时间，不会产生第二个设备
+        let message = notify("ssdp:alive", renderer_usn);
+        let event = listener.handle_notify(message, &mut queued, &mut in_flight);
+        assert!(event.is_none());
+        assert!(queued.is_empty());
+        let devices = listener.devices();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].udn, ROOT_UDN);
+
+        // 任意一个 UDN 的 byebye 都移除整个设备
+        let message = notify("ssdp:byebye", renderer_usn);
+        let event = listener.handle_notify(message, &mut queued, &mut in_flight);
+        assert!(matches!(event, Some(DeviceEvent::Removed(info)) if info.udn == ROOT_UDN));
+        assert!(listener.devices().is_empty());
+        assert!(listener.registry.lock().unwrap().aliases.is_empty());
+    }
+
+    #[test]
+    fn stop_before_run_is_not_lost() {
+        let listener = NotifyListener::new(Vec::new());
+        listener.stop();
+        let mut stop_rx = listener.stop_tx.subscribe();
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+            .block_on(async {
+                tokio::time::timeout(Duration::from_secs(1), wait_cancelled(&mut stop_rx))
+                    .await
+                    .expect("stop() before run() must still stop the listener");
+            });
+    }
+}
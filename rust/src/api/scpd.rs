@@ -0,0 +1,359 @@
+use crate::api::description::{list_items, parse_tree, DeviceDescription, XmlElement};
+use crate::api::cast::http_request;
+use anyhow::{Context, Result};
+use flutter_rust_bridge::frb;
+use reqwest::Method;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+// 获取失败的 SCPD 在这段时间内不再重试，避免每次控制动作都等待超时
+const SCPD_FAILURE_TTL: Duration = Duration::from_secs(60);
+
+// 已解析的 SCPD：SCPDURL -> 服务描述；设备重启或描述变化时由 forget_service_descriptions 清除
+static SCPD_CACHE: LazyLock<Mutex<HashMap<String, ServiceDescription>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// 获取失败的 SCPD：SCPDURL -> (失败时间, 错误信息)
+static SCPD_FAILURES: LazyLock<Mutex<HashMap<String, (Instant, String)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// --- 数据模型 ---
+
+/// 服务描述 (SCPD)：支持的动作及状态变量
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ServiceDescription {
+    pub service_type: String,
+    pub scpd_url: String,
+    pub actions: Vec<ActionInfo>,
+    pub state_variables: Vec<StateVariable>,
+}
+
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ActionInfo {
+    pub name: String, // 如 Seek、SetMute
+    pub arguments: Vec<ActionArgument>,
+}
+
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct ActionArgument {
+    pub name: String,
+    pub direction: ArgumentDirection,
+    pub related_state_variable: String,
+}
+
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArgumentDirection {
+    In,
+    Out,
+}
+
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct StateVariable {
+    pub name: String,
+    pub data_type: String, // 如 ui4、string、boolean
+    pub send_events: bool,
+    pub default_value: Option<String>,
+    pub allowed_values: Vec<String>,
+    pub allowed_value_range: Option<AllowedValueRange>,
+}
+
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone)]
+pub struct AllowedValueRange {
+    pub minimum: String,
+    pub maximum: String,
+    pub step: Option<String>,
+}
+
+impl ServiceDescription {
+    /// 服务是否实现了指定动作
+    #[frb(sync)]
+    pub fn supports_action(&self, action: String) -> bool {
+        self.find_action(&action).is_some()
+    }
+
+    pub(crate) fn find_action(&self, action: &str) -> Option<&ActionInfo> {
+        self.actions.iter().find(|a| a.name == action)
+    }
+}
+
+// --- 获取与解析 ---
+
+// 下载并解析 SCPD，结果按 SCPDURL 缓存；失败结果缓存 SCPD_FAILURE_TTL
+pub(crate) async fn fetch_service_description(
+    service_type: &str,
+    scpd_url: &str,
+) -> Result<ServiceDescription> {
+    if let Some(service) = SCPD_CACHE.lock().unwrap().get(scpd_url) {
+        return Ok(service.clone());
+    }
+    if let Some((failed_at, error)) = SCPD_FAILURES.lock().unwrap().get(scpd_url) {
+        if failed_at.elapsed() < SCPD_FAILURE_TTL {
+            return Err(anyhow::anyhow!("{} (cached failure)", error));
+        }
+    }
+
+    match download_scpd(service_type, scpd_url).await {
+        Ok(service) => {
+            SCPD_FAILURES.lock().unwrap().remove(scpd_url);
+            SCPD_CACHE
+                .lock()
+                .unwrap()
+                .insert(scpd_url.to_string(), service.clone());
+            Ok(service)
+        }
+        Err(e) => {
+            SCPD_FAILURES
+                .lock()
+                .unwrap()
+                .insert(scpd_url.to_string(), (Instant::now(), format!("{:#}", e)));
+            Err(e)
+        }
+    }
+}
+
+async fn download_scpd(service_type: &str, scpd_url: &str) -> Result<ServiceDescription> {
+    let xml = http_request(Method::GET, scpd_url)?
+        .timeout(Duration::from_secs(3))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("Failed to fetch SCPD {}", scpd_url))?
+        .text()
+        .await?;
+    parse_scpd(&xml, service_type, scpd_url)
+        .with_context(|| format!("Failed to parse SCPD {}", scpd_url))
+}
+
+// 调用动作前检查 SCPD：明确未声明该动作时返回错误；SCPD 无法获取或动作列表为空 (视为未知) 时不做拦截
+pub(crate) async fn ensure_action_supported(
+    description: &DeviceDescription,
+    service_type: &str,
+    action: &str,
+) -> Result<()> {
+    let Some(service) = description.find_service(service_type) else {
+        return Ok(());
+    };
+    if service.scpd_url.is_empty() {
+        return Ok(());
+    }
+
+    match fetch_service_description(service_type, &service.scpd_url).await {
+        Ok(scpd) if scpd.actions.is_empty() => {
+            println!("SCPD: {} lists no actions, skipping action check for {}", service_type, action);
+            Ok(())
+        }
+        Ok(scpd) if scpd.find_action(action).is_none() => Err(anyhow::anyhow!(
+            "Unsupported action: {} is not implemented by {}",
+            action,
+            service_type
+        )),
+        Ok(_) => Ok(()),
+        Err(e) => {
+            println!("SCPD: {:#}, skipping action check for {}", e, action);
+            Ok(())
+        }
+    }
+}
+
+// 设备重启或描述变化后丢弃其服务的 SCPD 缓存 (含失败记录)，下次调用动作时重新获取
+pub(crate) fn forget_service_descriptions(description: &DeviceDescription) {
+    let mut cache = SCPD_CACHE.lock().unwrap();
+    let mut failures = SCPD_FAILURES.lock().unwrap();
+    for service in description.all_services() {
+        cache.remove(&service.scpd_url);
+        failures.remove(&service.scpd_url);
+    }
+}
+
+fn parse_scpd(xml: &str, service_type: &str, scpd_url: &str) -> Result<ServiceDescription> {
+    let root = parse_tree(xml)?;
+    if root.name != "scpd" {
+        return Err(anyhow::anyhow!("Unexpected SCPD root element <{}>", root.name));
+    }
+
+    Ok(ServiceDescription {
+        service_type: service_type.to_string(),
+        scpd_url: scpd_url.to_string(),
+        actions: list_items(&root, "actionList", "action")
+            .map(parse_action)
+            .collect(),
+        state_variables: list_items(&root, "serviceStateTable", "stateVariable")
+            .map(parse_state_variable)
+            .collect(),
+    })
+}
+
+fn parse_action(element: &XmlElement) -> ActionInfo {
+    ActionInfo {
+        name: element.child_text("name").unwrap_or_default(),
+        arguments: list_items(element, "argumentList", "argument")
+            .map(|argument| {
+                let direction = match argument.child_text("direction") {
+                    Some(direction) if direction.eq_ignore_ascii_case("out") => {
+                        ArgumentDirection::Out
+                    }
+                    _ => ArgumentDirection::In,
+                };
+                ActionArgument {
+                    name: argument.child_text("name").unwrap_or_default(),
+                    direction,
+                    related_state_variable: argument
+                        .child_text("relatedStateVariable")
+                        .unwrap_or_default(),
+                }
+            })
+            .collect(),
+    }
+}
+
+fn parse_state_variable(element: &XmlElement) -> StateVariable {
+    // sendEvents 缺省为 yes
+    let send_events = !element
+        .attribute("sendEvents")
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("no"));
+
+    StateVariable {
+        name: element.child_text("name").unwrap_or_default(),
+        data_type: element.child_text("dataType").unwrap_or_default(),
+        send_events,
+        default_value: element.child_text("defaultValue"),
+        allowed_values: list_items(element, "allowedValueList", "allowedValue")
+            .map(|value| value.text.trim().to_string())
+            .collect(),
+        allowed_value_range: element.child("allowedValueRange").map(|range| {
+            AllowedValueRange {
+                minimum: range.child_text("minimum").unwrap_or_default(),
+                maximum: range.child_text("maximum").unwrap_or_default(),
+                step: range.child_text("step"),
+            }
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::description::parse_description;
+
+    const RENDERING_CONTROL: &str = include_str!("../../tests/fixtures/scpd/rendering_control.xml");
+    const KODI: &str = include_str!("../../tests/fixtures/descriptions/kodi.xml");
+
+    const RC_SERVICE: &str = "urn:schemas-upnp-org:service:RenderingControl:1";
+    const SCPD_URL: &str = "http://192.168.1.60:1151/RenderingControl/scpd.xml";
+
+    fn rendering_control() -> ServiceDescription {
+        parse_scpd(RENDERING_CONTROL, RC_SERVICE, SCPD_URL).unwrap()
+    }
+
+    fn state_variable<'a>(scpd: &'a ServiceDescription, name: &str) -> &'a StateVariable {
+        scpd.state_variables.iter().find(|v| v.name == name).unwrap()
+    }
+
+    #[test]
+    fn scpd_lists_actions_and_state_variables() {
+        let scpd = rendering_control();
+        assert_eq!(scpd.service_type, RC_SERVICE);
+        assert_eq!(scpd.scpd_url, SCPD_URL);
+        let actions: Vec<&str> = scpd.actions.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(actions, ["GetVolume", "SetVolume", "ListPresets"]);
+        assert_eq!(scpd.state_variables.len(), 5);
+        assert!(scpd.supports_action("SetVolume".to_string()));
+        assert!(!scpd.supports_action("SetMute".to_string()));
+    }
+
+    #[test]
+    fn scpd_with_wrong_root_is_rejected() {
+        assert!(parse_scpd("<root><device/></root>", RC_SERVICE, SCPD_URL).is_err());
+    }
+
+    #[test]
+    fn action_arguments_keep_direction_and_related_variable() {
+        let scpd = rendering_control();
+        let get_volume = scpd.find_action("GetVolume").unwrap();
+        let arguments: Vec<(&str, ArgumentDirection, &str)> = get_volume
+            .arguments
+            .iter()
+            .map(|a| (a.name.as_str(), a.direction, a.related_state_variable.as_str()))
+            .collect();
+        assert_eq!(
+            arguments,
+            [
+                ("InstanceID", ArgumentDirection::In, "A_ARG_TYPE_InstanceID"),
+                ("Channel", ArgumentDirection::In, "A_ARG_TYPE_Channel"),
+                // direction 不区分大小写
+                ("CurrentVolume", ArgumentDirection::Out, "Volume"),
+            ]
+        );
+        let list_presets = scpd.find_action("ListPresets").unwrap();
+        assert_eq!(list_presets.arguments[1].direction, ArgumentDirection::Out);
+    }
+
+    #[test]
+    fn state_variables_default_to_sending_events() {
+        let scpd = rendering_control();
+        assert!(state_variable(&scpd, "LastChange").send_events);
+        assert!(state_variable(&scpd, "PresetNameList").send_events);
+        assert!(!state_variable(&scpd, "Volume").send_events);
+    }
+
+    #[test]
+    fn state_variables_keep_allowed_values() {
+        let scpd = rendering_control();
+        let channel = state_variable(&scpd, "A_ARG_TYPE_Channel");
+        assert_eq!(channel.data_type, "string");
+        assert_eq!(channel.default_value.as_deref(), Some("Master"));
+        assert_eq!(channel.allowed_values, ["Master", "LF", "RF"]);
+        assert!(channel.allowed_value_range.is_none());
+
+        let volume = state_variable(&scpd, "Volume");
+        assert_eq!(volume.data_type, "ui2");
+        assert!(volume.allowed_values.is_empty());
+        let range = volume.allowed_value_range.as_ref().unwrap();
+        assert_eq!(
+            (range.minimum.as_str(), range.maximum.as_str(), range.step.as_deref()),
+            ("0", "100", Some("1"))
+        );
+        assert!(state_variable(&scpd, "A_ARG_TYPE_InstanceID").default_value.is_none());
+    }
+
+    #[test]
+    fn empty_action_list_skips_the_check_until_forgotten() {
+        let description = parse_description(KODI, "http://192.168.1.61:1151/").unwrap();
+        let scpd_url = description.find_service(RC_SERVICE).unwrap().scpd_url.clone();
+        let cache = |actions: Vec<ActionInfo>| {
+            SCPD_CACHE.lock().unwrap().insert(
+                scpd_url.clone(),
+                ServiceDescription {
+                    service_type: RC_SERVICE.to_string(),
+                    scpd_url: scpd_url.clone(),
+                    actions,
+                    state_variables: Vec::new(),
+                },
+            );
+        };
+        let check = || {
+            tokio::runtime::Builder::new_current_thread()
+                .build()
+                .unwrap()
+                .block_on(ensure_action_supported(&description, RC_SERVICE, "SetMute"))
+        };
+
+        // 动作列表为空：视为未知，不拦截
+        cache(Vec::new());
+        assert!(check().is_ok());
+
+        // 动作列表明确不含 SetMute：拦截
+        cache(rendering_control().actions);
+        assert!(check().is_err());
+
+        forget_service_descriptions(&description);
+        assert!(!SCPD_CACHE.lock().unwrap().contains_key(&scpd_url));
+    }
+}
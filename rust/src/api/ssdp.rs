@@ -0,0 +1,313 @@
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+// --- 数据模型 ---
+
+/// 解析后的 SSDP 消息 (M-SEARCH 响应或 NOTIFY)
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsdpMessage {
+    pub start_line: String,            // 如 "HTTP/1.1 200 OK"、"NOTIFY * HTTP/1.1"
+    pub method: Option<String>,        // 请求方法 (NOTIFY、M-SEARCH)，响应为 None
+    pub status_code: Option<u16>,      // 响应状态码，请求为 None
+    pub location: Option<String>,      // LOCATION
+    pub search_target: Option<String>, // ST (M-SEARCH 响应)
+    pub notification_type: Option<String>, // NT (NOTIFY)
+    pub notification_sub_type: Option<String>, // NTS：ssdp:alive、ssdp:byebye、ssdp:update
+    pub usn: Option<String>,
+    pub server: Option<String>, // SERVER，如 "Linux/4.9 UPnP/1.0 Kodi/20.2"
+    pub max_age: Option<u32>,   // CACHE-CONTROL: max-age
+    pub boot_id: Option<u32>,   // BOOTID.UPNP.ORG
+    pub next_boot_id: Option<u32>, // NEXTBOOTID.UPNP.ORG (ssdp:update)
+    pub config_id: Option<u32>, // CONFIGID.UPNP.ORG
+    pub search_port: Option<u16>, // SEARCHPORT.UPNP.ORG
+    pub headers: Vec<SsdpHeader>, // 所有头部，保留原始大小写和顺序
+}
+
+#[frb(dart_metadata=("freezed"))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsdpHeader {
+    pub name: String,
+    pub value: String,
+}
+
+// 设备重启或描述变化 (UPnP 1.1 BOOTID/CONFIGID)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DeviceChange {
+    Rebooted,
+    DescriptionChanged,
+}
+
+impl SsdpMessage {
+    /// 按名称查找头部 (不区分大小写)，用于读取厂商自定义头部 (如 X-User-Agent)
+    #[frb(sync)]
+    pub fn header(&self, name: String) -> Option<String> {
+        find_header(&self.headers, &name).map(str::to_string)
+    }
+
+    // 与同一设备之前的消息比较：BOOTID 变化表示重启，CONFIGID 变化表示描述文件已更新
+    // ssdp:update 预告的 NEXTBOOTID 视为预期值；UPnP 1.0 设备没有这两个头部，不做判断
+    pub(crate) fn change_since(&self, previous: &SsdpMessage) -> Option<DeviceChange> {
+        let expected_boot_id = previous.next_boot_id.or(previous.boot_id);
+        if let (Some(expected), Some(current)) = (expected_boot_id, self.boot_id) {
+            if expected != current {
+                return Some(DeviceChange::Rebooted);
+            }
+        }
+        match (previous.config_id, self.config_id) {
+            (Some(previous), Some(current)) if previous != current => {
+                Some(DeviceChange::DescriptionChanged)
+            }
+            _ => None,
+        }
+    }
+}
+
+// --- 解析 ---
+
+// 解析 HTTPU 消息，起始行不是 HTTP 请求/响应时返回 None
+pub(crate) fn parse_ssdp_message(data: &str) -> Option<SsdpMessage> {
+    let mut lines = data.lines();
+    let start_line = lines.next()?.trim().to_string();
+
+    let (method, status_code) = if start_line.starts_with("HTTP/") {
+        // HTTP/1.1 200 OK
+        let code = start_line.split_whitespace().nth(1)?.parse().ok()?;
+        (None, Some(code))
+    } else if start_line.ends_with("HTTP/1.1") || start_line.ends_with("HTTP/1.0") {
+        // NOTIFY * HTTP/1.1
+        (
+            Some(start_line.split_whitespace().next()?.to_string()),
+            None,
+        )
+    } else {
+        return None;
+    };
+
+    // 头部到空行为止；值中可能包含 ':' (如 URL)，只按第一个 ':' 切分
+    let headers: Vec<SsdpHeader> = lines
+        .map(str::trim)
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            Some(SsdpHeader {
+                name: name.trim().to_string(),
+                value: value.trim().to_string(),
+            })
+        })
+        .collect();
+
+    let text = |name: &str| {
+        find_header(&headers, name)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+
+    Some(SsdpMessage {
+        start_line,
+        method,
+        status_code,
+        location: text("LOCATION"),
+        search_target: text("ST"),
+        notification_type: text("NT"),
+        notification_sub_type: text("NTS"),
+        usn: text("USN"),
+        server: text("SERVER"),
+        max_age: find_header(&headers, "CACHE-CONTROL").and_then(parse_max_age),
+        boot_id: parse_number(&headers, "BOOTID.UPNP.ORG"),
+        next_boot_id: parse_number(&headers, "NEXTBOOTID.UPNP.ORG"),
+        config_id: parse_number(&headers, "CONFIGID.UPNP.ORG"),
+        search_port: parse_number(&headers, "SEARCHPORT.UPNP.ORG"),
+        headers,
+    })
+}
+
+fn find_header<'a>(headers: &'a [SsdpHeader], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|header| header.name.eq_ignore_ascii_case(name))
+        .map(|header| header.value.as_str())
+}
+
+fn parse_number<T: std::str::FromStr>(headers: &[SsdpHeader], name: &str) -> Option<T> {
+    find_header(headers, name).and_then(|value| value.parse().ok())
+}
+
+// 解析 CACHE-CONTROL: max-age=1800
+fn parse_max_age(cache_control: &str) -> Option<u32> {
+    cache_control.split(',').find_map(|directive| {
+        let (key, value) = directive.split_once('=')?;
+        if key.trim().eq_ignore_ascii_case("max-age") {
+            value.trim().parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(lines: &[&str]) -> SsdpMessage {
+        parse_ssdp_message(&(lines.join("\r\n") + "\r\n\r\n")).unwrap()
+    }
+
+    #[test]
+    fn parses_search_response() {
+        let message = parse(&[
+            "HTTP/1.1 200 OK",
+            "CACHE-CONTROL: max-age=1800",
+            "EXT:",
+            "LOCATION: http://192.168.1.20:9197/dmr",
+            "SERVER: SHP, UPnP/1.0, Samsung UPnP SDK/1.0",
+            "ST: urn:schemas-upnp-org:device:MediaRenderer:1",
+            "USN: uuid:a1f1b3c7::urn:schemas-upnp-org:device:MediaRenderer:1",
+            "BOOTID.UPNP.ORG: 12",
+            "CONFIGID.UPNP.ORG: 3",
+            "SEARCHPORT.UPNP.ORG: 1901",
+        ]);
+        assert_eq!(message.start_line, "HTTP/1.1 200 OK");
+        assert_eq!(message.method, None);
+        assert_eq!(message.status_code, Some(200));
+        assert_eq!(message.location.as_deref(), Some("http://192.168.1.20:9197/dmr"));
+        assert_eq!(
+            message.search_target.as_deref(),
+            Some("urn:schemas-upnp-org:device:MediaRenderer:1")
+        );
+        assert_eq!(
+            message.usn.as_deref(),
+            Some("uuid:a1f1b3c7::urn:schemas-upnp-org:device:MediaRenderer:1")
+        );
+        assert_eq!(message.max_age, Some(1800));
+        assert_eq!(message.boot_id, Some(12));
+        assert_eq!(message.config_id, Some(3));
+        assert_eq!(message.search_port, Some(1901));
+        assert_eq!(message.notification_type, None);
+        // 空值头部保留在 headers 中，但不作为类型化字段
+        assert_eq!(message.headers.len(), 9);
+        assert_eq!(message.header("EXT".to_string()).as_deref(), Some(""));
+    }
+
+    #[test]
+    fn parses_notify() {
+        let message = parse(&[
+            "NOTIFY * HTTP/1.1",
+            "HOST: 239.255.255.250:1900",
+            "CACHE-CONTROL: max-age=1800",
+            "LOCATION: http://192.168.1.60:1151/",
+            "NT: upnp:rootdevice",
+            "NTS: ssdp:alive",
+            "USN: uuid:d5c6e2a8::upnp:rootdevice",
+        ]);
+        assert_eq!(message.method.as_deref(), Some("NOTIFY"));
+        assert_eq!(message.status_code, None);
+        assert_eq!(message.notification_type.as_deref(), Some("upnp:rootdevice"));
+        assert_eq!(message.notification_sub_type.as_deref(), Some("ssdp:alive"));
+        assert_eq!(message.search_target, None);
+    }
+
+    #[test]
+    fn header_names_are_case_insensitive_and_keep_original_case() {
+        let message = parse(&[
+            "HTTP/1.1 200 OK",
+            "location:http://10.0.0.5:49152/description.xml",
+            "St :  upnp:rootdevice ",
+            "x-User-Agent: redsonic",
+        ]);
+        assert_eq!(
+            message.location.as_deref(),
+            Some("http://10.0.0.5:49152/description.xml")
+        );
+        assert_eq!(message.search_target.as_deref(), Some("upnp:rootdevice"));
+        assert_eq!(message.headers[0].name, "location");
+        assert_eq!(message.headers[1].name, "St");
+        assert_eq!(
+            message.header("X-USER-AGENT".to_string()).as_deref(),
+            Some("redsonic")
+        );
+        assert_eq!(message.header("SERVER".to_string()), None);
+    }
+
+    #[test]
+    fn location_keeps_colons_after_the_first() {
+        let message = parse(&["HTTP/1.1 200 OK", "LOCATION: http://[fe80::1]:49152/desc.xml"]);
+        assert_eq!(
+            message.location.as_deref(),
+            Some("http://[fe80::1]:49152/desc.xml")
+        );
+    }
+
+    #[test]
+    fn max_age_is_found_among_other_directives() {
+        let message = parse(&["HTTP/1.1 200 OK", "CACHE-CONTROL: no-cache, max-age=1800"]);
+        assert_eq!(message.max_age, Some(1800));
+
+        let message = parse(&["HTTP/1.1 200 OK", "Cache-Control: MAX-AGE = 900"]);
+        assert_eq!(message.max_age, Some(900));
+
+        let message = parse(&["HTTP/1.1 200 OK", "CACHE-CONTROL: no-cache"]);
+        assert_eq!(message.max_age, None);
+    }
+
+    #[test]
+    fn rejects_garbage_start_lines() {
+        assert!(parse_ssdp_message("").is_none());
+        assert!(parse_ssdp_message("hello world\r\nLOCATION: http://x/\r\n\r\n").is_none());
+        assert!(parse_ssdp_message("HTTP/1.1 OK\r\n\r\n").is_none());
+        assert!(parse_ssdp_message("\u{0}\u{1}binary").is_none());
+    }
+
+    fn alive(extra: &[&str]) -> SsdpMessage {
+        let mut lines = vec!["NOTIFY * HTTP/1.1", "NTS: ssdp:alive", "USN: uuid:tv::upnp:rootdevice"];
+        lines.extend_from_slice(extra);
+        parse(&lines)
+    }
+
+    #[test]
+    fn boot_id_change_means_reboot() {
+        let previous = alive(&["BOOTID.UPNP.ORG: 5", "CONFIGID.UPNP.ORG: 1"]);
+        let current = alive(&["BOOTID.UPNP.ORG: 6", "CONFIGID.UPNP.ORG: 1"]);
+        assert_eq!(current.change_since(&previous), Some(DeviceChange::Rebooted));
+        assert_eq!(previous.change_since(&previous), None);
+    }
+
+    #[test]
+    fn boot_id_announced_by_update_is_not_a_reboot() {
+        let update = parse(&[
+            "NOTIFY * HTTP/1.1",
+            "NTS: ssdp:update",
+            "USN: uuid:tv::upnp:rootdevice",
+            "BOOTID.UPNP.ORG: 5",
+            "NEXTBOOTID.UPNP.ORG: 6",
+        ]);
+        assert_eq!(update.next_boot_id, Some(6));
+        assert_eq!(alive(&["BOOTID.UPNP.ORG: 6"]).change_since(&update), None);
+        assert_eq!(
+            alive(&["BOOTID.UPNP.ORG: 7"]).change_since(&update),
+            Some(DeviceChange::Rebooted)
+        );
+    }
+
+    #[test]
+    fn config_id_change_means_new_description() {
+        let previous = alive(&["BOOTID.UPNP.ORG: 5", "CONFIGID.UPNP.ORG: 1"]);
+        let current = alive(&["BOOTID.UPNP.ORG: 5", "CONFIGID.UPNP.ORG: 2"]);
+        assert_eq!(
+            current.change_since(&previous),
+            Some(DeviceChange::DescriptionChanged)
+        );
+        // 重启优先于描述变化
+        let rebooted = alive(&["BOOTID.UPNP.ORG: 6", "CONFIGID.UPNP.ORG: 2"]);
+        assert_eq!(rebooted.change_since(&previous), Some(DeviceChange::Rebooted));
+    }
+
+    #[test]
+    fn upnp_1_0_messages_never_report_changes() {
+        let legacy = alive(&[]);
+        let current = alive(&["BOOTID.UPNP.ORG: 6", "CONFIGID.UPNP.ORG: 2"]);
+        assert_eq!(legacy.change_since(&legacy), None);
+        assert_eq!(current.change_since(&legacy), None);
+        assert_eq!(legacy.change_since(&current), None);
+    }
+}
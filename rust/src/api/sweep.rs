@@ -0,0 +1,248 @@
+use crate::api::cast::{
+    fill_missing_udn, parse_device_xml, record_device, unicast_search, wait_cancelled,
+    OnDeviceFound, ProjectorInfo, ScanDiagnostics, ScanOptions,
+};
+use crate::api::network::{select_scan_interfaces, ScanInterface};
+use anyhow::Result;
+use futures::StreamExt;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+use tokio::sync::watch;
+
+// 逐个探测时默认的并发数和每秒新开始探测的主机数
+const DEFAULT_SWEEP_CONCURRENCY: u32 = 32;
+const DEFAULT_SWEEP_RATE_PER_SEC: u32 = 50;
+// 每秒探测数上限 (间隔至少 1ms，过大的速率会使间隔为 0)
+const MAX_SWEEP_RATE_PER_SEC: u32 = 1000;
+// 最多扫描 /22 (1022 个主机)，更大的子网只扫描本机所在的 /22
+const MIN_SWEEP_PREFIX_LEN: u8 = 22;
+// 单个主机的单播 M-SEARCH 等待时间和端口连接超时
+const SWEEP_SSDP_TIMEOUT: Duration = Duration::from_millis(800);
+const SWEEP_CONNECT_TIMEOUT: Duration = Duration::from_millis(400);
+
+// 常见渲染器的描述文件地址：端口 -> 路径
+const DESCRIPTION_ENDPOINTS: &[(u16, &[&str])] = &[
+    (1400, &["/xml/device_description.xml"]), // Sonos
+    (7676, &["/smp_4_", "/smp_2_"]),          // Samsung (旧款)
+    (8008, &["/ssdp/device-desc.xml"]),       // Chromecast / Android TV
+    (9197, &["/dmr"]),                        // Samsung
+    (49152, &["/description.xml", "/rootDesc.xml", "/dmr"]),
+    (49153, &["/description.xml", "/rootDesc.xml", "/dmr"]),
+    (49154, &["/description.xml", "/rootDesc.xml", "/dmr"]),
+    (49155, &["/description.xml", "/rootDesc.xml", "/dmr"]),
+];
+
+// --- 3. 单播子网扫描 (组播、广播均不可用时的回退) ---
+
+// 按接口子网逐个主机发送单播 M-SEARCH，并尝试常见端口上的描述文件，
+// 受 sweep_rate_per_sec 限速、sweep_concurrency 限制并发，可通过 cancel 提前结束；
+// 扫描时长取决于主机数和速率，不受 timeout_secs 限制
+pub(crate) async fn sweep_subnets(
+    options: &ScanOptions,
+    devices: &mut Vec<ProjectorInfo>,
+    diagnostics: &mut ScanDiagnostics,
+    on_found: &OnDeviceFound<'_>,
+    cancel: &watch::Receiver<bool>,
+) -> Result<()> {
+    let hosts = sweep_hosts(&select_scan_interfaces(&options.interfaces)?);
+    diagnostics.swept_hosts = hosts.len() as u32;
+    println!("SSDP: Sweeping {} hosts", hosts.len());
+
+    let rate = options
+        .sweep_rate_per_sec
+        .unwrap_or(DEFAULT_SWEEP_RATE_PER_SEC)
+        .clamp(1, MAX_SWEEP_RATE_PER_SEC);
+    let concurrency = options
+        .sweep_concurrency
+        .unwrap_or(DEFAULT_SWEEP_CONCURRENCY)
+        .max(1) as usize;
+
+    // 按固定间隔放出主机，buffer_unordered 只在有空位时拉取下一个，两者共同限制发包速度
+    let mut ticker = tokio::time::interval(Duration::from_secs(1) / rate);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut probes = Box::pin(futures::stream::unfold(
+        (hosts.into_iter(), ticker),
+        |(mut hosts, mut ticker)| async move {
+            let host = hosts.next()?;
+            ticker.tick().await;
+            Some((host, (hosts, ticker)))
+        },
+    ))
+    .map(|host| probe_host(host, options))
+    .buffer_unordered(concurrency);
+
+    let mut cancel = cancel.clone();
+    loop {
+        let probed = tokio::select! {
+            _ = wait_cancelled(&mut cancel) => {
+                println!("SSDP: Sweep cancelled");
+                break;
+            }
+            probed = probes.next() => probed,
+        };
+        let Some(probed) = probed else {
+            break;
+        };
+        match probed {
+            Some(info) if !options.accepts(&info) => {
+                println!("SSDP: Skipping {} ({:?})", info.friendly_name, info.kind);
+            }
+            Some(info) => record_device(devices, info, on_found),
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+// 待扫描的主机地址 (不含网络地址、广播地址和本机地址)
+fn sweep_hosts(interfaces: &[ScanInterface]) -> Vec<Ipv4Addr> {
+    let mut hosts = Vec::new();
+    for iface in interfaces {
+        let prefix_len = iface.prefix_len.max(MIN_SWEEP_PREFIX_LEN);
+        // /31、/32 没有可扫描的其他主机
+        if prefix_len >= 31 {
+            continue;
+        }
+        let mask = u32::MAX << (32 - prefix_len as u32);
+        let network = u32::from(iface.ip) & mask;
+        let broadcast = network | !mask;
+        println!(
+            "SSDP: Sweep range {}/{} on {}",
+            Ipv4Addr::from(network),
+            prefix_len,
+            iface.name
+        );
+        hosts.extend(
+            (network + 1..broadcast)
+                .map(Ipv4Addr::from)
+                .filter(|ip| *ip != iface.ip),
+        );
+    }
+    hosts.sort();
+    hosts.dedup();
+    hosts
+}
+
+// 探测单个主机：同时发送单播 M-SEARCH 和检查常见端口，优先使用 SSDP 返回的 LOCATION
+async fn probe_host(ip: Ipv4Addr, options: &ScanOptions) -> Option<ProjectorInfo> {
+    let (ssdp, open_ports) =
+        tokio::join!(
+            search_host(ip, options),
+            futures::future::join_all(DESCRIPTION_ENDPOINTS.iter().map(
+                |&(port, paths)| async move { port_open(ip, port).await.then_some((port, paths)) }
+            ),)
+        );
+
+    if let Some(info) = ssdp {
+        return Some(info);
+    }
+    for (port, paths) in open_ports.into_iter().flatten() {
+        for path in paths {
+            let location = format!("http://{}:{}{}", ip, port, path);
+            if let Ok(mut info) = parse_device_xml(&location).await {
+                if !matches_targets(&options.targets(), &info) {
+                    return None;
+                }
+                fill_missing_udn(&mut info, None);
+                println!("SSDP: Sweep found {} at {}", info.friendly_name, location);
+                return Some(info);
+            }
+        }
+    }
+    None
+}
+
+// 对主机依次发送各个搜索目标的单播 M-SEARCH
+async fn search_host(ip: Ipv4Addr, options: &ScanOptions) -> Option<ProjectorInfo> {
+    for target in options.targets() {
+        let Ok((location, message)) =
+            unicast_search(IpAddr::V4(ip), target, SWEEP_SSDP_TIMEOUT).await
+        else {
+            continue;
+        };
+        match parse_device_xml(&location).await {
+            Ok(mut info) => {
+                fill_missing_udn(&mut info, message.usn.as_deref());
+                info.ssdp = Some(message);
+                println!("SSDP: Sweep found {} at {}", info.friendly_name, location);
+                return Some(info);
+            }
+            Err(e) => println!("SSDP: Failed to fetch {}: {}", location, e),
+        }
+    }
+    None
+}
+
+async fn port_open(ip: Ipv4Addr, port: u16) -> bool {
+    let addr = SocketAddr::new(IpAddr::V4(ip), port);
+    matches!(
+        tokio::time::timeout(SWEEP_CONNECT_TIMEOUT, tokio::net::TcpStream::connect(addr)).await,
+        Ok(Ok(_))
+    )
+}
+
+// 直接请求描述文件得到的设备没有 ST，按设备类型、服务类型或 UDN 判断是否属于搜索目标
+fn matches_targets(targets: &[&str], info: &ProjectorInfo) -> bool {
+    targets.iter().any(|target| {
+        target.eq_ignore_ascii_case("ssdp:all")
+            || target.eq_ignore_ascii_case("upnp:rootdevice")
+            || target.eq_ignore_ascii_case(&info.udn)
+            || info
+                .description
+                .all_device_types()
+                .iter()
+                .any(|device_type| device_type.eq_ignore_ascii_case(target))
+            || info
+                .description
+                .all_services()
+                .iter()
+                .any(|service| service.service_type.eq_ignore_ascii_case(target))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interface(ip: Ipv4Addr, prefix_len: u8) -> ScanInterface {
+        ScanInterface {
+            name: "en0".to_string(),
+            ip,
+            prefix_len,
+        }
+    }
+
+    #[test]
+    fn sweep_skips_network_broadcast_and_own_address() {
+        let ip = Ipv4Addr::new(192, 168, 1, 10);
+        let hosts = sweep_hosts(&[interface(ip, 24)]);
+        assert_eq!(hosts.len(), 253);
+        assert_eq!(hosts.first(), Some(&Ipv4Addr::new(192, 168, 1, 1)));
+        assert_eq!(hosts.last(), Some(&Ipv4Addr::new(192, 168, 1, 254)));
+        assert!(!hosts.contains(&ip));
+    }
+
+    #[test]
+    fn large_subnets_are_capped_to_the_local_slash_22() {
+        let ip = Ipv4Addr::new(10, 20, 6, 1);
+        let hosts = sweep_hosts(&[interface(ip, 16)]);
+        assert_eq!(hosts.len(), 1021);
+        assert_eq!(hosts.first(), Some(&Ipv4Addr::new(10, 20, 4, 1)));
+        assert_eq!(hosts.last(), Some(&Ipv4Addr::new(10, 20, 7, 254)));
+        assert!(!hosts.contains(&ip));
+    }
+
+    #[test]
+    fn overlapping_interfaces_are_deduplicated() {
+        let hosts = sweep_hosts(&[
+            interface(Ipv4Addr::new(192, 168, 1, 10), 24),
+            interface(Ipv4Addr::new(192, 168, 1, 20), 24),
+            interface(Ipv4Addr::new(172, 16, 0, 1), 32),
+        ]);
+        // 两个接口地址互相都是对方要扫描的主机
+        assert_eq!(hosts.len(), 254);
+        assert!(hosts.contains(&Ipv4Addr::new(192, 168, 1, 10)));
+        assert!(!hosts.iter().any(|ip| ip.octets()[0] == 172));
+    }
+}